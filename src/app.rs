@@ -2,31 +2,77 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::callback::Timeout;
 
-use crate::components::{Header, Footer, LoadingSpinner};
+use crate::components::{Header, Footer, LoadingSpinner, Toast, ToastContainer};
+use crate::components::game::CelebrationLayer;
+use crate::components::lobby::SeatOffers;
 use crate::services::auth_service::AuthService;
-use crate::services::websocket_service::WebSocketService;
+use crate::services::websocket_service::{ConnectionContext, WebSocketService};
+use crate::services::tab_coordinator::{TabCoordinator, TabRole};
+#[cfg(debug_assertions)]
+use crate::services::mock_waitlist::MockWaitlist;
+use crate::services::notification_service::{NotificationContext, NotificationService};
+use crate::services::alert_service::{AlertContext, AlertEvent, AlertPreferences, AlertService, DesktopPermission};
+use crate::services::sound_service::{Sound, SoundContext, SoundService, SoundSettings};
 use crate::auth::AuthState;
-use crate::game::GameState;
-use crate::types::{AppRoute, User};
+use crate::game::{TableSet, TablesContext};
+use crate::graphics::particle_effects::{Celebration, EffectSettings};
+use crate::graphics::theme::ThemeSettings;
+use crate::lobby::{clear_stored_room_passwords, LobbyState, RoomHistoryContext};
+use crate::types::{AppRoute, ClientMessage, NotificationMessage, NotificationPriority, ServerMessage, User};
+use uuid::Uuid;
+
+const CONNECTION_NOTICE: &str = "connection"; // dedupe key for the lost-connection warning
+const ROOM_LIST_TIMEOUT_MS: u32 = 10_000; // stop showing a refresh whose reply never came
 
 pub struct App {
     auth_state: AuthState,
-    game_state: GameState,
+    tables: TableSet,
+    lobby: LobbyState,
     loading: bool,
-    error_message: Option<String>,
+    notifications: NotificationService,
+    alerts: AlertService,
+    sounds: SoundService,
     _auth_service: AuthService,
-    _websocket_service: WebSocketService,
+    websocket_service: WebSocketService,
+    tab_coordinator: Option<TabCoordinator>,
+    socket_role: TabRole,
+    held_messages: Vec<ClientMessage>, // sent before any tab owned the socket
+    #[cfg(debug_assertions)]
+    mock_waitlist: MockWaitlist,
+    celebration: Option<Celebration>,
+    celebrations: u32, // how many have fired, so a repeat still plays
+    room_list_timeout: Option<Timeout>,
 }
 
 pub enum AppMsg {
     UserLoggedIn(User),
     UserLoggedOut,
-    GameStateUpdated(GameState),
+    TableUpdate(ServerMessage),
+    LobbyUpdate(ServerMessage),
+    FocusTable(String),
     ConnectionEstablished,
     ConnectionLost,
+    Reconnect,
     Error(String),
-    ClearError,
+    Notify(NotificationMessage),
+    DismissNotification(Uuid),
+    MarkNotificationsRead,
+    ClearNotificationHistory,
+    WindowFocused,
+    SetAlertPreferences(AlertPreferences),
+    RequestAlertPermission,
+    AlertPermissionChanged(DesktopPermission),
+    SetSoundSettings(SoundSettings),
+    PlaySound(Sound),
+    TimerWarning(String), // the action clock at a table is nearly out
+    SocketRoleChanged(TabRole),
+    MirroredServerMessage(String),
+    SendClientMessage(ClientMessage),
+    RoomListTimedOut,
+    RoomJoinedFromLobby(String),
+    DesktopNotificationClosed(String),
 }
 
 impl Component for App {
@@ -35,7 +81,24 @@ impl Component for App {
 
     fn create(ctx: &Context<Self>) -> Self {
         let auth_service = AuthService::new_for_app(ctx.link().clone());
-        let websocket_service = WebSocketService::new(ctx.link().clone());
+        let mut websocket_service = WebSocketService::new(ctx.link().clone());
+
+        // Only one tab per browser session holds the socket; the rest relay through it
+        let tab_coordinator = match TabCoordinator::new(
+            ctx.link().callback(AppMsg::SocketRoleChanged),
+            ctx.link().callback(AppMsg::MirroredServerMessage),
+            ctx.link().callback(AppMsg::SendClientMessage),
+        ) {
+            Ok(coordinator) => Some(coordinator),
+            Err(e) => {
+                log::warn!("Tab coordination disabled: {}", e);
+                websocket_service.connect(Callback::noop());
+                None
+            }
+        };
+        let socket_role = tab_coordinator
+            .as_ref()
+            .map_or(TabRole::Owner, |coordinator| coordinator.role());
         
         // Try to restore authentication state from local storage
         let auth_state = if let Ok(stored_user) = LocalStorage::get::<User>("primo_poker_user") {
@@ -43,14 +106,31 @@ impl Component for App {
         } else {
             AuthState::Unauthenticated
         };
+        let mut tables = TableSet::new();
+        tables.set_hero(auth_state.get_user().map(|user| user.id));
+        let mut lobby = LobbyState::new();
+        lobby.load_room_history(auth_state.get_user().map(|user| user.id));
+        clear_stored_room_passwords();
+        ThemeSettings::load().apply();
 
         Self {
             auth_state,
-            game_state: GameState::default(),
+            tables,
+            lobby,
             loading: false,
-            error_message: None,
+            notifications: NotificationService::new(ctx.link().clone()),
+            alerts: AlertService::new(ctx.link().clone()),
+            sounds: SoundService::new(ctx.link().clone()),
             _auth_service: auth_service,
-            _websocket_service: websocket_service,
+            websocket_service,
+            tab_coordinator,
+            socket_role,
+            held_messages: Vec::new(),
+            #[cfg(debug_assertions)]
+            mock_waitlist: MockWaitlist::new(ctx.link().clone()),
+            celebration: None,
+            celebrations: 0,
+            room_list_timeout: None,
         }
     }
 
@@ -59,6 +139,10 @@ impl Component for App {
             AppMsg::UserLoggedIn(user) => {
                 // Store user data in local storage
                 let _ = LocalStorage::set("primo_poker_user", &user);
+                self.tables.set_hero(Some(user.id));
+                // Show the cached favorites now; the server's copy replaces them when it arrives
+                self.lobby.load_room_history(Some(user.id));
+                ctx.link().send_message(AppMsg::SendClientMessage(ClientMessage::GetRoomHistory));
                 self.auth_state = AuthState::Authenticated(user);
                 self.loading = false;
                 
@@ -72,68 +156,343 @@ impl Component for App {
                 // Clear stored user data
                 LocalStorage::delete("primo_poker_user");
                 self.auth_state = AuthState::Unauthenticated;
-                self.game_state = GameState::default();
+                self.tables = TableSet::new();
+                self.lobby.load_room_history(None);
                 true
             }
-            AppMsg::GameStateUpdated(new_state) => {
-                self.game_state = new_state;
+            AppMsg::TableUpdate(message) => {
+                self.raise_alert(&message);
+                let audible = self.plays_feedback();
+                self.sounds.server_message(&message, &self.tables, audible);
+                let celebrated = self.celebrate(&message);
+                self.tables.apply(message) || celebrated
+            }
+            AppMsg::LobbyUpdate(message) => {
+                self.raise_alert(&message);
+                let celebrated = self.celebrate(&message);
+                if let ServerMessage::GameInvitation { ref room_id, ref room_name, ref from_username, ref invite_code } = message {
+                    let route = match invite_code {
+                        Some(code) => AppRoute::Invite { room_id: room_id.clone(), code: code.clone() },
+                        None => AppRoute::Game { room_id: room_id.clone() },
+                    };
+                    let navigator = ctx.link().navigator();
+                    let join = Callback::from(move |_| {
+                        if let Some(ref navigator) = navigator {
+                            navigator.push(&route);
+                        }
+                    });
+                    self.notifications.notify(
+                        NotificationMessage::info(format!("{} invited you to {}", from_username, room_name))
+                            .with_key(format!("invite_{}", room_id))
+                            .with_priority(NotificationPriority::High)
+                            .with_action("Join", join)
+                            .persistent(),
+                    );
+                }
+                let history = matches!(message, ServerMessage::RoomHistory { .. });
+                let changed = self.lobby.apply(message);
+                if history {
+                    for message in self.lobby.unsynced_favorites() {
+                        ctx.link().send_message(AppMsg::SendClientMessage(message));
+                    }
+                }
+                changed || celebrated
+            }
+            AppMsg::FocusTable(room_id) => {
+                self.tables.focus(&room_id);
                 true
             }
             AppMsg::ConnectionEstablished => {
                 self.loading = false;
-                self.error_message = None;
+                self.websocket_service.connection_established();
+                self.notifications.dismiss_key(CONNECTION_NOTICE);
+                self.send_held_messages(ctx);
+                // A new connection may have missed room updates; refresh the lobby's list
+                // unless a request held for the socket just went out
+                if !self.lobby.refreshing_rooms {
+                    self.dispatch(ctx, ClientMessage::GetRoomList);
+                }
                 true
             }
             AppMsg::ConnectionLost => {
-                self.error_message = Some("Connection to server lost. Attempting to reconnect...".to_string());
-                true
+                // Followers see this for a mirrored Disconnect; only the owner holds a socket
+                if self.socket_role == TabRole::Owner {
+                    self.websocket_service.schedule_reconnect();
+                }
+                // Anything held for this socket fails the usual way instead of waiting on it
+                let lobby_changed = self.send_held_messages(ctx);
+                let notified = self.notifications.notify(
+                    NotificationMessage::warning("Connection to server lost. Attempting to reconnect...")
+                        .with_key(CONNECTION_NOTICE)
+                        .with_priority(NotificationPriority::High)
+                        .persistent(),
+                );
+                notified || lobby_changed
+            }
+            AppMsg::Reconnect => {
+                if self.socket_role == TabRole::Owner {
+                    self.websocket_service.reconnect();
+                }
+                false
             }
             AppMsg::Error(error) => {
-                self.error_message = Some(error);
                 self.loading = false;
+                self.notifications.notify(NotificationMessage::error(error));
+                true
+            }
+            AppMsg::Notify(notification) => self.notifications.notify(notification),
+            AppMsg::DismissNotification(id) => self.notifications.dismiss(id),
+            AppMsg::MarkNotificationsRead => self.notifications.mark_read(),
+            AppMsg::ClearNotificationHistory => self.notifications.clear_history(),
+            AppMsg::WindowFocused => {
+                self.alerts.window_focused();
+                false
+            }
+            AppMsg::SetAlertPreferences(preferences) => self.alerts.set_preferences(preferences),
+            AppMsg::DesktopNotificationClosed(tag) => {
+                self.alerts.desktop_notification_closed(&tag);
+                false
+            }
+            AppMsg::RequestAlertPermission => {
+                self.alerts.request_permission();
+                false
+            }
+            AppMsg::AlertPermissionChanged(permission) => self.alerts.permission_changed(permission),
+            AppMsg::SetSoundSettings(settings) => self.sounds.set_settings(settings),
+            AppMsg::PlaySound(sound) => {
+                self.sounds.play(sound);
+                false
+            }
+            AppMsg::TimerWarning(room_id) => {
+                if self.plays_feedback() {
+                    self.sounds.timer_warning(&room_id, &self.tables);
+                }
+                false
+            }
+            AppMsg::SocketRoleChanged(role) => {
+                self.socket_role = role;
+                match role {
+                    TabRole::Owner => {
+                        let mirror = self
+                            .tab_coordinator
+                            .as_ref()
+                            .map_or_else(Callback::noop, |coordinator| coordinator.mirror_callback());
+                        // Held messages wait for ConnectionEstablished unless the socket is already up
+                        let connected = self.websocket_service.is_connected();
+                        self.websocket_service.connect(mirror);
+                        if connected {
+                            self.send_held_messages(ctx);
+                        }
+                    }
+                    TabRole::Follower => {
+                        self.websocket_service.disconnect();
+                        self.send_held_messages(ctx);
+                    }
+                }
                 true
             }
-            AppMsg::ClearError => {
-                self.error_message = None;
+            AppMsg::MirroredServerMessage(payload) => {
+                if let Err(e) = WebSocketService::handle_message(ctx.link(), &payload) {
+                    log::error!("Error handling mirrored message: {}", e);
+                }
+                false
+            }
+            AppMsg::SendClientMessage(message) => {
+                // Acting at one table hands focus to the next table waiting on us
+                let tables_changed = match message {
+                    ClientMessage::PlayerAction { ref room_id, .. } => {
+                        self.tables.action_taken(room_id);
+                        true
+                    }
+                    ClientMessage::StraddleDecision { ref room_id, .. }
+                    | ClientMessage::RunItTwiceDecision { ref room_id, .. } => self.tables.prompt_answered(room_id),
+                    _ => false,
+                };
+                // Until this tab's claim on the socket settles no tab may own it, so the
+                // message waits; later ones queue behind it to keep their order
+                let unsettled = self.tab_coordinator.as_ref().is_some_and(|coordinator| !coordinator.is_settled());
+                if unsettled || !self.held_messages.is_empty() {
+                    self.held_messages.push(message);
+                    return tables_changed;
+                }
+                tables_changed || self.dispatch(ctx, message)
+            }
+            AppMsg::RoomJoinedFromLobby(room_id) => {
+                self.lobby.record_join(&room_id);
                 true
             }
+            AppMsg::RoomListTimedOut => {
+                self.room_list_timeout = None;
+                self.lobby.room_list_timed_out()
+            }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let on_clear_error = ctx.link().callback(|_| AppMsg::ClearError);
+        let connection = ConnectionContext {
+            send: ctx.link().callback(AppMsg::SendClientMessage),
+            role: self.socket_role,
+        };
+        let tables = TablesContext {
+            tables: self.tables.clone(),
+            focus: ctx.link().callback(AppMsg::FocusTable),
+        };
+        let notifications = NotificationContext {
+            center: self.notifications.center().clone(),
+            notify: ctx.link().callback(AppMsg::Notify),
+            dismiss: ctx.link().callback(AppMsg::DismissNotification),
+            mark_read: ctx.link().callback(|_| AppMsg::MarkNotificationsRead),
+            clear_history: ctx.link().callback(|_| AppMsg::ClearNotificationHistory),
+        };
+        let alerts = AlertContext {
+            preferences: self.alerts.preferences(),
+            permission: self.alerts.permission(),
+            set_preferences: ctx.link().callback(AppMsg::SetAlertPreferences),
+            request_permission: ctx.link().callback(|_| AppMsg::RequestAlertPermission),
+        };
+        let sounds = SoundContext {
+            settings: self.sounds.settings(),
+            available: self.sounds.available(),
+            set_settings: ctx.link().callback(AppMsg::SetSoundSettings),
+            preview: ctx.link().callback(AppMsg::PlaySound),
+        };
+        let toasts: Vec<Toast> = notifications.center.visible().into_iter().map(Toast::from).collect();
+        let on_dismiss_toast = ctx.link().batch_callback(|id: String| Uuid::parse_str(&id).ok().map(AppMsg::DismissNotification));
+
+        let room_history = RoomHistoryContext {
+            record_join: ctx.link().callback(AppMsg::RoomJoinedFromLobby),
+        };
 
         html! {
-            <BrowserRouter>
-                <div class="app">
-                    <Header auth_state={self.auth_state.clone()} />
-                    
-                    // Error notification
-                    if let Some(ref error) = self.error_message {
-                        <div class="error-notification">
-                            <span>{ error }</span>
-                            <button onclick={on_clear_error} class="close-btn">{"×"}</button>
-                        </div>
-                    }
-                    
-                    // Loading spinner
-                    if self.loading {
-                        <LoadingSpinner />
-                    }
-                    
-                    // Main content area
-                    <main class="main-content">
-                        <Switch<AppRoute> render={switch} />
-                    </main>
-                    
-                    <Footer />
-                </div>
-            </BrowserRouter>
+            <ContextProvider<ConnectionContext> context={connection}>
+            <ContextProvider<TablesContext> context={tables}>
+            <ContextProvider<LobbyState> context={self.lobby.clone()}>
+            <ContextProvider<RoomHistoryContext> context={room_history}>
+            <ContextProvider<NotificationContext> context={notifications.clone()}>
+            <ContextProvider<AlertContext> context={alerts}>
+            <ContextProvider<SoundContext> context={sounds}>
+            <div class="app">
+                <Header auth_state={self.auth_state.clone()} />
+                
+                // Errors, connection warnings and anything components push
+                <ToastContainer
+                    toasts={toasts}
+                    queued={notifications.center.queued()}
+                    on_dismiss={on_dismiss_toast}
+                />
+                
+                // Seats held for us at waitlisted tables, shown on every page
+                <SeatOffers />
+                
+                <CelebrationLayer celebration={self.celebration} count={self.celebrations} />
+                
+                // Loading spinner
+                if self.loading {
+                    <LoadingSpinner />
+                }
+                
+                // Main content area
+                <main class="main-content">
+                    <Switch<AppRoute> render={switch} />
+                </main>
+                
+                <Footer />
+            </div>
+            </ContextProvider<SoundContext>>
+            </ContextProvider<AlertContext>>
+            </ContextProvider<NotificationContext>>
+            </ContextProvider<RoomHistoryContext>>
+            </ContextProvider<LobbyState>>
+            </ContextProvider<TablesContext>>
+            </ContextProvider<ConnectionContext>>
         }
     }
 }
 
+// App reads the router (to navigate after login, or from a notification), so it sits inside it
+#[function_component(Root)]
+pub fn root() -> Html {
+    html! {
+        <BrowserRouter>
+            <App />
+        </BrowserRouter>
+    }
+}
+
 // Route switching function
+impl App {
+    /// Sends a message through the socket, the owning tab or the dev mock server, and
+    /// tells the lobby whether its request is in flight.
+    fn dispatch(&mut self, ctx: &Context<Self>, message: ClientMessage) -> bool {
+        let sent = match (self.socket_role, self.tab_coordinator.as_ref()) {
+            (TabRole::Follower, Some(coordinator)) => coordinator.forward_to_owner(message.clone()),
+            // Mock waitlist server for Phase 1 testing, in dev builds only
+            #[cfg(debug_assertions)]
+            _ if !self.websocket_service.is_connected() && MockWaitlist::handles(&message) => {
+                let ahead = match message {
+                    ClientMessage::JoinWaitlist { ref room_id } => self.lobby.room(room_id).map_or(0, |room| room.waitlist),
+                    _ => 0,
+                };
+                self.mock_waitlist.handle(&message, ahead);
+                Ok(())
+            }
+            _ => self.websocket_service.send_message(message.clone()),
+        };
+        // Only a request that left this tab is in flight; one that failed
+        // to send must not leave the lobby waiting on a reply
+        match sent {
+            Ok(()) => {
+                // A list request can still be lost with the socket (or the owner tab's)
+                if let ClientMessage::GetRoomList = message {
+                    let link = ctx.link().clone();
+                    self.room_list_timeout = Some(Timeout::new(ROOM_LIST_TIMEOUT_MS, move || {
+                        link.send_message(AppMsg::RoomListTimedOut)
+                    }));
+                }
+                self.lobby.request_sent(&message)
+            }
+            Err(e) => {
+                log::warn!("Dropped client message: {}", e);
+                self.lobby.request_failed(&message, &e)
+            }
+        }
+    }
+
+    /// Sends the messages held while the socket had no owner, in order.
+    fn send_held_messages(&mut self, ctx: &Context<Self>) -> bool {
+        let mut changed = false;
+        for message in std::mem::take(&mut self.held_messages) {
+            changed |= self.dispatch(ctx, message);
+        }
+        changed
+    }
+
+    /// Flags events the player may be missing while the tab is in the background.
+    fn raise_alert(&mut self, message: &ServerMessage) {
+        if !self.plays_feedback() {
+            return;
+        }
+        if let Some(event) = AlertEvent::from_server_message(message, &self.tables, &self.lobby) {
+            self.alerts.alert(event);
+        }
+    }
+
+    /// Every tab handles every server frame; only one of them makes noise about it.
+    fn plays_feedback(&self) -> bool {
+        self.tab_coordinator.as_ref().is_none_or(TabCoordinator::plays_feedback)
+    }
+
+    /// Queues confetti and the like for big hands, big pots and tournament wins.
+    fn celebrate(&mut self, message: &ServerMessage) -> bool {
+        let Some(celebration) = Celebration::from_server_message(message, &self.tables, &EffectSettings::load()) else {
+            return false;
+        };
+        self.celebration = Some(celebration);
+        self.celebrations += 1;
+        true
+    }
+}
+
 fn switch(routes: AppRoute) -> Html {
     match routes {
         AppRoute::Home => html! { <crate::components::pages::HomePage /> },
@@ -143,6 +502,10 @@ fn switch(routes: AppRoute) -> Html {
         AppRoute::Game { room_id } => html! { 
             <crate::components::game::GamePage room_id={room_id} /> 
         },
+        AppRoute::Invite { room_id, code } => html! {
+            <crate::components::game::GamePage room_id={room_id} invite_code={Some(code)} />
+        },
+        AppRoute::Tables => html! { <crate::components::multi_table::MultiTablePage /> },
         AppRoute::Profile => html! { <crate::components::profile::ProfilePage /> },
         AppRoute::Demo => html! { <crate::components::demo::ComponentDemo /> },
         AppRoute::NotFound => html! { <crate::components::pages::NotFoundPage /> },
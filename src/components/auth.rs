@@ -3,9 +3,12 @@ use web_sys::HtmlInputElement;
 use wasm_bindgen::JsCast;
 use yew_router::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::callback::Timeout;
 
-use crate::types::{LoginCredentials, RegisterData, AppRoute, User};
+use crate::types::{LoginCredentials, LoginOutcome, RegisterData, AppRoute, User, TwoFactorChallenge};
 use crate::services::auth_service::AuthService;
+use crate::components::common::EnhancedInput;
+use crate::utils::validation::{self, FieldState, FieldValidator, USERNAME_CHECK_DEBOUNCE_MS};
 
 // Login Component
 pub struct LoginPage {
@@ -15,6 +18,9 @@ pub struct LoginPage {
     remember_me: bool,
     show_password: bool,
     auth_service: AuthService,
+    // Set when the server asks for a second factor after the password step
+    two_factor_challenge: Option<TwoFactorChallenge>,
+    two_factor_code: String,
 }
 
 pub enum LoginMsg {
@@ -23,6 +29,10 @@ pub enum LoginMsg {
     ToggleRememberMe,
     TogglePasswordVisibility,
     SubmitLogin,
+    TwoFactorRequired(TwoFactorChallenge),
+    UpdateTwoFactorCode(String),
+    SubmitTwoFactorCode,
+    CancelTwoFactor,
     LoginSuccess,
     LoginError(String),
     ClearError,
@@ -54,6 +64,8 @@ impl Component for LoginPage {
             remember_me: LocalStorage::get::<bool>("primo_poker_remember").unwrap_or(false),
             show_password: false,
             auth_service: AuthService::new(on_auth_success, on_auth_error),
+            two_factor_challenge: None,
+            two_factor_code: String::new(),
         }
     }
 
@@ -105,6 +117,48 @@ impl Component for LoginPage {
                 
                 wasm_bindgen_futures::spawn_local(async move {
                     match auth_service.login(credentials).await {
+                        Ok(LoginOutcome::Authenticated { .. }) => {
+                            link.send_message(LoginMsg::LoginSuccess);
+                        }
+                        Ok(LoginOutcome::TwoFactorRequired { challenge }) => {
+                            link.send_message(LoginMsg::TwoFactorRequired(challenge));
+                        }
+                        Err(error) => {
+                            link.send_message(LoginMsg::LoginError(error.to_string()));
+                        }
+                    }
+                });
+                true
+            }
+            LoginMsg::TwoFactorRequired(challenge) => {
+                self.loading = false;
+                self.two_factor_challenge = Some(challenge);
+                self.two_factor_code.clear();
+                true
+            }
+            LoginMsg::UpdateTwoFactorCode(code) => {
+                self.two_factor_code = code;
+                self.error_message = None;
+                true
+            }
+            LoginMsg::SubmitTwoFactorCode => {
+                let Some(challenge) = self.two_factor_challenge.clone() else {
+                    return false;
+                };
+                if self.two_factor_code.trim().is_empty() {
+                    self.error_message = Some("Authentication code is required".to_string());
+                    return true;
+                }
+
+                self.loading = true;
+                self.error_message = None;
+
+                let code = self.two_factor_code.clone();
+                let auth_service = self.auth_service.clone();
+                let link = ctx.link().clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    match auth_service.verify_two_factor(challenge, code).await {
                         Ok(_) => {
                             link.send_message(LoginMsg::LoginSuccess);
                         }
@@ -115,8 +169,17 @@ impl Component for LoginPage {
                 });
                 true
             }
+            LoginMsg::CancelTwoFactor => {
+                self.two_factor_challenge = None;
+                self.two_factor_code.clear();
+                self.credentials.password.clear();
+                self.error_message = None;
+                true
+            }
             LoginMsg::LoginSuccess => {
                 self.loading = false;
+                self.two_factor_challenge = None;
+                self.two_factor_code.clear();
                 // Navigation to lobby will be handled by the app component
                 // after it receives the UserLoggedIn message
                 true
@@ -134,6 +197,10 @@ impl Component for LoginPage {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.two_factor_challenge.is_some() {
+            return self.render_two_factor_step(ctx);
+        }
+
         let link = ctx.link();
         
         let on_username_change = link.callback(|e: Event| {
@@ -256,6 +323,83 @@ impl Component for LoginPage {
     }
 }
 
+impl LoginPage {
+    fn render_two_factor_step(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        let on_code_input = link.callback(|e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            LoginMsg::UpdateTwoFactorCode(input.value())
+        });
+
+        let on_submit = link.callback(|e: SubmitEvent| {
+            e.prevent_default();
+            LoginMsg::SubmitTwoFactorCode
+        });
+
+        let on_cancel = link.callback(|_| LoginMsg::CancelTwoFactor);
+        let on_clear_error = link.callback(|_| LoginMsg::ClearError);
+
+        html! {
+            <div class="auth-page">
+                <div class="auth-container">
+                    <div class="auth-header">
+                        <h1 class="auth-title">{"Two-Factor Authentication"}</h1>
+                        <p class="auth-subtitle">
+                            {"Enter the 6-digit code from your authenticator app, or one of your recovery codes"}
+                        </p>
+                    </div>
+
+                    if let Some(ref error) = self.error_message {
+                        <div class="error-banner">
+                            <span class="error-text">{error}</span>
+                            <button class="error-close" onclick={on_clear_error}>{"×"}</button>
+                        </div>
+                    }
+
+                    <form class="auth-form" onsubmit={on_submit}>
+                        <div class="form-group">
+                            <label for="totp-code" class="form-label">{"Authentication Code"}</label>
+                            <input
+                                type="text"
+                                id="totp-code"
+                                class="form-input totp-code-input"
+                                placeholder="123456"
+                                value={self.two_factor_code.clone()}
+                                oninput={on_code_input}
+                                disabled={self.loading}
+                                autocomplete="one-time-code"
+                                inputmode="numeric"
+                            />
+                        </div>
+
+                        <button
+                            type="submit"
+                            class={classes!("auth-button", "primary", if self.loading { Some("loading") } else { None })}
+                            disabled={self.loading}
+                        >
+                            if self.loading {
+                                <span class="button-spinner"></span>
+                                {"Verifying..."}
+                            } else {
+                                {"Verify"}
+                            }
+                        </button>
+                        <button
+                            type="button"
+                            class="auth-button secondary"
+                            onclick={on_cancel}
+                            disabled={self.loading}
+                        >
+                            {"Back to sign in"}
+                        </button>
+                    </form>
+                </div>
+            </div>
+        }
+    }
+}
+
 // Registration Component  
 pub struct RegisterPage {
     form_data: RegisterData,
@@ -266,10 +410,15 @@ pub struct RegisterPage {
     show_password: bool,
     show_confirm_password: bool,
     auth_service: AuthService,
+    username_state: FieldState,
+    // Pending debounced availability check; dropping it cancels the timer
+    username_check: Option<Timeout>,
 }
 
 pub enum RegisterMsg {
     UpdateUsername(String),
+    CheckUsernameAvailability,
+    UsernameAvailabilityChecked(String, Result<bool, String>), // username, available
     UpdateEmail(String),
     UpdateDisplayName(String),
     UpdatePassword(String),
@@ -307,6 +456,8 @@ impl Component for RegisterPage {
             show_password: false,
             show_confirm_password: false,
             auth_service: AuthService::new(on_auth_success, on_auth_error),
+            username_state: FieldState::Idle,
+            username_check: None,
         }
     }
 
@@ -314,25 +465,67 @@ impl Component for RegisterPage {
         match msg {
             RegisterMsg::UpdateUsername(username) => {
                 self.form_data.username = username;
-                self.errors.remove("username");
+                self.username_check = None;
                 self.validate_username();
+
+                // Only ask the server once the user pauses typing
+                if !self.username_state.is_invalid() {
+                    self.username_state = FieldState::Validating;
+                    let link = ctx.link().clone();
+                    self.username_check = Some(Timeout::new(USERNAME_CHECK_DEBOUNCE_MS, move || {
+                        link.send_message(RegisterMsg::CheckUsernameAvailability);
+                    }));
+                }
+                true
+            }
+            RegisterMsg::CheckUsernameAvailability => {
+                self.username_check = None;
+                let username = self.form_data.username.clone();
+                let auth_service = self.auth_service.clone();
+                let link = ctx.link().clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = auth_service
+                        .check_username_available(&username)
+                        .await
+                        .map_err(|e| e.to_string());
+                    link.send_message(RegisterMsg::UsernameAvailabilityChecked(username, result));
+                });
+                false
+            }
+            RegisterMsg::UsernameAvailabilityChecked(username, result) => {
+                // Ignore responses for a value the user has since edited
+                if username != self.form_data.username {
+                    return false;
+                }
+                match result {
+                    Ok(true) => self.username_state = FieldState::Valid,
+                    Ok(false) => {
+                        let message = "Username is already taken".to_string();
+                        self.errors.insert("username".to_string(), message.clone());
+                        self.username_state = FieldState::Invalid(message);
+                    }
+                    Err(error) => {
+                        // The server re-checks on registration, so don't block the form
+                        log::warn!("Username availability check failed: {}", error);
+                        self.username_state = FieldState::Idle;
+                    }
+                }
                 true
             }
             RegisterMsg::UpdateEmail(email) => {
                 self.form_data.email = email;
-                self.errors.remove("email");
-                self.validate_email();
+                self.validate_field(validation::email_validator(), &self.form_data.email.clone());
                 true
             }
             RegisterMsg::UpdateDisplayName(display_name) => {
                 self.form_data.display_name = display_name;
-                self.errors.remove("display_name");
+                self.validate_field(validation::display_name_validator(), &self.form_data.display_name.clone());
                 true
             }
             RegisterMsg::UpdatePassword(password) => {
                 self.form_data.password = password;
-                self.errors.remove("password");
-                self.validate_password();
+                self.validate_field(validation::password_validator(), &self.form_data.password.clone());
                 if !self.confirm_password.is_empty() {
                     self.validate_password_match();
                 }
@@ -340,7 +533,6 @@ impl Component for RegisterPage {
             }
             RegisterMsg::UpdateConfirmPassword(confirm_password) => {
                 self.confirm_password = confirm_password;
-                self.errors.remove("confirm_password");
                 self.validate_password_match();
                 true
             }
@@ -366,6 +558,11 @@ impl Component for RegisterPage {
                     return true;
                 }
 
+                if self.username_state.is_pending() {
+                    self.errors.insert("form".to_string(), "Still checking username availability, please try again".to_string());
+                    return true;
+                }
+
                 if !self.terms_accepted {
                     self.errors.insert("terms".to_string(), "You must accept the terms of service".to_string());
                     return true;
@@ -416,7 +613,7 @@ impl Component for RegisterPage {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let link = ctx.link();
         
-        let on_username_change = link.callback(|e: Event| {
+        let on_username_input = link.callback(|e: InputEvent| {
             let input: HtmlInputElement = e.target_unchecked_into();
             RegisterMsg::UpdateUsername(input.value())
         });
@@ -467,19 +664,16 @@ impl Component for RegisterPage {
                     <form class="auth-form" onsubmit={on_submit}>
                         <div class="form-group">
                             <label for="reg-username" class="form-label">{"Username"}</label>
-                            <input
-                                type="text"
+                            <EnhancedInput
                                 id="reg-username"
-                                class={classes!("form-input", if self.errors.contains_key("username") { Some("error") } else { None })}
+                                name="username"
                                 placeholder="Choose a username"
                                 value={self.form_data.username.clone()}
-                                onchange={on_username_change}
+                                oninput={on_username_input}
                                 disabled={self.loading}
-                                autocomplete="username"
+                                autocomplete={Some("username".to_string())}
+                                validation={self.username_state.clone()}
                             />
-                            if let Some(error) = self.errors.get("username") {
-                                <span class="field-error">{error}</span>
-                            }
                         </div>
 
                         <div class="form-group">
@@ -617,70 +811,48 @@ impl Component for RegisterPage {
 }
 
 impl RegisterPage {
-    fn validate_username(&mut self) {
-        let username = &self.form_data.username;
-        if username.len() < 3 {
-            self.errors.insert("username".to_string(), "Username must be at least 3 characters".to_string());
-        } else if username.len() > 20 {
-            self.errors.insert("username".to_string(), "Username must be no more than 20 characters".to_string());
-        } else if !username.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
-            self.errors.insert("username".to_string(), "Username can only contain letters, numbers, _ and -".to_string());
-        }
-    }
-
-    fn validate_email(&mut self) {
-        let email = &self.form_data.email;
-        if !email.contains('@') || !email.contains('.') {
-            self.errors.insert("email".to_string(), "Please enter a valid email address".to_string());
+    fn validate_field(&mut self, validator: FieldValidator, value: &str) {
+        match validator.validate(value) {
+            Ok(()) => {
+                self.errors.remove(validator.field());
+            }
+            Err(error) => {
+                self.errors.insert(error.field, error.message);
+            }
         }
     }
 
-    fn validate_password(&mut self) {
-        let password = &self.form_data.password;
-        if password.len() < 8 {
-            self.errors.insert("password".to_string(), "Password must be at least 8 characters".to_string());
-        } else if !password.chars().any(|c| c.is_uppercase()) {
-            self.errors.insert("password".to_string(), "Password must contain at least one uppercase letter".to_string());
-        } else if !password.chars().any(|c| c.is_lowercase()) {
-            self.errors.insert("password".to_string(), "Password must contain at least one lowercase letter".to_string());
-        } else if !password.chars().any(|c| c.is_numeric()) {
-            self.errors.insert("password".to_string(), "Password must contain at least one number".to_string());
+    fn validate_username(&mut self) {
+        let result = validation::username_validator().validate(&self.form_data.username);
+        match result {
+            Ok(()) => {
+                self.errors.remove("username");
+                // Keep an earlier availability result for an unchanged value
+                if self.username_state.is_invalid() {
+                    self.username_state = FieldState::Idle;
+                }
+            }
+            Err(ref error) => {
+                self.errors.insert(error.field.clone(), error.message.clone());
+                self.username_state = FieldState::from_result(result);
+            }
         }
     }
 
     fn validate_password_match(&mut self) {
-        if self.form_data.password != self.confirm_password {
-            self.errors.insert("confirm_password".to_string(), "Passwords do not match".to_string());
-        }
+        self.validate_field(
+            validation::confirm_password_validator(&self.form_data.password),
+            &self.confirm_password.clone(),
+        );
     }
 
     fn validate_all_fields(&mut self) {
-        if self.form_data.username.trim().is_empty() {
-            self.errors.insert("username".to_string(), "Username is required".to_string());
-        } else {
+        if !self.username_state.is_invalid() {
             self.validate_username();
         }
-
-        if self.form_data.email.trim().is_empty() {
-            self.errors.insert("email".to_string(), "Email is required".to_string());
-        } else {
-            self.validate_email();
-        }
-
-        if self.form_data.display_name.trim().is_empty() {
-            self.errors.insert("display_name".to_string(), "Display name is required".to_string());
-        }
-
-        if self.form_data.password.is_empty() {
-            self.errors.insert("password".to_string(), "Password is required".to_string());
-        } else {
-            self.validate_password();
-        }
-
-        if self.confirm_password.is_empty() {
-            self.errors.insert("confirm_password".to_string(), "Please confirm your password".to_string());
-        } else {
-            self.validate_password_match();
-        }
+        self.validate_field(validation::email_validator(), &self.form_data.email.clone());
+        self.validate_field(validation::display_name_validator(), &self.form_data.display_name.clone());
+        self.validate_field(validation::password_validator(), &self.form_data.password.clone());
+        self.validate_password_match();
     }
 }
@@ -3,8 +3,14 @@ use yew::prelude::*;
 use yew_router::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
 
-use crate::types::AppRoute;
+use crate::types::{AppRoute, NotificationAction, NotificationMessage, NotificationPriority, NotificationType};
+use crate::services::notification_service::NotificationContext;
 use crate::auth::AuthState;
+use crate::game::TablesContext;
+use crate::utils::validation::FieldState;
+
+// Validation lives in utils::validation; re-exported here for existing imports
+pub use crate::utils::validation::{ValidationError, FormValidator};
 
 // Header Component
 #[derive(Properties, PartialEq)]
@@ -15,6 +21,7 @@ pub struct HeaderProps {
 #[function_component(Header)]
 pub fn header(props: &HeaderProps) -> Html {
     let navigator = use_navigator().unwrap();
+    let tables = use_context::<TablesContext>().map(|ctx| ctx.tables);
     
     let on_logout = {
         let navigator = navigator.clone();
@@ -58,10 +65,19 @@ pub fn header(props: &HeaderProps) -> Html {
                         <Link<AppRoute> to={AppRoute::Lobby} classes="nav-link">
                             {"Lobby"}
                         </Link<AppRoute>>
+                        if let Some(tables) = tables.filter(|tables| !tables.is_empty()) {
+                            <Link<AppRoute> to={AppRoute::Tables} classes="nav-link">
+                                {format!("Tables ({})", tables.len())}
+                                if tables.pending_action_count() > 0 {
+                                    <span class="nav-badge action-required">{tables.pending_action_count()}</span>
+                                }
+                            </Link<AppRoute>>
+                        }
                         <Link<AppRoute> to={AppRoute::Profile} classes="nav-link">
                             {"Profile"}
                         </Link<AppRoute>>
                         
+                        <NotificationBell />
                         if let Some(user) = props.auth_state.get_user() {
                             <div class="user-info">
                                 <span class="username">{&user.display_name}</span>
@@ -70,6 +86,7 @@ pub fn header(props: &HeaderProps) -> Html {
                             </div>
                         }
                     } else {
+                        <NotificationBell />
                         <Link<AppRoute> to={AppRoute::Login} classes="nav-link">
                             {"Login"}
                         </Link<AppRoute>>
@@ -391,6 +408,23 @@ pub struct Toast {
     pub message: String,
     pub toast_type: ToastType,
     pub duration: Option<u32>, // Duration in milliseconds, None for persistent
+    pub priority: NotificationPriority,
+    pub repeat_count: u32, // shown as a counter once the same toast fires again
+    pub actions: Vec<NotificationAction>,
+}
+
+impl From<&NotificationMessage> for Toast {
+    fn from(notification: &NotificationMessage) -> Self {
+        Self {
+            id: notification.id.to_string(),
+            message: notification.message.clone(),
+            toast_type: notification.notification_type.into(),
+            duration: notification.duration.map(|seconds| (seconds * 1000.0) as u32),
+            priority: notification.priority,
+            repeat_count: notification.repeat_count,
+            actions: notification.actions.clone(),
+        }
+    }
 }
 
 #[derive(Clone, PartialEq)]
@@ -401,6 +435,17 @@ pub enum ToastType {
     Info,
 }
 
+impl From<NotificationType> for ToastType {
+    fn from(notification_type: NotificationType) -> Self {
+        match notification_type {
+            NotificationType::Info => ToastType::Info,
+            NotificationType::Success => ToastType::Success,
+            NotificationType::Warning => ToastType::Warning,
+            NotificationType::Error => ToastType::Error,
+        }
+    }
+}
+
 impl ToastType {
     fn as_class(&self) -> &'static str {
         match self {
@@ -425,12 +470,14 @@ impl ToastType {
 pub struct ToastContainerProps {
     pub toasts: Vec<Toast>,
     pub on_dismiss: Callback<String>, // Toast ID to dismiss
+    #[prop_or_default]
+    pub queued: usize, // toasts waiting for room on screen
 }
 
 #[function_component(ToastContainer)]
 pub fn toast_container(props: &ToastContainerProps) -> Html {
     html! {
-        <div class="toast-container">
+        <div class="toast-container" aria-live="polite">
             {for props.toasts.iter().map(|toast| {
                 let toast_id = toast.id.clone();
                 let on_dismiss = {
@@ -438,14 +485,41 @@ pub fn toast_container(props: &ToastContainerProps) -> Html {
                     let id = toast_id.clone();
                     Callback::from(move |_| callback.emit(id.clone()))
                 };
+                let urgent = toast.priority >= NotificationPriority::High;
                 
                 html! {
-                    <div key={toast.id.clone()} class={format!("toast {}", toast.toast_type.as_class())}>
+                    <div
+                        key={toast.id.clone()}
+                        class={classes!("toast", toast.toast_type.as_class(), urgent.then_some("toast-urgent"))}
+                        role={if urgent { "alert" } else { "status" }}
+                    >
                         <div class="toast-icon">
                             {toast.toast_type.icon()}
                         </div>
                         <div class="toast-content">
                             <span class="toast-message">{&toast.message}</span>
+                            if toast.repeat_count > 1 {
+                                <span class="toast-count">{format!("×{}", toast.repeat_count)}</span>
+                            }
+                            if !toast.actions.is_empty() {
+                                <div class="toast-actions">
+                                    {for toast.actions.iter().map(|action| {
+                                        let on_click = action.on_click.clone();
+                                        let on_dismiss = on_dismiss.clone();
+                                        html! {
+                                            <button
+                                                class="toast-action"
+                                                onclick={Callback::from(move |e: MouseEvent| {
+                                                    on_click.emit(());
+                                                    on_dismiss.emit(e);
+                                                })}
+                                            >
+                                                {&action.label}
+                                            </button>
+                                        }
+                                    })}
+                                </div>
+                            }
                         </div>
                         <button class="toast-close" onclick={on_dismiss}>
                             {"×"}
@@ -453,6 +527,87 @@ pub fn toast_container(props: &ToastContainerProps) -> Html {
                     </div>
                 }
             })}
+            if props.queued > 0 {
+                <div class="toast-queued">{format!("+{} more", props.queued)}</div>
+            }
+        </div>
+    }
+}
+
+// Notification Bell - header button opening the notification history drawer
+#[function_component(NotificationBell)]
+pub fn notification_bell() -> Html {
+    let notifications = use_context::<NotificationContext>();
+    let open = use_state(|| false);
+
+    let Some(notifications) = notifications else {
+        return html! {};
+    };
+
+    let on_toggle = {
+        let open = open.clone();
+        let mark_read = notifications.mark_read.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !*open {
+                mark_read.emit(());
+            }
+            open.set(!*open);
+        })
+    };
+    let on_close = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(false))
+    };
+    let on_clear = {
+        let clear_history = notifications.clear_history.clone();
+        Callback::from(move |_: MouseEvent| clear_history.emit(()))
+    };
+    let unread = notifications.center.unread;
+    let history = notifications.center.history();
+
+    html! {
+        <div class="notification-bell">
+            <button
+                class="bell-toggle"
+                title="Notifications"
+                aria-expanded={open.to_string()}
+                onclick={on_toggle}
+            >
+                {"🔔"}
+                if unread > 0 {
+                    <span class="nav-badge">{unread}</span>
+                }
+            </button>
+            if *open {
+                <div class="notification-drawer" role="dialog" aria-label="Notification history">
+                    <div class="drawer-header">
+                        <h4>{"Notifications"}</h4>
+                        <button class="drawer-clear" onclick={on_clear} disabled={history.is_empty()}>{"Clear"}</button>
+                        <button class="close-btn" onclick={on_close}>{"×"}</button>
+                    </div>
+                    if history.is_empty() {
+                        <p class="drawer-empty">{"Nothing yet"}</p>
+                    } else {
+                        <ul class="drawer-list">
+                            {for history.iter().map(|notification| {
+                                let toast_type = ToastType::from(notification.notification_type);
+                                html! {
+                                    <li key={notification.id.to_string()} class={classes!("drawer-item", toast_type.as_class())}>
+                                        <span class="toast-icon">{toast_type.icon()}</span>
+                                        <span class="drawer-message">
+                                            {&notification.message}
+                                            if notification.repeat_count > 1 {
+                                                <span class="toast-count">{format!(" ×{}", notification.repeat_count)}</span>
+                                            }
+                                        </span>
+                                        <span class="drawer-time">{notification.created_at.format("%H:%M").to_string()}</span>
+                                    </li>
+                                }
+                            })}
+                        </ul>
+                    }
+                </div>
+            }
         </div>
     }
 }
@@ -582,6 +737,8 @@ pub struct EnhancedInputProps {
     #[prop_or_default]
     pub error: bool,
     #[prop_or_default]
+    pub validation: FieldState,
+    #[prop_or_default]
     pub oninput: Callback<InputEvent>,
     #[prop_or_default]
     pub onchange: Callback<Event>,
@@ -591,32 +748,60 @@ pub struct EnhancedInputProps {
     pub id: String,
     #[prop_or_default]
     pub name: String,
+    #[prop_or_default]
+    pub autocomplete: Option<String>,
 }
 
 #[function_component(EnhancedInput)]
 pub fn enhanced_input(props: &EnhancedInputProps) -> Html {
+    let invalid = props.error || props.validation.is_invalid();
     let mut classes = vec!["form-input"];
-    if props.error {
+    if invalid {
         classes.push("error");
     }
+    if props.validation == FieldState::Valid {
+        classes.push("valid");
+    }
     if let Some(ref class) = props.class {
         classes.push(class);
     }
     let class_str = classes.join(" ");
+    let error_id = format!("{}-error", props.id);
+
+    let (indicator_class, indicator) = match props.validation {
+        FieldState::Idle => ("", ""),
+        FieldState::Validating => ("validating", ""),
+        FieldState::Valid => ("valid", "✓"),
+        FieldState::Invalid(_) => ("invalid", "✕"),
+    };
 
     html! {
-        <input
-            type={props.input_type.clone()}
-            class={class_str}
-            value={props.value.clone()}
-            placeholder={props.placeholder.clone()}
-            disabled={props.disabled}
-            required={props.required}
-            oninput={props.oninput.clone()}
-            onchange={props.onchange.clone()}
-            id={props.id.clone()}
-            name={props.name.clone()}
-        />
+        <div class="enhanced-input">
+            <input
+                type={props.input_type.clone()}
+                class={class_str}
+                value={props.value.clone()}
+                placeholder={props.placeholder.clone()}
+                disabled={props.disabled}
+                required={props.required}
+                oninput={props.oninput.clone()}
+                onchange={props.onchange.clone()}
+                id={props.id.clone()}
+                name={props.name.clone()}
+                autocomplete={props.autocomplete.clone()}
+                aria-invalid={invalid.to_string()}
+                aria-busy={props.validation.is_pending().to_string()}
+                aria-describedby={props.validation.error().map(|_| error_id.clone())}
+            />
+            if props.validation != FieldState::Idle {
+                <span class={classes!("validation-indicator", indicator_class)} aria-hidden="true">
+                    {indicator}
+                </span>
+            }
+            if let Some(message) = props.validation.error() {
+                <div id={error_id} class="form-error" role="alert">{message}</div>
+            }
+        </div>
     }
 }
 
@@ -676,71 +861,3 @@ pub fn screen_reader_only(props: &ScreenReaderOnlyProps) -> Html {
         </span>
     }
 }
-
-// Validation utilities
-pub struct ValidationError {
-    pub field: String,
-    pub message: String,
-}
-
-pub struct FormValidator;
-
-impl FormValidator {
-    pub fn validate_email(email: &str) -> Result<(), ValidationError> {
-        // Simple email validation - in a real app you'd use a proper regex
-        if email.contains('@') && email.contains('.') && email.len() > 5 {
-            Ok(())
-        } else {
-            Err(ValidationError {
-                field: "email".to_string(),
-                message: "Please enter a valid email address".to_string(),
-            })
-        }
-    }
-
-    pub fn validate_required(value: &str, field_name: &str) -> Result<(), ValidationError> {
-        if value.trim().is_empty() {
-            Err(ValidationError {
-                field: field_name.to_string(),
-                message: format!("{} is required", field_name),
-            })
-        } else {
-            Ok(())
-        }
-    }
-
-    pub fn validate_min_length(value: &str, min_length: usize, field_name: &str) -> Result<(), ValidationError> {
-        if value.len() < min_length {
-            Err(ValidationError {
-                field: field_name.to_string(),
-                message: format!("{} must be at least {} characters", field_name, min_length),
-            })
-        } else {
-            Ok(())
-        }
-    }
-
-    pub fn validate_password_strength(password: &str) -> Result<(), ValidationError> {
-        let has_upper = password.chars().any(|c| c.is_uppercase());
-        let has_lower = password.chars().any(|c| c.is_lowercase());
-        let has_digit = password.chars().any(|c| c.is_numeric());
-        let has_special = password.chars().any(|c| "!@#$%^&*()_+-=[]{}|;:,.<>?".contains(c));
-
-        if password.len() < 8 {
-            return Err(ValidationError {
-                field: "password".to_string(),
-                message: "Password must be at least 8 characters long".to_string(),
-            });
-        }
-
-        if !has_upper || !has_lower || !has_digit || !has_special {
-            return Err(ValidationError {
-                field: "password".to_string(),
-                message: "Password must contain uppercase, lowercase, number, and special character".to_string(),
-            });
-        }
-
-        Ok(())
-    }
-}
-
@@ -1,8 +1,55 @@
 use yew::prelude::*;
+use yew_router::prelude::*;
+use chrono::Utc;
+use gloo_events::EventListener;
+use gloo_timers::callback::Interval;
+use uuid::Uuid;
+
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlInputElement};
+
+use crate::components::lobby::RoomPasswordModal;
+use crate::lobby::{remember_room_password, room_password, LobbyState};
+use crate::graphics::animations::{Animator, MotionPreference};
+use crate::graphics::canvas::context_2d;
+use crate::graphics::chips::{count_label, ChipStack};
+use crate::graphics::particle_effects::{draw_particles, Celebration, EffectSettings, ParticleSystem};
+use crate::graphics::scene::Scene;
+use crate::graphics::theme::{suit_variable, ThemeSettings};
+use crate::graphics::{create_renderer, TableRenderer, TableView};
+use crate::game::{BlindClock, GameState, HandResultLine, HandSummary, TablePrompt, TablesContext};
+use crate::rules::{BetLimits, BettingContext, FIXED_LIMIT_RAISE_CAP};
+use crate::services::websocket_service::ConnectionContext;
+use crate::types::{AppRoute, BettingStructure, Card, ClientMessage, JoinRoomError, GamePhase, GameType, PlayerAction, PlayerInfo, PotShare, Rank, Suit};
+
+// Placeholder hand for the table shown before a room has loaded
+const MOCK_HERO_CARDS: [Card; 7] = [
+    Card::Card { suit: Suit::Spades, rank: Rank::Ace },
+    Card::Card { suit: Suit::Hearts, rank: Rank::King },
+    Card::Card { suit: Suit::Diamonds, rank: Rank::Queen },
+    Card::Card { suit: Suit::Clubs, rank: Rank::Jack },
+    Card::Card { suit: Suit::Spades, rank: Rank::Ten },
+    Card::Card { suit: Suit::Hearts, rank: Rank::Nine },
+    Card::Card { suit: Suit::Diamonds, rank: Rank::Eight },
+];
+
+// Placeholder board for the table shown before a room has loaded, river still to come
+const MOCK_BOARD: [Card; 5] = [
+    Card::Card { suit: Suit::Spades, rank: Rank::Ace },
+    Card::Card { suit: Suit::Hearts, rank: Rank::Two },
+    Card::Card { suit: Suit::Diamonds, rank: Rank::King },
+    Card::Card { suit: Suit::Clubs, rank: Rank::Jack },
+    Card::Unknown,
+];
+
+// The classic table's places clockwise from the bottom, where the hero sits
+const CLASSIC_SEAT_ORDER: [u8; 6] = [6, 4, 5, 1, 2, 3];
 
 #[derive(Properties, PartialEq)]
 pub struct GamePageProps {
     pub room_id: String,
+    #[prop_or_default]
+    pub invite_code: Option<String>, // set when opened from an invite link
 }
 
 // Playing Card Component
@@ -21,6 +68,7 @@ pub fn playing_card(props: &CardProps) -> Html {
         if props.face_up { "face-up" } else { "face-down" },
         props.classes.clone()
     );
+    let suit_color = suit_variable(&props.suit);
 
     html! {
         <div class={card_classes} style="
@@ -37,8 +85,8 @@ pub fn playing_card(props: &CardProps) -> Html {
         ">
             if props.face_up {
                 <div class="card-face" style="
-                    background: white;
-                    color: #1f2937;
+                    background: var(--card-face);
+                    color: var(--suit-spades);
                     width: 100%;
                     height: 100%;
                     border-radius: 6px;
@@ -47,12 +95,12 @@ pub fn playing_card(props: &CardProps) -> Html {
                     align-items: center;
                     justify-content: center;
                 ">
-                    <div class="card-rank" style="font-size: 0.9rem;">{&props.rank}</div>
-                    <div class="card-suit" style="font-size: 0.8rem;">{&props.suit}</div>
+                    <div class="card-rank" style={format!("font-size: 0.9rem; color: {};", suit_color)}>{&props.rank}</div>
+                    <div class="card-suit" style={format!("font-size: 0.8rem; color: {};", suit_color)}>{&props.suit}</div>
                 </div>
             } else {
                 <div class="card-back" style="
-                    background: linear-gradient(45deg, #1e40af, #3b82f6);
+                    background: var(--card-back);
                     width: 100%;
                     height: 100%;
                     border-radius: 6px;
@@ -63,13 +111,7 @@ pub fn playing_card(props: &CardProps) -> Html {
                     <div class="card-back-pattern" style="
                         width: 80%;
                         height: 80%;
-                        background: repeating-linear-gradient(
-                            45deg,
-                            rgba(255, 255, 255, 0.1),
-                            rgba(255, 255, 255, 0.1) 2px,
-                            transparent 2px,
-                            transparent 6px
-                        );
+                        background: var(--card-back-pattern);
                         border-radius: 4px;
                     "></div>
                 </div>
@@ -78,6 +120,58 @@ pub fn playing_card(props: &CardProps) -> Html {
     }
 }
 
+/// Renders a dealt card, face down when its value is hidden from us.
+pub fn card_view(card: &Card, classes: Classes) -> Html {
+    match card {
+        Card::Card { suit, rank } => html! {
+            <PlayingCard suit={suit.to_string()} rank={rank.to_string()} face_up={true} classes={classes} />
+        },
+        Card::Unknown => html! {
+            <PlayingCard suit="hidden" rank="hidden" face_up={false} classes={classes} />
+        },
+    }
+}
+
+// Stacked Chips Component - an amount as columns of chips with the exact figure beside them
+#[derive(Properties, PartialEq)]
+pub struct StackedChipsProps {
+    pub amount: i64,
+}
+
+const DOM_CHIP_SIZE: f64 = 22.0; // px
+
+#[function_component(StackedChips)]
+pub fn stacked_chips(props: &StackedChipsProps) -> Html {
+    let stack = ChipStack::new(props.amount);
+    let rise = DOM_CHIP_SIZE * 0.11; // matches the canvas table's layer offset at this size
+
+    html! {
+        <div class="chip-stack" title={format!("${}", props.amount)}>
+            <div class="chip-columns">
+                {for stack.columns.iter().map(|column| html! {
+                    <div class="chip-column" style={format!("height: {}px;", DOM_CHIP_SIZE + rise * (column.height() as f64 - 1.0))}>
+                        {for (0..column.height()).map(|layer| html! {
+                            <svg
+                                class="chip"
+                                viewBox="0 0 40 40"
+                                style={format!("bottom: {}px;", rise * layer as f64)}
+                            >
+                                <circle cx="20" cy="20" r="18" fill={column.denomination.color} stroke="rgba(0, 0, 0, 0.45)" stroke-width="2" />
+                                <circle cx="20" cy="20" r="14" fill="none" stroke={column.denomination.inlay} stroke-width="1.5" stroke-dasharray="2,1" />
+                                <circle cx="20" cy="20" r="10" fill="none" stroke={column.denomination.inlay} stroke-width="1" />
+                            </svg>
+                        })}
+                        if column.compacted() {
+                            <span class="chip-count">{count_label(column.count)}</span>
+                        }
+                    </div>
+                })}
+            </div>
+            <span class="chip-stack-amount">{format!("${}", props.amount)}</span>
+        </div>
+    }
+}
+
 // Player Seat Component
 #[derive(Properties, PartialEq)]
 pub struct PlayerSeatProps {
@@ -87,6 +181,12 @@ pub struct PlayerSeatProps {
     pub is_active: bool,
     pub is_dealer: bool,
     pub current_bet: Option<u32>,
+    #[prop_or_default]
+    pub game_type: GameType,
+    #[prop_or_default]
+    pub cards: Vec<Card>, // as the table sees them: Unknown for down cards
+    #[prop_or_default]
+    pub winning_cards: Vec<Card>, // picked out after a showdown
 }
 
 #[function_component(PlayerSeat)]
@@ -110,8 +210,8 @@ pub fn player_seat(props: &PlayerSeatProps) -> Html {
     };
 
     let base_style = "
-        background: rgba(15, 23, 42, 0.8);
-        border: 2px solid rgba(16, 185, 129, 0.5);
+        background: var(--seat-background);
+        border: 2px solid var(--seat-border);
         border-radius: 12px;
         padding: 0.8rem;
         text-align: center;
@@ -122,6 +222,8 @@ pub fn player_seat(props: &PlayerSeatProps) -> Html {
 
     let combined_style = format!("{} {}", position_style, base_style);
 
+    let rules = props.game_type.rules();
+
     html! {
         <div class={seat_classes} style={combined_style}>
             if let Some(ref name) = props.player_name {
@@ -146,49 +248,51 @@ pub fn player_seat(props: &PlayerSeatProps) -> Html {
                                 right: -5px;
                                 width: 20px;
                                 height: 20px;
-                                background: #fbbf24;
+                                background: var(--game-accent);
                                 border-radius: 50%;
                                 display: flex;
                                 align-items: center;
                                 justify-content: center;
                                 font-size: 0.7rem;
                                 font-weight: bold;
-                                color: #1f2937;
+                                color: var(--game-accent-text);
                             ">{"D"}</div>
                         }
                     </div>
                     <div class="player-info">
                         <div class="player-name" style="
-                            color: #fbbf24;
+                            color: var(--seat-name);
                             font-weight: bold;
                             font-size: 0.9rem;
                             margin-bottom: 0.3rem;
                         ">{name}</div>
                         if let Some(chips) = props.chips {
                             <div class="player-chips" style="
-                                color: #10b981;
+                                color: var(--seat-chips);
                                 font-family: monospace;
                                 font-size: 0.8rem;
                                 margin-bottom: 0.3rem;
                             ">{format!("${}", chips)}</div>
                         }
                         if let Some(bet) = props.current_bet {
-                            <div class="current-bet" style="
-                                color: #ef4444;
-                                font-size: 0.8rem;
-                                font-weight: bold;
-                            ">{format!("${}", bet)}</div>
+                            <div class="current-bet">
+                                <StackedChips amount={i64::from(bet)} />
+                            </div>
                         }
                     </div>
                     <div class="player-cards">
-                        <PlayingCard suit="hidden" rank="hidden" face_up={false} classes={classes!("player-card")} />
-                        <PlayingCard suit="hidden" rank="hidden" face_up={false} classes={classes!("player-card")} />
+                        // Opponents' stud up-cards are the ones the server has revealed
+                        {for props.cards.iter().map(|card| {
+                            let face_up = rules.is_stud() && *card != Card::Unknown;
+                            let winning = props.winning_cards.contains(card);
+                            card_view(card, classes!("player-card", face_up.then_some("up-card"), winning.then_some("winning-card")))
+                        })}
                     </div>
                 </>
             } else {
                 <div class="empty-seat" style="
                     background: rgba(71, 85, 105, 0.3);
-                    border: 2px dashed rgba(148, 163, 184, 0.5);
+                    border: 2px dashed var(--seat-open);
                     border-radius: 12px;
                     padding: 1rem;
                     text-align: center;
@@ -208,9 +312,250 @@ pub fn player_seat(props: &PlayerSeatProps) -> Html {
     }
 }
 
+// Canvas Table Component - the whole table drawn by the WebGL or Canvas2D renderer
+#[derive(Properties, PartialEq)]
+pub struct CanvasTableProps {
+    pub state: GameState,
+    pub hero_id: Option<Uuid>,
+}
+
+const CANVAS_TABLE_ASPECT: f64 = 0.625; // height / width, the DOM table's 800x500
+
+/// What CanvasTable keeps between renders: the renderer, the latest scene, the tweens
+/// running over it and the animation frame they're waiting on.
+#[derive(Default)]
+struct CanvasPainter {
+    renderer: Option<Box<dyn TableRenderer>>,
+    scene: Option<Scene>,
+    animator: Animator,
+    frame: Option<i32>, // pending requestAnimationFrame id
+}
+
+impl CanvasPainter {
+    fn paint(&mut self, now: f64) {
+        if let (Some(renderer), Some(scene)) = (self.renderer.as_mut(), self.scene.as_ref()) {
+            renderer.render(&self.animator.frame(scene, now));
+        }
+    }
+
+    /// Keeps painting once per repaint until the last tween has finished.
+    fn animate(&mut self, now: f64, tick: Option<&Closure<dyn FnMut(f64)>>) {
+        self.paint(now);
+        if let Some(tick) = tick.filter(|_| self.frame.is_none() && self.animator.is_animating(now)) {
+            self.frame = gloo_utils::window().request_animation_frame(tick.as_ref().unchecked_ref()).ok();
+        }
+    }
+}
+
+fn performance_now() -> f64 {
+    gloo_utils::window().performance().map_or(0.0, |performance| performance.now())
+}
+
+#[function_component(CanvasTable)]
+pub fn canvas_table(props: &CanvasTableProps) -> Html {
+    let canvas_ref = use_node_ref();
+    let painter = use_mut_ref(CanvasPainter::default);
+    let tick = use_mut_ref(|| None::<Closure<dyn FnMut(f64)>>);
+    let size = use_state(|| (0.0, 0.0, 1.0)); // CSS width, CSS height, device pixel ratio
+
+    // Size the canvas to its container and follow window resizes and zoom changes
+    {
+        let canvas_ref = canvas_ref.clone();
+        let painter = painter.clone();
+        let tick = tick.clone();
+        let size = size.clone();
+        use_effect_with((), move |_| {
+            let canvas = canvas_ref.cast::<HtmlCanvasElement>();
+            if let Some(ref canvas) = canvas {
+                // Artwork that finishes loading only needs the current frame drawn again
+                let redraw = {
+                    let painter = painter.clone();
+                    Callback::from(move |_| painter.borrow_mut().paint(performance_now()))
+                };
+                match create_renderer(canvas.clone(), ThemeSettings::load().palette(), redraw) {
+                    Ok(created) => painter.borrow_mut().renderer = Some(created),
+                    Err(e) => log::error!("Canvas table unavailable: {:?}", e),
+                }
+            }
+            painter.borrow_mut().animator = Animator::new(MotionPreference::load().reduce_motion());
+            *tick.borrow_mut() = Some(Closure::new({
+                let painter = painter.clone();
+                let tick = tick.clone();
+                move |now: f64| {
+                    let mut painter = painter.borrow_mut();
+                    painter.frame = None;
+                    painter.animate(now, tick.borrow().as_ref());
+                }
+            }));
+
+            let measure = move || {
+                let Some(container) = canvas.as_ref().and_then(|canvas| canvas.parent_element()) else {
+                    return;
+                };
+                let width = f64::from(container.client_width());
+                size.set((width, (width * CANVAS_TABLE_ASPECT).round(), gloo_utils::window().device_pixel_ratio()));
+            };
+            measure();
+            let listener = EventListener::new(&gloo_utils::window(), "resize", move |_| measure());
+            move || {
+                drop(listener);
+                if let Some(frame) = painter.borrow_mut().frame.take() {
+                    let _ = gloo_utils::window().cancel_animation_frame(frame);
+                }
+                // The closure holds a handle to itself; dropping it here breaks the cycle
+                tick.borrow_mut().take();
+            }
+        });
+    }
+
+    // Resizing clears the drawing buffer, so it only happens when the size really changes
+    {
+        let painter = painter.clone();
+        use_effect_with(*size, move |&(width, height, pixel_ratio)| {
+            if let Some(ref mut renderer) = painter.borrow_mut().renderer {
+                renderer.resize(width, height, pixel_ratio);
+            }
+        });
+    }
+
+    {
+        use_effect_with((props.state.clone(), props.hero_id, *size), move |(state, hero_id, (width, height, _))| {
+            if *width > 0.0 {
+                let scene = Scene::from_state(state, *hero_id, *width, *height);
+                let now = performance_now();
+                let mut painter = painter.borrow_mut();
+                painter.animator.update(&scene, state.last_hand.as_ref(), now);
+                painter.scene = Some(scene);
+                painter.animate(now, tick.borrow().as_ref());
+            }
+        });
+    }
+
+    let (_, height, _) = *size;
+    html! {
+        <div class="canvas-table">
+            <canvas ref={canvas_ref} style={format!("display: block; width: 100%; height: {}px;", height)} />
+        </div>
+    }
+}
+
+// Celebration Layer Component - confetti, chip bursts and sparkle over the whole page
+#[derive(Properties, PartialEq)]
+pub struct CelebrationLayerProps {
+    pub celebration: Option<Celebration>,
+    pub count: u32, // bumped for every celebration, so the same one can fire twice running
+}
+
+/// The particles on screen and the animation frame they're waiting on.
+struct CelebrationPainter {
+    context: Option<CanvasRenderingContext2d>,
+    system: ParticleSystem,
+    frame: Option<i32>,
+    last: f64, // time of the previous frame
+}
+
+impl CelebrationPainter {
+    fn paint(&mut self, now: f64, tick: Option<&Closure<dyn FnMut(f64)>>) {
+        self.system.step(now - self.last);
+        self.last = now;
+        let Some(ref context) = self.context else {
+            return;
+        };
+        let window = gloo_utils::window();
+        let pixel_ratio = window.device_pixel_ratio();
+        let _ = context.set_transform(pixel_ratio, 0.0, 0.0, pixel_ratio, 0.0, 0.0);
+        if let Some(canvas) = context.canvas() {
+            context.clear_rect(0.0, 0.0, f64::from(canvas.width()) / pixel_ratio, f64::from(canvas.height()) / pixel_ratio);
+        }
+        draw_particles(context, self.system.particles());
+        if let Some(tick) = tick.filter(|_| self.frame.is_none() && !self.system.is_idle()) {
+            self.frame = window.request_animation_frame(tick.as_ref().unchecked_ref()).ok();
+        }
+    }
+}
+
+#[function_component(CelebrationLayer)]
+pub fn celebration_layer(props: &CelebrationLayerProps) -> Html {
+    let canvas_ref = use_node_ref();
+    let painter = use_mut_ref(|| None::<CelebrationPainter>);
+    let tick = use_mut_ref(|| None::<Closure<dyn FnMut(f64)>>);
+
+    {
+        let painter = painter.clone();
+        let tick = tick.clone();
+        use_effect_with((), move |_| {
+            *tick.borrow_mut() = Some(Closure::new({
+                let painter = painter.clone();
+                let tick = tick.clone();
+                move |now: f64| {
+                    if let Some(ref mut painter) = *painter.borrow_mut() {
+                        painter.frame = None;
+                        painter.paint(now, tick.borrow().as_ref());
+                    }
+                }
+            }));
+            move || {
+                if let Some(frame) = painter.borrow_mut().as_mut().and_then(|painter| painter.frame.take()) {
+                    let _ = gloo_utils::window().cancel_animation_frame(frame);
+                }
+                tick.borrow_mut().take();
+            }
+        });
+    }
+
+    {
+        let canvas_ref = canvas_ref.clone();
+        use_effect_with(props.count, {
+            let celebration = props.celebration;
+            move |_| {
+                // Settings are read as each celebration fires, so the profile page applies at once
+                let settings = EffectSettings::load();
+                let Some(celebration) = celebration.filter(|_| settings.enabled && !MotionPreference::load().reduce_motion()) else {
+                    return;
+                };
+                let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() else {
+                    return;
+                };
+                let window = gloo_utils::window();
+                let width = window.inner_width().ok().and_then(|width| width.as_f64()).unwrap_or_default();
+                let height = window.inner_height().ok().and_then(|height| height.as_f64()).unwrap_or_default();
+                let pixel_ratio = window.device_pixel_ratio();
+                let now = performance_now();
+                let mut painter = painter.borrow_mut();
+                let painter = painter.get_or_insert_with(|| CelebrationPainter {
+                    context: context_2d(&canvas).map_err(|e| log::warn!("Celebrations unavailable: {:?}", e)).ok(),
+                    system: ParticleSystem::new(settings.budget, now as u32),
+                    frame: None,
+                    last: now,
+                });
+                if painter.system.is_idle() {
+                    // Follow the window between celebrations; resizing mid-burst would blank it
+                    canvas.set_width((width * pixel_ratio).round() as u32);
+                    canvas.set_height((height * pixel_ratio).round() as u32);
+                    painter.system = ParticleSystem::new(settings.budget, now as u32);
+                    painter.last = now;
+                }
+                painter.system.celebrate(celebration, width, height);
+                painter.paint(now, tick.borrow().as_ref());
+            }
+        });
+    }
+
+    html! {
+        <canvas ref={canvas_ref} class="celebration-layer" aria-hidden="true" />
+    }
+}
+
 // Community Cards Component
+#[derive(Properties, PartialEq)]
+pub struct CommunityCardsProps {
+    pub cards: Vec<Card>,
+    #[prop_or_default]
+    pub winning_cards: Vec<Card>, // picked out after a showdown
+}
+
 #[function_component(CommunityCards)]
-pub fn community_cards() -> Html {
+pub fn community_cards(props: &CommunityCardsProps) -> Html {
     html! {
         <div class="community-cards" style="
             position: absolute;
@@ -239,22 +584,89 @@ pub fn community_cards() -> Html {
                 gap: 8px;
                 align-items: center;
             ">
-                // Flop
-                <PlayingCard suit="♠" rank="A" face_up={true} classes={classes!("community-card")} />
-                <PlayingCard suit="♥" rank="♥" face_up={true} classes={classes!("community-card")} />
-                <PlayingCard suit="♦" rank="K" face_up={true} classes={classes!("community-card")} />
-                // Turn
-                <PlayingCard suit="♣" rank="J" face_up={true} classes={classes!("community-card")} />
-                // River
-                <PlayingCard suit="hidden" rank="hidden" face_up={false} classes={classes!("community-card")} />
+                {for props.cards.iter().map(|card| {
+                    let winning = props.winning_cards.contains(card);
+                    card_view(card, classes!("community-card", winning.then_some("winning-card")))
+                })}
             </div>
         </div>
     }
 }
 
 // Game Actions Component
+#[derive(Properties, PartialEq)]
+pub struct GameActionsProps {
+    #[prop_or_default]
+    pub room_id: String,
+    #[prop_or_default]
+    pub bring_in: Option<i64>, // Stud third street: the low card may bring in instead of completing
+    #[prop_or_default]
+    pub structure: BettingStructure,
+    pub betting: BettingContext,
+}
+
+/// The action sent for a bet or raise to `amount` total, with its button label.
+pub fn sized_raise(betting: &BettingContext, limits: &BetLimits, amount: i64) -> (PlayerAction, String) {
+    let action = if amount >= betting.all_in_to() {
+        PlayerAction::AllIn
+    } else if betting.current_bet == 0 {
+        PlayerAction::Bet(amount)
+    } else {
+        PlayerAction::Raise(amount)
+    };
+    let label = match action {
+        _ if limits.capped => "Capped".to_string(),
+        PlayerAction::AllIn => format!("All-in ${}", amount),
+        PlayerAction::Bet(_) => format!("Bet ${}", amount),
+        _ => format!("Raise to ${}", amount),
+    };
+    (action, label)
+}
+
 #[function_component(GameActions)]
-pub fn game_actions() -> Html {
+pub fn game_actions(props: &GameActionsProps) -> Html {
+    let connection = use_context::<ConnectionContext>();
+    let betting = props.betting;
+    let limits = props.structure.bet_limits(&betting);
+    let raise_to = use_state(|| limits.min_to);
+
+    // Snap back to the smallest legal raise whenever the action changes
+    {
+        let raise_to = raise_to.clone();
+        use_effect_with(limits, move |limits| raise_to.set(limits.min_to));
+    }
+    let amount = limits.clamp(*raise_to);
+
+    let act = |action: PlayerAction| {
+        let connection = connection.clone();
+        let room_id = props.room_id.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(ref connection) = connection {
+                connection.send.emit(ClientMessage::PlayerAction {
+                    room_id: room_id.clone(),
+                    action: action.clone(),
+                });
+            }
+        })
+    };
+    let preset = |to: i64| {
+        let raise_to = raise_to.clone();
+        Callback::from(move |_: MouseEvent| raise_to.set(to))
+    };
+    let on_slide = {
+        let raise_to = raise_to.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<i64>() {
+                raise_to.set(value);
+            }
+        })
+    };
+
+    let to_call = betting.to_call();
+    let (raise_action, raise_label) = sized_raise(&betting, &limits, amount);
+    let adjustable = !limits.capped && limits.max_to > limits.min_to;
+
     html! {
         <div class="game-actions" style="
             position: fixed;
@@ -271,7 +683,7 @@ pub fn game_actions() -> Html {
             box-shadow: 0 4px 12px rgba(0, 0, 0, 0.4);
             z-index: 100;
         ">
-            <button class="action-btn fold-btn" style="
+            <button class="action-btn fold-btn" onclick={act(PlayerAction::Fold)} style="
                 background: linear-gradient(135deg, #dc2626, #b91c1c);
                 color: white;
                 border: none;
@@ -282,89 +694,734 @@ pub fn game_actions() -> Html {
                 transition: all 0.2s;
                 box-shadow: 0 2px 4px rgba(0, 0, 0, 0.3);
             ">{"Fold"}</button>
-            <button class="action-btn call-btn" style="
-                background: linear-gradient(135deg, #059669, #047857);
-                color: white;
-                border: none;
-                padding: 12px 20px;
-                border-radius: 8px;
-                font-weight: 600;
-                cursor: pointer;
-                transition: all 0.2s;
-                box-shadow: 0 2px 4px rgba(0, 0, 0, 0.3);
-            ">{"Call $50"}</button>
-            <button class="action-btn raise-btn" style="
-                background: linear-gradient(135deg, #d97706, #b45309);
-                color: white;
-                border: none;
-                padding: 12px 20px;
-                border-radius: 8px;
-                font-weight: 600;
-                cursor: pointer;
-                transition: all 0.2s;
-                box-shadow: 0 2px 4px rgba(0, 0, 0, 0.3);
-            ">{"Raise"}</button>
-            <div class="bet-slider" style="
-                display: flex;
-                flex-direction: column;
-                align-items: center;
-                gap: 8px;
-                margin-left: 15px;
-            ">
-                <input 
-                    type="range" 
-                    min="50" 
-                    max="1000" 
-                    value="100" 
-                    class="slider" 
-                    style="
-                        width: 120px;
-                        height: 6px;
-                        background: #374151;
-                        border-radius: 3px;
-                        outline: none;
-                        cursor: pointer;
-                    "
-                />
-                <span class="bet-amount" style="
-                    color: #fbbf24;
+            if let Some(amount) = props.bring_in {
+                <button class="action-btn bring-in-btn" onclick={act(PlayerAction::BringIn)} style="
+                    background: linear-gradient(135deg, #7c3aed, #6d28d9);
+                    color: white;
+                    border: none;
+                    padding: 12px 20px;
+                    border-radius: 8px;
+                    font-weight: 600;
+                    cursor: pointer;
+                    transition: all 0.2s;
+                    box-shadow: 0 2px 4px rgba(0, 0, 0, 0.3);
+                ">{format!("Bring in ${}", amount)}</button>
+            }
+            <button
+                class="action-btn call-btn"
+                onclick={act(if to_call > 0 { PlayerAction::Call } else { PlayerAction::Check })}
+                style="
+                    background: linear-gradient(135deg, #059669, #047857);
+                    color: white;
+                    border: none;
+                    padding: 12px 20px;
+                    border-radius: 8px;
                     font-weight: 600;
-                    font-size: 0.9rem;
-                ">{"$100"}</span>
+                    cursor: pointer;
+                    transition: all 0.2s;
+                    box-shadow: 0 2px 4px rgba(0, 0, 0, 0.3);
+                "
+            >
+                {if to_call > 0 { format!("Call ${}", to_call) } else { "Check".to_string() }}
+            </button>
+            <button
+                class="action-btn raise-btn"
+                onclick={act(raise_action)}
+                disabled={limits.capped}
+                style="
+                    background: linear-gradient(135deg, #d97706, #b45309);
+                    color: white;
+                    border: none;
+                    padding: 12px 20px;
+                    border-radius: 8px;
+                    font-weight: 600;
+                    cursor: pointer;
+                    transition: all 0.2s;
+                    box-shadow: 0 2px 4px rgba(0, 0, 0, 0.3);
+                "
+            >
+                {raise_label}
+            </button>
+            if adjustable {
+                <div class="bet-slider" style="
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 8px;
+                    margin-left: 15px;
+                ">
+                    <div class="bet-presets">
+                        <button class="bet-preset" onclick={preset(limits.min_to)}>{"Min"}</button>
+                        <button class="bet-preset" onclick={preset(limits.clamp(betting.pot_fraction_to(0.5)))}>{"½ Pot"}</button>
+                        <button class="bet-preset" onclick={preset(limits.clamp(betting.pot_fraction_to(1.0)))}>{"Pot"}</button>
+                        if props.structure == BettingStructure::NoLimit {
+                            <button class="bet-preset" onclick={preset(limits.max_to)}>{"All-in"}</button>
+                        }
+                    </div>
+                    <input
+                        type="range"
+                        min={limits.min_to.to_string()}
+                        max={limits.max_to.to_string()}
+                        step={limits.step.to_string()}
+                        value={amount.to_string()}
+                        oninput={on_slide}
+                        class="slider"
+                        style="
+                            width: 120px;
+                            height: 6px;
+                            background: #374151;
+                            border-radius: 3px;
+                            outline: none;
+                            cursor: pointer;
+                        "
+                    />
+                    <span class="bet-amount" style="
+                        color: #fbbf24;
+                        font-weight: 600;
+                        font-size: 0.9rem;
+                    ">{format!("{} · ${}", props.structure.abbreviation(), amount)}</span>
+                </div>
+            } else if props.structure == BettingStructure::FixedLimit {
+                <span class="bet-limit-note">
+                    {if limits.capped {
+                        format!("Betting capped at {} bets", FIXED_LIMIT_RAISE_CAP)
+                    } else {
+                        format!("{} of {} bets", betting.raises, FIXED_LIMIT_RAISE_CAP)
+                    }}
+                </span>
+            }
+        </div>
+    }
+}
+
+// Blind Level Clock Component - tournament level countdown
+#[derive(Properties, PartialEq)]
+pub struct BlindLevelClockProps {
+    pub clock: BlindClock,
+}
+
+#[function_component(BlindLevelClock)]
+pub fn blind_level_clock(props: &BlindLevelClockProps) -> Html {
+    let now = use_state(Utc::now);
+
+    {
+        let now = now.clone();
+        use_effect_with((), move |_| {
+            let interval = Interval::new(1000, move || now.set(Utc::now()));
+            move || drop(interval)
+        });
+    }
+
+    let level = &props.clock.level;
+    let remaining = props.clock.seconds_remaining(*now);
+    let ante = if level.ante > 0 { format!(" ante {}", level.ante) } else { String::new() };
+
+    html! {
+        <div class={classes!("blind-level-clock", (remaining <= 60).then_some("ending"))}>
+            <span class="blind-level">{format!("Level {}", level.level)}</span>
+            <span class="blind-amounts">{format!("{}/{}{}", level.small_blind, level.big_blind, ante)}</span>
+            <span class="blind-countdown">{format!("{:02}:{:02}", remaining / 60, remaining % 60)}</span>
+            if let Some(ref next) = props.clock.next_level {
+                <span class="blind-next">{format!("Next: {}/{}", next.small_blind, next.big_blind)}</span>
+            }
+        </div>
+    }
+}
+
+// Hand Result Banner Component - the showdown: winners split into high and low halves,
+// the hands that were shown with the winning cards picked out, and a show-or-muck choice
+// for the hero after an uncontested win
+#[derive(Properties, PartialEq)]
+pub struct HandResultBannerProps {
+    pub room_id: String,
+    pub summary: HandSummary,
+    pub hero_id: Option<Uuid>,
+}
+
+#[function_component(HandResultBanner)]
+pub fn hand_result_banner(props: &HandResultBannerProps) -> Html {
+    let connection = use_context::<ConnectionContext>();
+    let decided = use_state(|| false); // the banner is keyed by hand, so this resets each hand
+    let summary = &props.summary;
+
+    let showdown_cards = |cards: &[Card], class: &'static str| {
+        html! {
+            {for cards.iter().map(|card| card_view(card, classes!(class, summary.is_winning_card(card).then_some("winning-card"))))}
+        }
+    };
+
+    // Winners' amounts land one after another, like the chips pushed to each seat
+    let render_line = |(index, line): (usize, &HandResultLine)| {
+        let label = match (summary.hi_lo, line.share) {
+            (false, _) => "Winner",
+            (true, PotShare::High) => "High",
+            (true, PotShare::Low) => "Low",
+            (true, PotShare::Scoop) => "Scoop",
+        };
+        html! {
+            <li class={classes!("hand-result-line", format!("{:?}", line.share).to_lowercase())}>
+                <span class="hand-result-share">{label}</span>
+                <span class="hand-result-player">
+                    {&line.player_name}
+                    if let Some(cards) = summary.shown_cards(line.player_id) {
+                        <span class="showdown-hand">{showdown_cards(cards, "showdown-card")}</span>
+                    }
+                </span>
+                <span class="hand-result-hand">{summary.hand_name(line)}</span>
+                <span class="hand-result-amount" style={format!("animation-delay: {}ms;", index * 250)}>
+                    {format!("+${}", line.amount)}
+                </span>
+            </li>
+        }
+    };
+
+    // A hand run twice splits every pot in half, one half per board
+    let render_board = |index: usize, board: &Vec<Card>| {
+        html! {
+            <div class="hand-result-board">
+                <div class="hand-result-board-cards">
+                    <span class="board-label">{format!("Board {}", index + 1)}</span>
+                    {showdown_cards(board, "community-card")}
+                </div>
+                <ul class="hand-result-lines">
+                    {for summary.winners.iter().enumerate().filter(|(_, line)| usize::from(line.board) == index).map(render_line)}
+                </ul>
             </div>
+        }
+    };
+
+    // Everyone else who turned their cards over
+    let losers: Vec<_> = summary
+        .shown
+        .iter()
+        .filter(|hand| !summary.winners.iter().any(|line| line.player_id == hand.player_id))
+        .collect();
+
+    let decide = |show: bool| {
+        let connection = connection.clone();
+        let decided = decided.clone();
+        let room_id = props.room_id.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(ref connection) = connection {
+                connection.send.emit(ClientMessage::ShowdownDecision { room_id: room_id.clone(), show });
+            }
+            decided.set(true);
+        })
+    };
+
+    html! {
+        <div class="hand-result-banner">
+            <div class="hand-result-title">{format!("Hand #{}", summary.hand_number)}</div>
+            if summary.boards.is_empty() {
+                if !summary.board.is_empty() {
+                    <div class="hand-result-board-cards">{showdown_cards(&summary.board, "community-card")}</div>
+                }
+                <ul class="hand-result-lines">
+                    {for summary.winners.iter().enumerate().map(render_line)}
+                </ul>
+            } else {
+                {for summary.boards.iter().enumerate().map(|(index, board)| render_board(index, board))}
+            }
+            if !losers.is_empty() {
+                <ul class="showdown-losers">
+                    {for losers.into_iter().map(|hand| html! {
+                        <li>
+                            <span class="hand-result-player">{&hand.player_name}</span>
+                            <span class="showdown-hand">{showdown_cards(&hand.cards, "showdown-card")}</span>
+                        </li>
+                    })}
+                </ul>
+            }
+            if summary.hi_lo && !summary.low_qualified() {
+                <div class="hand-result-note">{"No qualifying low - high hand scoops"}</div>
+            }
+            if summary.hero_may_show(props.hero_id) && !*decided {
+                <div class="showdown-choice">
+                    <span>{"Everyone folded. Show your hand?"}</span>
+                    <button class="btn btn-secondary" onclick={decide(true)}>{"Show"}</button>
+                    <button class="btn btn-secondary" onclick={decide(false)}>{"Muck"}</button>
+                </div>
+            }
+        </div>
+    }
+}
+
+// Table Prompt Component - straddle and run-it-twice decisions
+#[derive(Properties, PartialEq)]
+pub struct TablePromptBarProps {
+    pub room_id: String,
+    pub prompt: TablePrompt,
+}
+
+#[function_component(TablePromptBar)]
+pub fn table_prompt_bar(props: &TablePromptBarProps) -> Html {
+    let connection = use_context::<ConnectionContext>();
+
+    let answer = |yes: bool| {
+        let connection = connection.clone();
+        let room_id = props.room_id.clone();
+        let message = match props.prompt {
+            TablePrompt::Straddle { .. } => ClientMessage::StraddleDecision { room_id, post: yes },
+            TablePrompt::RunItTwice { .. } => ClientMessage::RunItTwiceDecision { room_id, agree: yes },
+        };
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            if let Some(ref connection) = connection {
+                connection.send.emit(message.clone());
+            }
+        })
+    };
+
+    let (question, accept, decline, time_limit) = match props.prompt {
+        TablePrompt::Straddle { amount, time_limit } => {
+            (format!("Post a ${} straddle?", amount), "Straddle", "No thanks", time_limit)
+        }
+        TablePrompt::RunItTwice { time_limit } => {
+            ("All in - run it twice?".to_string(), "Run it twice", "Once", time_limit)
+        }
+    };
+
+    html! {
+        <div class="table-prompt">
+            <span class="table-prompt-question">{question}</span>
+            <span class="table-prompt-timer">{format!("{}s", time_limit)}</span>
+            <button class="btn btn-primary" onclick={answer(true)}>{accept}</button>
+            <button class="btn btn-secondary" onclick={answer(false)}>{decline}</button>
         </div>
     }
 }
 
+// Owner Controls Component - kick, pause and between-hand blind changes for the room creator
+#[derive(Properties, PartialEq)]
+pub struct OwnerControlsProps {
+    pub room_id: String,
+    pub state: GameState,
+    pub hero_id: Option<Uuid>,
+}
+
+#[function_component(OwnerControls)]
+pub fn owner_controls(props: &OwnerControlsProps) -> Html {
+    let connection = use_context::<ConnectionContext>();
+    let state = &props.state;
+    let (current_small, current_big) = state
+        .current_room
+        .as_ref()
+        .map_or((0, 0), |room| (room.small_blind, room.big_blind));
+    let blinds = use_state(|| (current_small, current_big));
+    let expanded = use_state(|| false);
+
+    let send = |message: ClientMessage| {
+        let connection = connection.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(ref connection) = connection {
+                connection.send.emit(message.clone());
+            }
+        })
+    };
+    let on_toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_: MouseEvent| expanded.set(!*expanded))
+    };
+    let blind_input = |big: bool| {
+        let blinds = blinds.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<i64>() {
+                let (small, big_blind) = *blinds;
+                blinds.set(if big { (small, value) } else { (value, big_blind) });
+            }
+        })
+    };
+
+    let (small_blind, big_blind) = *blinds;
+    let blinds_valid = small_blind > 0 && small_blind < big_blind && (small_blind, big_blind) != (current_small, current_big);
+
+    html! {
+        <div class="owner-controls">
+            <button class="btn btn-secondary" onclick={on_toggle}>{"Host controls"}</button>
+            if state.paused {
+                <span class="owner-paused">{"Paused"}</span>
+            }
+            if *expanded {
+                <div class="owner-controls-panel">
+                    <button
+                        class="btn btn-secondary"
+                        onclick={send(ClientMessage::SetPaused { room_id: props.room_id.clone(), paused: !state.paused })}
+                    >
+                        {if state.paused { "Resume game" } else { "Pause game" }}
+                    </button>
+                    <div class="owner-blinds">
+                        <input type="number" min="1" value={small_blind.to_string()} onchange={blind_input(false)} />
+                        <span>{"/"}</span>
+                        <input type="number" min="2" value={big_blind.to_string()} onchange={blind_input(true)} />
+                        <button
+                            class="btn btn-primary"
+                            disabled={!blinds_valid}
+                            onclick={send(ClientMessage::ChangeBlinds { room_id: props.room_id.clone(), small_blind, big_blind })}
+                        >
+                            {"Change blinds"}
+                        </button>
+                    </div>
+                    if let Some((small, big)) = state.pending_blinds {
+                        <div class="owner-note">{format!("Blinds go to ${}/${} next hand", small, big)}</div>
+                    }
+                    <ul class="owner-players">
+                        {for state.get_seated_players().into_iter().filter(|player| Some(player.id) != props.hero_id).map(|player| html! {
+                            <li>
+                                <span>{&player.display_name}</span>
+                                <button
+                                    class="btn btn-danger"
+                                    onclick={send(ClientMessage::KickPlayer { room_id: props.room_id.clone(), player_id: player.id })}
+                                >
+                                    {"Kick"}
+                                </button>
+                            </li>
+                        })}
+                    </ul>
+                </div>
+            }
+        </div>
+    }
+}
+
+/// Places on the classic table for a room's seats, as (seat index, position) pairs: turned
+/// so the hero's seat is at the bottom, with the rest spread evenly clockwise from it.
+/// Occupied seats are placed first and take the nearest free place; the classic table has
+/// six, so at bigger tables the empty seats give way and players past six are left to the
+/// canvas view.
+fn classic_places(seats: usize, hero_seat: usize, occupied: &[usize]) -> Vec<(usize, u8)> {
+    let places = CLASSIC_SEAT_ORDER.len();
+    let turn = |seat: usize| (seat + seats - hero_seat) % seats;
+    let mut order: Vec<usize> = (0..seats).collect();
+    order.sort_by_key(|&seat| (!occupied.contains(&seat), turn(seat)));
+
+    let mut taken = [false; CLASSIC_SEAT_ORDER.len()];
+    let mut placed = Vec::new();
+    for seat in order {
+        let wanted = (turn(seat) * places + seats / 2) / seats;
+        if let Some(place) = (wanted..places).chain(0..wanted).find(|&place| !taken[place]) {
+            taken[place] = true;
+            placed.push((seat, CLASSIC_SEAT_ORDER[place]));
+        }
+    }
+    placed
+}
+
 // Main Game Page Component
 #[function_component(GamePage)]
 pub fn game_page(props: &GamePageProps) -> Html {
+    let connection = use_context::<ConnectionContext>();
+    let navigator = use_navigator();
+    let tables = use_context::<TablesContext>().map(|ctx| ctx.tables);
+    let seated = tables.as_ref().is_some_and(|tables| tables.contains(&props.room_id));
+    let kicked = !seated && tables.as_ref().is_some_and(|tables| tables.was_kicked(&props.room_id));
+    let other_tables = tables.as_ref().map_or(0, |tables| tables.len() - usize::from(seated));
+    let moved_to = tables
+        .as_ref()
+        .and_then(|tables| tables.moved_to(&props.room_id))
+        .map(str::to_string);
+    let state = tables
+        .as_ref()
+        .and_then(|tables| tables.get(&props.room_id))
+        .cloned()
+        .unwrap_or_default();
+    let game_type = state.game_type();
+    let rules = game_type.rules();
+    let stakes = match state.current_room {
+        Some(ref room) if rules.uses_bring_in => format!("Ante: ${} · Bring-in: ${}", room.ante, room.bring_in),
+        Some(ref room) if room.ante > 0 && room.button_ante => {
+            format!("Blinds: ${}/${} · Button ante: ${}", room.small_blind, room.big_blind, room.ante)
+        }
+        Some(ref room) if room.ante > 0 => format!("Blinds: ${}/${} · Ante: ${}", room.small_blind, room.big_blind, room.ante),
+        Some(ref room) => format!("Blinds: ${}/${}", room.small_blind, room.big_blind),
+        None => "Blinds: $25/$50".to_string(),
+    };
+    // Only the player opening third street may bring in; after that there's a bet to face
+    let bring_in = state
+        .current_room
+        .as_ref()
+        .filter(|room| rules.uses_bring_in && room.bring_in > 0 && state.game_phase == GamePhase::ThirdStreet && state.current_bet == 0)
+        .map(|room| room.bring_in);
+    let hero_id = tables.as_ref().and_then(|tables| tables.hero_id());
+    let betting = match state.current_room {
+        Some(_) => state.betting_context(hero_id),
+        // Mock spot for Phase 1 testing, matching the placeholder table
+        None => BettingContext {
+            pot: 200,
+            current_bet: 100,
+            player_bet: 0,
+            player_stack: 5000,
+            big_blind: 50,
+            last_raise: 50,
+            raises: 1,
+            big_bet_street: false,
+        },
+    };
+    // Only the cards dealt so far this hand; the placeholder table shows a full hand
+    let dealt = match state.current_room {
+        Some(_) => rules.cards_dealt_by(&state.game_phase),
+        None => rules.hole_cards,
+    };
+    let hero_cards: Vec<Card> = MOCK_HERO_CARDS.iter().copied().take(dealt).collect();
+    let face_down = vec![Card::Unknown; dealt];
+
+    // Between hands the last showdown stays up, as on the canvas table
+    let showdown = state.last_hand.as_ref().filter(|_| !state.is_in_hand());
+    let table_cards = |player: &PlayerInfo| -> Vec<Card> {
+        if state.is_in_hand() {
+            match (player.is_folded, player.hand.is_empty()) {
+                (true, _) => Vec::new(),
+                (false, true) => vec![Card::Unknown; dealt],
+                (false, false) => player.hand.clone(),
+            }
+        } else {
+            showdown.and_then(|summary| summary.shown_cards(player.id)).map(<[Card]>::to_vec).unwrap_or_default()
+        }
+    };
+    let winning_cards = |cards: &[Card]| -> Vec<Card> {
+        cards.iter().copied().filter(|card| showdown.is_some_and(|summary| summary.is_winning_card(card))).collect()
+    };
+    let (board, pot) = match state.current_room {
+        Some(_) => (showdown.map_or(&state.community_cards, |summary| &summary.board).clone(), state.pot),
+        None => (MOCK_BOARD.to_vec(), 200),
+    };
+    let board_winners = winning_cards(&board);
+
+    let live_seats = {
+        let seats = state.current_room.as_ref().map_or(CLASSIC_SEAT_ORDER.len(), |room| usize::from(room.max_players)).max(2);
+        let seat_index = |seat_position: u8| usize::from(seat_position.saturating_sub(1)) % seats;
+        let hero_seat = hero_id
+            .and_then(|id| state.players.get(&id))
+            .map_or(0, |hero| seat_index(hero.seat_position));
+        let occupied: Vec<usize> = state.players.values().map(|player| seat_index(player.seat_position)).collect();
+        let chips = |amount: i64| u32::try_from(amount.max(0)).unwrap_or(u32::MAX);
+
+        classic_places(seats, hero_seat, &occupied)
+            .into_iter()
+            .map(|(seat, position)| match state.players.values().find(|player| seat_index(player.seat_position) == seat) {
+                Some(hero) if hero_id == Some(hero.id) => {
+                    let cards = table_cards(hero);
+                    let winners = winning_cards(&cards);
+                    html! {
+                        <div class={classes!("player-seat", format!("position-{}", position))}>
+                            <div class="player-info">
+                                <div class="player-name">{"You"}</div>
+                                <div class="player-chips">{format!("${}", hero.chips)}</div>
+                                if hero.current_bet > 0 {
+                                    <div class="current-bet"><StackedChips amount={hero.current_bet} /></div>
+                                }
+                            </div>
+                            <div class="player-cards">
+                                {for cards.iter().enumerate().map(|(index, card)| {
+                                    let winning = winners.contains(card);
+                                    card_view(card, classes!("hand-card", rules.card_face_up(index).then_some("up-card"), winning.then_some("winning-card")))
+                                })}
+                            </div>
+                        </div>
+                    }
+                }
+                Some(player) => {
+                    let cards = table_cards(player);
+                    html! {
+                        <PlayerSeat
+                            game_type={game_type.clone()}
+                            position={position}
+                            player_name={Some(player.display_name.clone())}
+                            chips={Some(chips(player.chips))}
+                            is_active={state.active_player == Some(player.id)}
+                            is_dealer={player.is_dealer || state.dealer_position == Some(player.seat_position)}
+                            current_bet={(player.current_bet > 0).then(|| chips(player.current_bet))}
+                            winning_cards={winning_cards(&cards)}
+                            cards={cards}
+                        />
+                    }
+                }
+                None => html! {
+                    <PlayerSeat game_type={game_type.clone()} position={position} player_name={Option::<String>::None} chips={Option::<u32>::None} is_active={false} is_dealer={false} current_bet={Option::<u32>::None} />
+                },
+            })
+            .collect::<Html>()
+    };
+
+    let lobby = use_context::<LobbyState>();
+    let join_error = lobby.as_ref().and_then(|lobby| lobby.join_error(&props.room_id));
+    let invite_link = lobby
+        .as_ref()
+        .and_then(|lobby| lobby.invite_code(&props.room_id))
+        .map(|code| {
+            let origin = gloo_utils::window().location().origin().unwrap_or_default();
+            format!("{}/invite/{}/{}", origin, props.room_id, code)
+        });
+    let is_private = state.current_room.as_ref().is_some_and(|room| room.is_private);
+    let is_owner = state.is_owner(hero_id);
+    let table_view = use_state(TableView::load);
+
+    // Opening a table adds it to the player's set rather than replacing the current one.
+    // If the server balanced us onto another tournament table, follow it instead of rejoining.
+    // We join once per room opened, so losing the seat (say, to the host) doesn't rejoin.
+    let join_attempted = use_mut_ref(|| None::<String>);
+    {
+        let connection = connection.clone();
+        let navigator = navigator.clone();
+        use_effect_with(
+            (props.room_id.clone(), seated, moved_to, props.invite_code.clone()),
+            move |(room_id, seated, moved_to, invite_code)| {
+                if let Some(new_room_id) = moved_to {
+                    if let Some(navigator) = navigator {
+                        navigator.replace(&AppRoute::Game { room_id: new_room_id.clone() });
+                    }
+                } else if !*seated && join_attempted.borrow().as_ref() != Some(room_id) {
+                    *join_attempted.borrow_mut() = Some(room_id.clone());
+                    if let Some(connection) = connection {
+                        connection.send.emit(ClientMessage::JoinRoom {
+                            room_id: room_id.clone(),
+                            password: room_password(room_id),
+                            invite_code: invite_code.clone(),
+                        });
+                    }
+                } else if invite_code.is_some() {
+                    // The invite code is spent once we're in; keep it out of history
+                    if let Some(navigator) = navigator {
+                        navigator.replace(&AppRoute::Game { room_id: room_id.clone() });
+                    }
+                }
+            },
+        );
+    }
+
+    let send = {
+        let connection = connection.clone();
+        move |message: ClientMessage| {
+            if let Some(ref connection) = connection {
+                connection.send.emit(message);
+            }
+        }
+    };
+
+    if kicked {
+        return html! {
+            <div class="game-page join-failed">
+                <div class="error-notification">
+                    <span class="error-text">{"You were removed from this table by the host."}</span>
+                </div>
+                <Link<AppRoute> to={AppRoute::Lobby} classes="btn btn-primary">{"Back to lobby"}</Link<AppRoute>>
+            </div>
+        };
+    }
+
+    if let Some(error) = join_error.filter(|_| !seated) {
+        let back_to_lobby = {
+            let navigator = navigator.clone();
+            Callback::from(move |_| {
+                if let Some(ref navigator) = navigator {
+                    navigator.push(&AppRoute::Lobby);
+                }
+            })
+        };
+        if !error.needs_password() {
+            return html! {
+                <div class="game-page join-failed">
+                    <div class="error-notification">
+                        <span class="error-text">{error.to_string()}</span>
+                    </div>
+                    <Link<AppRoute> to={AppRoute::Lobby} classes="btn btn-primary">{"Back to lobby"}</Link<AppRoute>>
+                </div>
+            };
+        }
+
+        let on_submit = {
+            let room_id = props.room_id.clone();
+            let send = send.clone();
+            Callback::from(move |password: String| {
+                remember_room_password(&room_id, &password);
+                send(ClientMessage::JoinRoom { room_id: room_id.clone(), password: Some(password), invite_code: None });
+            })
+        };
+        // The first refusal just means we hadn't given a password yet
+        let message = (error != JoinRoomError::PasswordRequired).then(|| error.to_string());
+        return html! {
+            <div class="game-page join-failed">
+                <RoomPasswordModal
+                    room_name={format!("Room {}", props.room_id)}
+                    error={message}
+                    on_submit={on_submit}
+                    on_cancel={back_to_lobby}
+                />
+            </div>
+        };
+    }
+
+    let on_create_invite = {
+        let room_id = props.room_id.clone();
+        let send = send.clone();
+        Callback::from(move |_: MouseEvent| send(ClientMessage::CreateInvite { room_id: room_id.clone() }))
+    };
+    let on_toggle_view = {
+        let table_view = table_view.clone();
+        Callback::from(move |_: MouseEvent| {
+            let next = match *table_view {
+                TableView::Dom => TableView::Canvas,
+                TableView::Canvas => TableView::Dom,
+            };
+            next.save();
+            table_view.set(next);
+        })
+    };
+    let on_select_link = Callback::from(|e: FocusEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        input.select();
+    });
+
     html! {
         <div class="game-page" style="
-            background: linear-gradient(135deg, #0f172a 0%, #1e293b 25%, #0f5132 75%, #134e4a 100%);
+            background: var(--game-background);
             min-height: 100vh;
             color: white;
             display: flex;
             flex-direction: column;
         ">
             <div class="game-header" style="
-                background: rgba(15, 23, 42, 0.95);
+                background: var(--game-header);
                 backdrop-filter: blur(20px);
-                border-bottom: 2px solid rgba(251, 191, 36, 0.3);
+                border-bottom: 2px solid color-mix(in srgb, var(--game-accent) 30%, transparent);
                 padding: 1rem 2rem;
                 display: flex;
                 justify-content: space-between;
                 align-items: center;
             ">
                 <h1 class="room-title" style="
-                    color: #fbbf24;
+                    color: var(--game-accent);
                     font-size: 1.8rem;
                     font-weight: 700;
                     margin: 0;
-                ">{format!("Texas Hold'em - Room {}", props.room_id)}</h1>
+                ">{format!("{} - Room {}", rules.name, props.room_id)}</h1>
                 <div class="game-info" style="display: flex; gap: 2rem; align-items: center;">
-                    <span class="blinds" style="color: #cbd5e1; font-weight: 600;">{"Blinds: $25/$50"}</span>
-                    <span class="pot-size" style="color: #cbd5e1; font-weight: 600;">{"Pot: $200"}</span>
+                    if let Some(clock) = state.blind_clock.clone() {
+                        <BlindLevelClock clock={clock} />
+                    }
+                    <span class="blinds" style="color: var(--game-muted); font-weight: 600;">{stakes}</span>
+                    <span class="pot-size" style="color: var(--game-muted); font-weight: 600;">{format!("Pot: ${}", pot)}</span>
+                    if is_owner {
+                        <OwnerControls room_id={props.room_id.clone()} state={state.clone()} hero_id={hero_id} />
+                    }
+                    if is_private {
+                        if let Some(link) = invite_link {
+                            <input class="invite-link" type="text" readonly={true} value={link} onfocus={on_select_link} title="One-time invite link" />
+                        } else {
+                            <button class="btn btn-secondary" onclick={on_create_invite}>{"Invite"}</button>
+                        }
+                    }
+                    <button class="btn btn-secondary table-view-toggle" onclick={on_toggle_view}>
+                        {match *table_view {
+                            TableView::Dom => "Canvas table",
+                            TableView::Canvas => "Classic table",
+                        }}
+                    </button>
+                    if other_tables > 0 {
+                        <Link<AppRoute> to={AppRoute::Tables} classes="btn btn-secondary">
+                            {format!("All tables ({})", other_tables + 1)}
+                        </Link<AppRoute>>
+                    }
                 </div>
             </div>
 
@@ -376,74 +1433,129 @@ pub fn game_page(props: &GamePageProps) -> Html {
                 padding: 2rem;
                 position: relative;
             ">
-                <div class="poker-table" style="
-                    width: 800px;
-                    height: 500px;
-                    background: radial-gradient(ellipse 400px 250px at center, rgba(16, 185, 129, 0.1) 0%, rgba(21, 128, 61, 0.05) 100%);
-                    border: 3px solid #10b981;
-                    border-radius: 50%;
-                    position: relative;
-                    display: flex;
-                    align-items: center;
-                    justify-content: center;
-                    box-shadow: 
-                        0 0 50px rgba(16, 185, 129, 0.3),
-                        inset 0 0 30px rgba(0, 0, 0, 0.3);
-                ">
-                    // Player seats positioned around the table
-                    <PlayerSeat 
-                        position={1} 
-                        player_name={Some("Alice".to_string())} 
-                        chips={Some(1500)} 
-                        is_active={false} 
-                        is_dealer={true}
-                        current_bet={Some(50)}
-                    />
-                    <PlayerSeat 
-                        position={2} 
-                        player_name={Some("Bob".to_string())} 
-                        chips={Some(800)} 
-                        is_active={true} 
-                        is_dealer={false}
-                        current_bet={Some(100)}
-                    />
-                    <PlayerSeat 
-                        position={3} 
-                        player_name={Some("Charlie".to_string())} 
-                        chips={Some(2200)} 
-                        is_active={false} 
-                        is_dealer={false}
-                        current_bet={None}
-                    />
-                    <PlayerSeat position={4} player_name={Option::<String>::None} chips={Option::<u32>::None} is_active={false} is_dealer={false} current_bet={Option::<u32>::None} />
-                    <PlayerSeat position={5} player_name={Option::<String>::None} chips={Option::<u32>::None} is_active={false} is_dealer={false} current_bet={Option::<u32>::None} />
-                    
-                    // Player's own seat (position 6)
-                    <div class="player-seat position-6">
-                        <div class="player-info">
-                            <div class="player-name">{"You"}</div>
-                            <div class="player-chips">{"$5000"}</div>
-                        </div>
-                        <div class="player-cards">
-                            <PlayingCard suit="♠" rank="A" face_up={true} classes={classes!("hand-card")} />
-                            <PlayingCard suit="♥" rank="K" face_up={true} classes={classes!("hand-card")} />
-                        </div>
-                    </div>
+                if *table_view == TableView::Canvas {
+                    <CanvasTable state={state.clone()} hero_id={hero_id} />
+                } else {
+                    <div class="poker-table" style="
+                        width: 800px;
+                        height: 500px;
+                        background:
+                            radial-gradient(ellipse at center, transparent 45%, rgba(0, 0, 0, 0.35) 100%),
+                            var(--table-felt-texture),
+                            var(--table-felt);
+                        border: 3px solid var(--table-border);
+                        border-radius: 50%;
+                        position: relative;
+                        display: flex;
+                        align-items: center;
+                        justify-content: center;
+                        box-shadow: 
+                            0 0 50px var(--table-glow),
+                            inset 0 0 30px rgba(0, 0, 0, 0.3);
+                    ">
+                        if state.current_room.is_some() {
+                            // Seats around the table from the hero's, showing the last showdown between hands
+                            {live_seats}
+                        } else {
+                            // Placeholder seats until the room loads
+                            <PlayerSeat 
+                                game_type={game_type.clone()}
+                                position={1} 
+                                player_name={Some("Alice".to_string())} 
+                                chips={Some(1500)} 
+                                is_active={false} 
+                                is_dealer={true}
+                                current_bet={Some(50)}
+                                cards={face_down.clone()}
+                            />
+                            <PlayerSeat 
+                                game_type={game_type.clone()}
+                                position={2} 
+                                player_name={Some("Bob".to_string())} 
+                                chips={Some(800)} 
+                                is_active={true} 
+                                is_dealer={false}
+                                current_bet={Some(100)}
+                                cards={face_down.clone()}
+                            />
+                            <PlayerSeat 
+                                game_type={game_type.clone()}
+                                position={3} 
+                                player_name={Some("Charlie".to_string())} 
+                                chips={Some(2200)} 
+                                is_active={false} 
+                                is_dealer={false}
+                                current_bet={None}
+                                cards={face_down}
+                            />
+                            <PlayerSeat game_type={game_type.clone()} position={4} player_name={Option::<String>::None} chips={Option::<u32>::None} is_active={false} is_dealer={false} current_bet={Option::<u32>::None} />
+                            <PlayerSeat game_type={game_type.clone()} position={5} player_name={Option::<String>::None} chips={Option::<u32>::None} is_active={false} is_dealer={false} current_bet={Option::<u32>::None} />
+
+                            // Player's own seat (position 6)
+                            <div class="player-seat position-6">
+                                <div class="player-info">
+                                    <div class="player-name">{"You"}</div>
+                                    <div class="player-chips">{"$5000"}</div>
+                                </div>
+                                <div class="player-cards">
+                                    // Our own down cards are visible to us; up cards are marked as shown to the table
+                                    {for hero_cards.iter().enumerate().map(|(index, card)| {
+                                        card_view(card, classes!("hand-card", rules.card_face_up(index).then_some("up-card")))
+                                    })}
+                                </div>
+                            </div>
+                        }
 
-                    // Center area with community cards and pot
-                    <div class="table-center">
-                        <CommunityCards />
-                        <div class="pot-display">
-                            <div class="pot-label">{"Total Pot"}</div>
-                            <div class="pot-amount">{"$200"}</div>
+                        // Center area with community cards and pot
+                        <div class="table-center">
+                            if state.paused {
+                                <div class="table-paused">{"Game paused by the host"}</div>
+                            }
+                            if !state.second_board.is_empty() {
+                                <div class="run-it-twice-boards">
+                                    {for [&state.community_cards, &state.second_board].into_iter().enumerate().map(|(index, board)| html! {
+                                        <div class="board-row">
+                                            <span class="board-label">{format!("Board {}", index + 1)}</span>
+                                            {for board.iter().map(|card| card_view(card, classes!("community-card")))}
+                                        </div>
+                                    })}
+                                </div>
+                            } else if rules.community_cards > 0 {
+                                <CommunityCards cards={board} winning_cards={board_winners} />
+                            }
+                            if pot > 0 {
+                                <div class="pot-display">
+                                    <div class="pot-label">{"Total Pot"}</div>
+                                    <div class="pot-amount"><StackedChips amount={pot} /></div>
+                                </div>
+                            }
                         </div>
                     </div>
-                </div>
+                }
             </div>
 
             // Game actions at the bottom
             <div class="player-interface">
-                <GameActions />
+                if let Some(prompt) = state.prompt.clone() {
+                    <TablePromptBar room_id={props.room_id.clone()} prompt={prompt} />
+                }
+                if let Some(summary) = state.last_hand.as_ref().filter(|_| !state.is_in_hand()) {
+                    <HandResultBanner
+                        key={summary.hand_number}
+                        room_id={props.room_id.clone()}
+                        summary={summary.clone()}
+                        hero_id={hero_id}
+                    />
+                }
+                // Only on our turn, as on the table tiles; the placeholder table keeps its mock spot
+                if state.action_required || state.current_room.is_none() {
+                    <GameActions
+                        room_id={props.room_id.clone()}
+                        bring_in={bring_in}
+                        structure={state.betting_structure()}
+                        betting={betting}
+                    />
+                }
             </div>
         </div>
     }
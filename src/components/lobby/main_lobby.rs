@@ -6,13 +6,21 @@ use wasm_bindgen::JsCast;
 use chrono::Utc;
 use uuid::Uuid;
 
-use crate::types::{User, GameRoom, RoomFilter, GameType, AppRoute, PlayerStats, PlayerStatus};
-use super::{RoomFilters, RoomCard, PlayerSidebar, PlayerProfileModal};
+use crate::types::{User, GameRoom, RoomConfig, RoomFilter, RoomSort, RoomSortKey, GameType, BettingStructure, AppRoute, PlayerStats, PlayerStatus, ClientMessage, QuickJoinSettings, SavedRoom, NotificationMessage};
+use crate::lobby::{remember_room_password, room_password, user_storage_key, LobbyState, RoomHistoryContext};
+use crate::quick_join::rank_rooms;
+use crate::services::websocket_service::ConnectionContext;
+use crate::services::notification_service::NotificationContext;
+use super::{RoomFilters, RoomCard, TournamentCard, PlayerSidebar, PlayerProfileModal, RoomPasswordModal, QuickJoinSettingsPanel};
+
+const SORT_KEY: &str = "primo_poker_room_sort";
+const QUICK_JOIN_KEY: &str = "primo_poker_quick_join";
 
 #[derive(Clone, Default)]
 pub struct CreateRoomForm {
     pub name: String,
     pub game_type: GameType,
+    pub betting_structure: BettingStructure,
     pub max_players: u8,
     pub small_blind: i64,
     pub big_blind: i64,
@@ -20,12 +28,50 @@ pub struct CreateRoomForm {
     pub max_buy_in: i64,
     pub is_private: bool,
     pub password: String,
+    pub ante: i64,
+    pub button_ante: bool,
+    pub straddle_allowed: bool,
+    pub run_it_twice: bool,
+}
+
+impl CreateRoomForm {
+    pub fn to_room_config(&self) -> RoomConfig {
+        RoomConfig {
+            name: self.name.trim().to_string(),
+            game_type: self.game_type.clone(),
+            betting_structure: self.betting_structure,
+            max_players: self.max_players,
+            small_blind: self.small_blind,
+            big_blind: self.big_blind,
+            min_buy_in: self.min_buy_in,
+            max_buy_in: self.max_buy_in,
+            is_private: self.is_private,
+            password: self.is_private.then(|| self.password.clone()),
+            ante: self.ante,
+            button_ante: self.ante > 0 && self.button_ante,
+            straddle_allowed: self.straddle_allowed,
+            run_it_twice: self.run_it_twice,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LobbyTab {
+    CashGames,
+    Favorites,
+    Recent,
+    Tournaments,
 }
 
 pub struct LobbyPage {
     user: Option<User>,
-    available_rooms: Vec<GameRoom>,
-    filtered_rooms: Vec<GameRoom>,
+    active_tab: LobbyTab,
+    lobby_state: LobbyState,
+    connection: Option<ConnectionContext>,
+    _lobby_handle: Option<ContextHandle<LobbyState>>,
+    _connection_handle: Option<ContextHandle<ConnectionContext>>,
+    filtered_rooms: Vec<GameRoom>, // lobby_state.rooms after filters, then sorted
+    room_sort: RoomSort,
     loading: bool,
     websocket_connected: bool,
     filter_criteria: RoomFilter,
@@ -38,12 +84,15 @@ pub struct LobbyPage {
     selected_player: Option<User>,
     selected_player_stats: Option<PlayerStats>,
     is_selected_player_friend: bool,
+    password_prompt: Option<GameRoom>, // private room waiting on its password
+    awaiting_created_room: bool,       // CreateRoom sent from this page's modal
+    quick_join_settings: QuickJoinSettings,
+    show_quick_join_settings: bool,
 }
 
 pub enum LobbyMsg {
     LoadUserData,
     UserDataLoaded(User),
-    RoomsUpdated(Vec<GameRoom>),
     WebSocketConnected,
     WebSocketDisconnected,
     UpdateFilter(RoomFilter),
@@ -53,13 +102,22 @@ pub enum LobbyMsg {
     UpdateFilterPlayers(Option<u8>),
     ToggleEmptyRooms,
     ToggleFullRooms,
+    SortRooms(RoomSortKey),
     JoinRoom(String),
+    UnlockRoom(String), // password for the prompted private room
+    CancelPasswordPrompt,
     ShowCreateRoomModal,
     HideCreateRoomModal,
     UpdateCreateRoomForm(String, String), // field_name, value
     CreateRoom,
     RefreshRooms,
     QuickJoin,
+    ShowQuickJoinSettings,
+    HideQuickJoinSettings,
+    UpdateQuickJoinSettings(QuickJoinSettings),
+    JoinWaitlist(String),
+    LeaveWaitlist(String),
+    ToggleFavorite(String),
     Error(String),
     ClearError,
     ToggleSidebar,
@@ -71,6 +129,12 @@ pub enum LobbyMsg {
     RemoveFriend(String),
     SendMessage(String),
     InviteToGame(String),
+    // Tournament messages
+    SelectTab(LobbyTab),
+    LobbyStateUpdated(Box<LobbyState>), // boxed: the full lobby snapshot dwarfs every other message
+    ConnectionUpdated(ConnectionContext),
+    RegisterTournament(String),
+    UnregisterTournament(String),
 }
 
 impl Component for LobbyPage {
@@ -87,56 +151,30 @@ impl Component for LobbyPage {
         // Note: Removed immediate redirect to allow lobby to load even without user data
         // The app component handles authentication navigation
 
-        // Create mock room data for testing
-        let mock_rooms = vec![
-            GameRoom {
-                id: "room1".to_string(),
-                name: "High Stakes Hold'em".to_string(), 
-                game_type: GameType::TexasHoldem,
-                small_blind: 25,
-                big_blind: 50,
-                min_buy_in: 1000,
-                max_buy_in: 10000,
-                current_players: 2,
-                max_players: 9,
-                is_private: false,
-                is_active: true,
-                created_at: Utc::now(),
-            },
-            GameRoom {
-                id: "room2".to_string(),
-                name: "Beginner Texas Hold'em".to_string(),
-                game_type: GameType::TexasHoldem,
-                small_blind: 1,
-                big_blind: 2,
-                min_buy_in: 50,
-                max_buy_in: 200,
-                current_players: 1,
-                max_players: 6,
-                is_private: false,
-                is_active: true,
-                created_at: Utc::now(),
-            },
-            GameRoom {
-                id: "room3".to_string(),
-                name: "Omaha Hi-Lo".to_string(),
-                game_type: GameType::Omaha,
-                small_blind: 10,
-                big_blind: 20,
-                min_buy_in: 400,
-                max_buy_in: 2000,
-                current_players: 3,
-                max_players: 8,
-                is_private: false,
-                is_active: true,
-                created_at: Utc::now(),
-            },
-        ];
+        // Tournament listings and registrations are kept by App and arrive over the socket
+        let (lobby_state, lobby_handle) = match link.context::<LobbyState>(link.callback(|state| LobbyMsg::LobbyStateUpdated(Box::new(state)))) {
+            Some((state, handle)) => (state, Some(handle)),
+            None => (LobbyState::default(), None),
+        };
+        let (connection, connection_handle) = match link.context::<ConnectionContext>(link.callback(LobbyMsg::ConnectionUpdated)) {
+            Some((connection, handle)) => (Some(connection), Some(handle)),
+            None => (None, None),
+        };
+
+        // Full list once on mount; the server streams deltas after that
+        if let Some(ref connection) = connection {
+            connection.send.emit(ClientMessage::GetRoomList);
+        }
 
-        Self {
+        let mut lobby = Self {
             user: None,
-            available_rooms: mock_rooms.clone(),
-            filtered_rooms: mock_rooms, // Initialize with mock data
+            active_tab: LobbyTab::CashGames,
+            lobby_state,
+            connection,
+            _lobby_handle: lobby_handle,
+            _connection_handle: connection_handle,
+            filtered_rooms: Vec::new(),
+            room_sort: RoomSort::default(),
             loading: true,
             websocket_connected: false,
             filter_criteria: RoomFilter {
@@ -144,14 +182,17 @@ impl Component for LobbyPage {
                 max_stakes: None,
                 max_players_filter: None,
                 game_types: vec![GameType::TexasHoldem], // Default filter
+                betting_structures: Vec::new(),
                 show_full_rooms: true,
                 show_empty_rooms: true,
+                search: String::new(),
             },
             show_create_room_modal: false,
             error_message: None,
             create_room_form: CreateRoomForm {
                 name: String::new(),
                 game_type: GameType::TexasHoldem,
+                betting_structure: BettingStructure::NoLimit,
                 max_players: 6,
                 small_blind: 10,
                 big_blind: 20,
@@ -159,6 +200,10 @@ impl Component for LobbyPage {
                 max_buy_in: 10000,
                 is_private: false,
                 password: String::new(),
+                ante: 0,
+                button_ante: false,
+                straddle_allowed: false,
+                run_it_twice: false,
             },
             sidebar_collapsed: false,
             // Player Profile Modal state
@@ -166,7 +211,13 @@ impl Component for LobbyPage {
             selected_player: None,
             selected_player_stats: None,
             is_selected_player_friend: false,
-        }
+            password_prompt: None,
+            awaiting_created_room: false,
+            quick_join_settings: QuickJoinSettings::default(),
+            show_quick_join_settings: false,
+        };
+        lobby.apply_filters();
+        lobby
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -178,18 +229,24 @@ impl Component for LobbyPage {
                 false
             }
             LobbyMsg::UserDataLoaded(user) => {
+                if let Ok(sort) = LocalStorage::get::<RoomSort>(user_storage_key(SORT_KEY, Some(user.id))) {
+                    self.room_sort = sort;
+                    self.apply_filters();
+                }
+                self.quick_join_settings = LocalStorage::get(user_storage_key(QUICK_JOIN_KEY, Some(user.id))).unwrap_or_default();
                 self.user = Some(user);
                 self.loading = false;
-                
-                // Load mock room data for development
-                self.load_mock_rooms();
-                
-                // Connect to lobby WebSocket for real-time updates
-                // self.websocket_service.connect_lobby(); // TODO: Implement
                 true
             }
-            LobbyMsg::RoomsUpdated(rooms) => {
-                self.available_rooms = rooms;
+            LobbyMsg::SortRooms(key) => {
+                // Clicking the active column flips direction; a new column starts highest first
+                self.room_sort = if self.room_sort.key == key {
+                    RoomSort { key, descending: !self.room_sort.descending }
+                } else {
+                    RoomSort { key, descending: true }
+                };
+                let user_id = self.user.as_ref().map(|user| user.id);
+                let _ = LocalStorage::set(user_storage_key(SORT_KEY, user_id), self.room_sort);
                 self.apply_filters();
                 true
             }
@@ -214,8 +271,10 @@ impl Component for LobbyPage {
                     max_stakes: None,
                     max_players_filter: None,
                     game_types: vec![GameType::TexasHoldem],
+                    betting_structures: Vec::new(),
                     show_full_rooms: true,
                     show_empty_rooms: true,
+                    search: String::new(),
                 };
                 self.apply_filters();
                 true
@@ -247,25 +306,44 @@ impl Component for LobbyPage {
                 true
             }
             LobbyMsg::JoinRoom(room_id) => {
-                // Navigate to game room
-                let navigator = ctx.link().navigator().unwrap();
-                navigator.push(&AppRoute::Game { room_id });
+                // Private rooms need a password unless we've already unlocked them this session
+                let locked = self
+                    .lobby_state
+                    .rooms
+                    .iter()
+                    .find(|room| room.id == room_id && room.is_private)
+                    .filter(|room| room_password(&room.id).is_none());
+                if let Some(room) = locked {
+                    self.password_prompt = Some(room.clone());
+                    return true;
+                }
+
+                self.record_join(ctx, &room_id);
+                self.enter_room(ctx, room_id);
                 false
             }
+            LobbyMsg::UnlockRoom(password) => {
+                // The game page sends the remembered password with its JoinRoom
+                if let Some(room) = self.password_prompt.take() {
+                    remember_room_password(&room.id, &password);
+                    self.record_join(ctx, &room.id);
+                    self.enter_room(ctx, room.id);
+                }
+                true
+            }
+            LobbyMsg::CancelPasswordPrompt => {
+                self.password_prompt = None;
+                true
+            }
             LobbyMsg::ShowCreateRoomModal => {
                 self.show_create_room_modal = true;
                 true
             }
             LobbyMsg::HideCreateRoomModal => {
                 self.show_create_room_modal = false;
+                self.awaiting_created_room = false;
                 // Reset form when closing
-                self.create_room_form = CreateRoomForm::default();
-                self.create_room_form.game_type = GameType::TexasHoldem;
-                self.create_room_form.max_players = 6;
-                self.create_room_form.small_blind = 10;
-                self.create_room_form.big_blind = 20;
-                self.create_room_form.min_buy_in = 1000;
-                self.create_room_form.max_buy_in = 10000;
+                self.reset_create_room_form();
                 true
             }
             LobbyMsg::UpdateCreateRoomForm(field, value) => {
@@ -275,10 +353,16 @@ impl Component for LobbyPage {
                         self.create_room_form.game_type = match value.as_str() {
                             "texas_holdem" => GameType::TexasHoldem,
                             "omaha" => GameType::Omaha,
+                            "omaha_hi_lo" => GameType::OmahaHiLo,
                             "seven_card_stud" => GameType::SevenCardStud,
                             _ => GameType::TexasHoldem,
                         };
                     }
+                    "betting_structure" => {
+                        if let Some(structure) = BettingStructure::from_key(&value) {
+                            self.create_room_form.betting_structure = structure;
+                        }
+                    }
                     "max_players" => {
                         if let Ok(players) = value.parse::<u8>() {
                             self.create_room_form.max_players = players;
@@ -308,11 +392,29 @@ impl Component for LobbyPage {
                         self.create_room_form.is_private = value == "true";
                     }
                     "password" => self.create_room_form.password = value,
+                    "ante" => {
+                        if let Ok(ante) = value.parse::<i64>() {
+                            self.create_room_form.ante = ante.max(0);
+                        }
+                    }
+                    "button_ante" => {
+                        self.create_room_form.button_ante = value == "true";
+                    }
+                    "straddle_allowed" => {
+                        self.create_room_form.straddle_allowed = value == "true";
+                    }
+                    "run_it_twice" => {
+                        self.create_room_form.run_it_twice = value == "true";
+                    }
                     _ => {}
                 }
                 true
             }
             LobbyMsg::CreateRoom => {
+                if self.lobby_state.creating_room {
+                    return false;
+                }
+
                 // Validate form
                 if self.create_room_form.name.trim().is_empty() {
                     self.error_message = Some("Room name is required".to_string());
@@ -326,58 +428,87 @@ impl Component for LobbyPage {
                     self.error_message = Some("Max buy-in must be larger than min buy-in".to_string());
                     return true;
                 }
+                if self.create_room_form.ante >= self.create_room_form.big_blind {
+                    self.error_message = Some("Ante must be smaller than the big blind".to_string());
+                    return true;
+                }
 
-                // Create new room (mock implementation)
-                let new_room = GameRoom {
-                    id: format!("room_{}", chrono::Utc::now().timestamp()),
-                    name: self.create_room_form.name.clone(),
-                    game_type: self.create_room_form.game_type.clone(),
-                    current_players: 0,
-                    max_players: self.create_room_form.max_players,
-                    small_blind: self.create_room_form.small_blind,
-                    big_blind: self.create_room_form.big_blind,
-                    min_buy_in: self.create_room_form.min_buy_in,
-                    max_buy_in: self.create_room_form.max_buy_in,
-                    is_private: self.create_room_form.is_private,
-                    is_active: true,
-                    created_at: chrono::Utc::now(),
-                };
+                if self.create_room_form.is_private && self.create_room_form.password.trim().is_empty() {
+                    self.error_message = Some("Private rooms need a password".to_string());
+                    return true;
+                }
 
-                // Add to available rooms
-                self.available_rooms.push(new_room.clone());
-                self.apply_filters();
-                
-                // Close modal and reset form
-                self.show_create_room_modal = false;
-                self.create_room_form = CreateRoomForm::default();
-                self.create_room_form.game_type = GameType::TexasHoldem;
-                self.create_room_form.max_players = 6;
-                self.create_room_form.small_blind = 10;
-                self.create_room_form.big_blind = 20;
-                self.create_room_form.min_buy_in = 1000;
-                self.create_room_form.max_buy_in = 10000;
-
-                // Navigate to the newly created room
-                let navigator = ctx.link().navigator().unwrap();
-                navigator.push(&AppRoute::Game { room_id: new_room.id });
-                
+                // The modal stays open until the server confirms or rejects the room
+                self.error_message = None;
+                self.awaiting_created_room = true;
+                self.send(ClientMessage::CreateRoom { room_config: self.create_room_form.to_room_config() });
                 true
             }
             LobbyMsg::RefreshRooms => {
-                // Refresh room list (mock implementation)
-                self.load_mock_rooms();
-                true
+                self.send(ClientMessage::GetRoomList);
+                false
             }
             LobbyMsg::QuickJoin => {
-                // Find first available room
-                if let Some(room) = self.filtered_rooms.iter().find(|r| r.current_players < r.max_players) {
-                    let navigator = ctx.link().navigator().unwrap();
-                    navigator.push(&AppRoute::Game { room_id: room.id.clone() });
-                } else {
-                    self.error_message = Some("No available rooms for quick join".to_string());
+                // Best open table for the user's bankroll and preferences
+                let chips = self.user.as_ref().map_or(0, |user| user.chips);
+                let recent = self.lobby_state.recent_room_ids();
+                let best = rank_rooms(&self.filtered_rooms, chips, &self.quick_join_settings, &recent)
+                    .first()
+                    .map(|room| room.id.clone());
+                if let Some(room_id) = best {
+                    self.enter_room(ctx, room_id);
+                    return true;
+                }
+
+                // Every matching table is full - queue for the one with the shortest waitlist
+                let shortest_queue = self
+                    .filtered_rooms
+                    .iter()
+                    .filter(|r| !r.is_private && r.is_active && self.lobby_state.waitlist(&r.id).is_none())
+                    .filter(|r| !self.quick_join_settings.blocked_rooms.contains(&r.id))
+                    .min_by_key(|r| r.waitlist);
+                match shortest_queue {
+                    Some(room) => {
+                        self.send(ClientMessage::JoinWaitlist { room_id: room.id.clone() });
+                        let room_id = room.id.clone();
+                        self.notify(
+                            ctx,
+                            NotificationMessage::info(format!("No open seats - you're on the waitlist for {}", room.name))
+                                .with_key(format!("quick_join_waitlist_{}", room.id))
+                                .with_action("Leave waitlist", ctx.link().callback(move |_| LobbyMsg::LeaveWaitlist(room_id.clone()))),
+                        );
+                    }
+                    None => self.error_message = Some("No available rooms for quick join".to_string()),
                 }
                 true
             }
+            LobbyMsg::ShowQuickJoinSettings => {
+                self.show_quick_join_settings = true;
+                true
+            }
+            LobbyMsg::HideQuickJoinSettings => {
+                self.show_quick_join_settings = false;
+                true
+            }
+            LobbyMsg::UpdateQuickJoinSettings(settings) => {
+                let user_id = self.user.as_ref().map(|user| user.id);
+                let _ = LocalStorage::set(user_storage_key(QUICK_JOIN_KEY, user_id), &settings);
+                self.quick_join_settings = settings;
+                true
+            }
+            LobbyMsg::JoinWaitlist(room_id) => {
+                self.send(ClientMessage::JoinWaitlist { room_id });
+                false
+            }
+            LobbyMsg::LeaveWaitlist(room_id) => {
+                self.send(ClientMessage::LeaveWaitlist { room_id });
+                false
+            }
+            LobbyMsg::ToggleFavorite(room_id) => {
+                let favorite = !self.lobby_state.is_favorite(&room_id);
+                self.send(ClientMessage::SetFavoriteRoom { room_id, favorite });
+                false
+            }
             LobbyMsg::Error(error) => {
                 self.error_message = Some(error);
                 true
@@ -460,6 +591,61 @@ impl Component for LobbyPage {
                 web_sys::console::log_1(&format!("Inviting player to game: {}", player_id).into());
                 false
             }
+            LobbyMsg::SelectTab(tab) => {
+                if tab == LobbyTab::Tournaments && self.active_tab != tab {
+                    self.send(ClientMessage::GetTournamentList);
+                }
+                self.active_tab = tab;
+                true
+            }
+            LobbyMsg::LobbyStateUpdated(lobby_state) => {
+                let rooms_changed = self.lobby_state.rooms != lobby_state.rooms;
+                self.lobby_state = *lobby_state;
+                if rooms_changed {
+                    self.apply_filters();
+                }
+                if self.awaiting_created_room {
+                    if let Some(room_id) = self.lobby_state.created_room.clone() {
+                        // Close modal, reset form and go straight to the new room
+                        self.awaiting_created_room = false;
+                        self.show_create_room_modal = false;
+                        self.reset_create_room_form();
+                        let navigator = ctx.link().navigator().unwrap();
+                        navigator.push(&AppRoute::Game { room_id });
+                    } else if self.lobby_state.room_creation_error.is_some() {
+                        self.awaiting_created_room = false;
+                    }
+                }
+                true
+            }
+            LobbyMsg::ConnectionUpdated(connection) => {
+                self.connection = Some(connection);
+                false
+            }
+            LobbyMsg::RegisterTournament(tournament_id) => {
+                let Some(tournament) = self.lobby_state.tournament(&tournament_id) else {
+                    return false;
+                };
+                if !tournament.registration_open() {
+                    self.error_message = Some(format!("Registration for {} is closed", tournament.config.name));
+                    return true;
+                }
+                let chips = self.user.as_ref().map_or(0, |user| user.chips);
+                if chips < tournament.total_cost() {
+                    self.error_message = Some(format!(
+                        "You need ${} to enter {}",
+                        tournament.total_cost(),
+                        tournament.config.name
+                    ));
+                    return true;
+                }
+                self.send(ClientMessage::RegisterTournament { tournament_id });
+                false
+            }
+            LobbyMsg::UnregisterTournament(tournament_id) => {
+                self.send(ClientMessage::UnregisterTournament { tournament_id });
+                false
+            }
         }
     }
 
@@ -481,11 +667,20 @@ impl Component for LobbyPage {
         };
 
         let on_join_room = link.callback(|room_id: String| LobbyMsg::JoinRoom(room_id));
+        let on_join_waitlist = link.callback(LobbyMsg::JoinWaitlist);
+        let on_leave_waitlist = link.callback(LobbyMsg::LeaveWaitlist);
         let on_show_create_modal = link.callback(|_| LobbyMsg::ShowCreateRoomModal);
         let on_hide_create_modal = link.callback(|_| LobbyMsg::HideCreateRoomModal);
         let on_clear_error = link.callback(|_| LobbyMsg::ClearError);
         let on_refresh_rooms = link.callback(|_| LobbyMsg::RefreshRooms);
         let on_quick_join = link.callback(|_| LobbyMsg::QuickJoin);
+        let open_favorites = self
+            .lobby_state
+            .favorite_rooms
+            .iter()
+            .filter_map(|saved| self.lobby_state.room(&saved.room_id))
+            .filter(|room| room.is_active && room.current_players < room.max_players)
+            .count();
         let on_create_room = link.callback(|_| LobbyMsg::CreateRoom);
         
         // Filter callbacks
@@ -494,6 +689,7 @@ impl Component for LobbyPage {
             let game_type = match select.value().as_str() {
                 "texas_holdem" => GameType::TexasHoldem,
                 "omaha" => GameType::Omaha,
+                "omaha_hi_lo" => GameType::OmahaHiLo,
                 "seven_card_stud" => GameType::SevenCardStud,
                 _ => GameType::TexasHoldem,
             };
@@ -554,59 +750,142 @@ impl Component for LobbyPage {
                 // Main Content
                 <div class="lobby-main-container">
                     <div class="lobby-content">
-                        // Enhanced Filters Section
-                        <RoomFilters 
-                            current_filter={self.filter_criteria.clone()}
-                            on_filter_change={link.callback(LobbyMsg::UpdateFilter)}
-                            on_reset_filters={link.callback(|_| LobbyMsg::ResetFilters)}
-                        />
-
-                        // Action Buttons
-                        <div class="action-buttons">
-                            <button 
-                                class="create-room-btn primary"
-                                onclick={on_show_create_modal.clone()}
+                        <div class="lobby-tabs" role="tablist">
+                            <button
+                                role="tab"
+                                class={classes!("lobby-tab", (self.active_tab == LobbyTab::CashGames).then_some("active"))}
+                                aria-selected={(self.active_tab == LobbyTab::CashGames).to_string()}
+                                onclick={link.callback(|_| LobbyMsg::SelectTab(LobbyTab::CashGames))}
+                            >
+                                {"Cash Games"}
+                            </button>
+                            <button
+                                role="tab"
+                                class={classes!("lobby-tab", (self.active_tab == LobbyTab::Favorites).then_some("active"))}
+                                aria-selected={(self.active_tab == LobbyTab::Favorites).to_string()}
+                                onclick={link.callback(|_| LobbyMsg::SelectTab(LobbyTab::Favorites))}
+                            >
+                                {"⭐ Favorites"}
+                                if open_favorites > 0 {
+                                    <span class="tab-badge" title="Favorite tables with open seats">{open_favorites}</span>
+                                }
+                            </button>
+                            <button
+                                role="tab"
+                                class={classes!("lobby-tab", (self.active_tab == LobbyTab::Recent).then_some("active"))}
+                                aria-selected={(self.active_tab == LobbyTab::Recent).to_string()}
+                                onclick={link.callback(|_| LobbyMsg::SelectTab(LobbyTab::Recent))}
                             >
-                                {"🏠 Create Room"}
+                                {"🕒 Recent"}
                             </button>
-                            <button class="quick-join-btn secondary" onclick={on_quick_join}>
-                                {"⚡ Quick Join"}
+                            <button
+                                role="tab"
+                                class={classes!("lobby-tab", (self.active_tab == LobbyTab::Tournaments).then_some("active"))}
+                                aria-selected={(self.active_tab == LobbyTab::Tournaments).to_string()}
+                                onclick={link.callback(|_| LobbyMsg::SelectTab(LobbyTab::Tournaments))}
+                            >
+                                {"Tournaments"}
                             </button>
                         </div>
 
-                        // Room List
-                        <div class="rooms-section">
-                            <div class="rooms-header">
-                                <h3>{format!("Available Games ({})", self.filtered_rooms.len())}</h3>
-                                <button class="refresh-btn" onclick={on_refresh_rooms}>{"🔄 Refresh"}</button>
+                        if self.active_tab == LobbyTab::Tournaments {
+                            {self.render_tournaments(ctx)}
+                        } else if self.active_tab == LobbyTab::Favorites {
+                            {self.render_saved_rooms(ctx, &self.lobby_state.favorite_rooms, "Favorite Tables", "Star a table to keep it here")}
+                        } else if self.active_tab == LobbyTab::Recent {
+                            {self.render_saved_rooms(ctx, &self.lobby_state.recent_rooms, "Recently Played", "Tables you join will show up here")}
+                        } else {
+                            // Enhanced Filters Section
+                            <RoomFilters 
+                                user_id={Some(user.id)}
+                                current_filter={self.filter_criteria.clone()}
+                                on_filter_change={link.callback(LobbyMsg::UpdateFilter)}
+                                on_reset_filters={link.callback(|_| LobbyMsg::ResetFilters)}
+                            />
+
+                            // Action Buttons
+                            <div class="action-buttons">
+                                <button 
+                                    class="create-room-btn primary"
+                                    onclick={on_show_create_modal.clone()}
+                                >
+                                    {"🏠 Create Room"}
+                                </button>
+                                <button class="quick-join-btn secondary" onclick={on_quick_join}>
+                                    {"⚡ Quick Join"}
+                                </button>
+                                <button
+                                    class="quick-join-settings-btn"
+                                    title="Quick Join settings"
+                                    onclick={link.callback(|_| LobbyMsg::ShowQuickJoinSettings)}
+                                >
+                                    {"⚙"}
+                                </button>
                             </div>
-                            
-                            if self.filtered_rooms.is_empty() {
-                                <div class="no-rooms">
-                                    <div class="empty-state">
-                                        <h4>{"No games match your filters"}</h4>
-                                        <p>{"Try adjusting your filter criteria or create a new room"}</p>
-                                        <button 
-                                            class="create-room-btn primary"
-                                            onclick={on_show_create_modal}
-                                        >
-                                            {"Create New Room"}
-                                        </button>
-                                    </div>
+
+                            // Room List
+                            <div class="rooms-section">
+                                <div class="rooms-header">
+                                    <h3>{format!("Available Games ({})", self.filtered_rooms.len())}</h3>
+                                    <button class="refresh-btn" onclick={on_refresh_rooms} disabled={self.lobby_state.refreshing_rooms}>
+                                        {if self.lobby_state.refreshing_rooms { "🔄 Refreshing..." } else { "🔄 Refresh" }}
+                                    </button>
                                 </div>
-                            } else {
-                                <div class="rooms-grid">
-                                    { for self.filtered_rooms.iter().map(|room| {
+                                {self.render_waitlists(ctx)}
+                                <div class="room-sort-bar" role="group" aria-label="Sort rooms">
+                                    <span class="sort-label">{"Sort by"}</span>
+                                    {for RoomSortKey::ALL.iter().map(|key| {
+                                        let key = *key;
+                                        let active = self.room_sort.key == key;
+                                        let arrow = match (active, self.room_sort.descending) {
+                                            (false, _) => "",
+                                            (true, true) => " ▼",
+                                            (true, false) => " ▲",
+                                        };
                                         html! {
-                                            <RoomCard 
-                                                room={room.clone()}
-                                                on_join={on_join_room.clone()}
-                                            />
+                                            <button
+                                                class={classes!("sort-btn", active.then_some("active"))}
+                                                onclick={link.callback(move |_| LobbyMsg::SortRooms(key))}
+                                            >
+                                                {format!("{}{}", key.label(), arrow)}
+                                            </button>
                                         }
-                                    }) }
+                                    })}
                                 </div>
-                            }
-                        </div>
+                            
+                                if self.filtered_rooms.is_empty() {
+                                    <div class="no-rooms">
+                                        <div class="empty-state">
+                                            <h4>{"No games match your filters"}</h4>
+                                            <p>{"Try adjusting your filter criteria or create a new room"}</p>
+                                            <button 
+                                                class="create-room-btn primary"
+                                                onclick={on_show_create_modal}
+                                            >
+                                                {"Create New Room"}
+                                            </button>
+                                        </div>
+                                    </div>
+                                } else {
+                                    <div class="rooms-grid">
+                                        { for self.filtered_rooms.iter().map(|room| {
+                                            html! {
+                                                <RoomCard 
+                                                    key={room.id.clone()}
+                                                    room={room.clone()}
+                                                    on_join={on_join_room.clone()}
+                                                    waitlist={self.lobby_state.waitlist(&room.id).cloned()}
+                                                    on_join_waitlist={on_join_waitlist.clone()}
+                                                    on_leave_waitlist={on_leave_waitlist.clone()}
+                                                    favorite={self.lobby_state.is_favorite(&room.id)}
+                                                    on_toggle_favorite={link.callback(LobbyMsg::ToggleFavorite)}
+                                                />
+                                            }
+                                        }) }
+                                    </div>
+                                }
+                            </div>
+                        }
                     </div>
 
                     // Player Sidebar
@@ -659,6 +938,30 @@ impl Component for LobbyPage {
                                         <option value="omaha" selected={matches!(self.create_room_form.game_type, GameType::Omaha)}>
                                             {"Omaha"}
                                         </option>
+                                        <option value="omaha_hi_lo" selected={matches!(self.create_room_form.game_type, GameType::OmahaHiLo)}>
+                                            {"Omaha Hi-Lo"}
+                                        </option>
+                                        <option value="seven_card_stud" selected={matches!(self.create_room_form.game_type, GameType::SevenCardStud)}>
+                                            {"Seven Card Stud"}
+                                        </option>
+                                    </select>
+                                </div>
+                                <div class="form-group">
+                                    <label>{"Betting Structure"}</label>
+                                    <select 
+                                        onchange={
+                                            let on_form_input = on_form_input.clone();
+                                            Callback::from(move |e: Event| {
+                                                let select: HtmlInputElement = e.target_unchecked_into();
+                                                on_form_input.emit(("betting_structure".to_string(), select.value()))
+                                            })
+                                        }
+                                    >
+                                        {for BettingStructure::ALL.iter().map(|structure| html! {
+                                            <option value={structure.key()} selected={self.create_room_form.betting_structure == *structure}>
+                                                {structure.to_string()}
+                                            </option>
+                                        })}
                                     </select>
                                 </div>
                                 <div class="form-group">
@@ -737,6 +1040,69 @@ impl Component for LobbyPage {
                                         />
                                     </div>
                                 </div>
+                                <div class="form-row">
+                                    <div class="form-group">
+                                        <label>{"Ante"}</label>
+                                        <input
+                                            type="number"
+                                            min="0"
+                                            value={self.create_room_form.ante.to_string()}
+                                            onchange={
+                                                let on_form_input = on_form_input.clone();
+                                                Callback::from(move |e: Event| {
+                                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                                    on_form_input.emit(("ante".to_string(), input.value()))
+                                                })
+                                            }
+                                        />
+                                    </div>
+                                    <div class="form-group">
+                                        <label class="checkbox-label">
+                                            <input
+                                                type="checkbox"
+                                                checked={self.create_room_form.button_ante}
+                                                onchange={
+                                                    let on_form_input = on_form_input.clone();
+                                                    Callback::from(move |e: Event| {
+                                                        let input: HtmlInputElement = e.target_unchecked_into();
+                                                        on_form_input.emit(("button_ante".to_string(), input.checked().to_string()))
+                                                    })
+                                                }
+                                            />
+                                            <span>{"Button ante (dealer pays for the table)"}</span>
+                                        </label>
+                                    </div>
+                                </div>
+                                <div class="form-group home-game-options">
+                                    <label class="checkbox-label">
+                                        <input
+                                            type="checkbox"
+                                            checked={self.create_room_form.straddle_allowed}
+                                            onchange={
+                                                let on_form_input = on_form_input.clone();
+                                                Callback::from(move |e: Event| {
+                                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                                    on_form_input.emit(("straddle_allowed".to_string(), input.checked().to_string()))
+                                                })
+                                            }
+                                        />
+                                        <span>{"Allow straddles"}</span>
+                                    </label>
+                                    <label class="checkbox-label">
+                                        <input
+                                            type="checkbox"
+                                            checked={self.create_room_form.run_it_twice}
+                                            onchange={
+                                                let on_form_input = on_form_input.clone();
+                                                Callback::from(move |e: Event| {
+                                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                                    on_form_input.emit(("run_it_twice".to_string(), input.checked().to_string()))
+                                                })
+                                            }
+                                        />
+                                        <span>{"Run it twice on all-ins"}</span>
+                                    </label>
+                                </div>
                                 <div class="form-group">
                                     <label class="checkbox-label">
                                         <input 
@@ -753,15 +1119,59 @@ impl Component for LobbyPage {
                                         <span>{"Private Room (requires password)"}</span>
                                     </label>
                                 </div>
+                                if self.create_room_form.is_private {
+                                    <div class="form-group">
+                                        <label>{"Room Password"}</label>
+                                        <input
+                                            type="password"
+                                            placeholder="Players need this to join"
+                                            value={self.create_room_form.password.clone()}
+                                            onchange={
+                                                let on_form_input = on_form_input.clone();
+                                                Callback::from(move |e: Event| {
+                                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                                    on_form_input.emit(("password".to_string(), input.value()))
+                                                })
+                                            }
+                                        />
+                                    </div>
+                                }
+                                if let Some(ref error) = self.lobby_state.room_creation_error {
+                                    <div class="form-error">{format!("Couldn't create room: {}", error)}</div>
+                                }
                             </div>
                             <div class="modal-footer">
                                 <button class="cancel-btn" onclick={on_hide_create_modal}>{"Cancel"}</button>
-                                <button class="create-btn primary" onclick={on_create_room}>{"Create Room"}</button>
+                                <button
+                                    class="create-btn primary"
+                                    onclick={on_create_room}
+                                    disabled={self.lobby_state.creating_room}
+                                >
+                                    {if self.lobby_state.creating_room { "Creating..." } else { "Create Room" }}
+                                </button>
                             </div>
                         </div>
                     </div>
                 }
 
+                if self.show_quick_join_settings {
+                    <QuickJoinSettingsPanel
+                        settings={self.quick_join_settings.clone()}
+                        rooms={self.lobby_state.rooms.clone()}
+                        chips={user.chips}
+                        on_change={link.callback(LobbyMsg::UpdateQuickJoinSettings)}
+                        on_close={link.callback(|_| LobbyMsg::HideQuickJoinSettings)}
+                    />
+                }
+
+                if let Some(ref room) = self.password_prompt {
+                    <RoomPasswordModal
+                        room_name={room.name.clone()}
+                        on_submit={link.callback(LobbyMsg::UnlockRoom)}
+                        on_cancel={link.callback(|_| LobbyMsg::CancelPasswordPrompt)}
+                    />
+                }
+
                 // Player Profile Modal
                 if let (Some(player), Some(stats)) = (&self.selected_player, &self.selected_player_stats) {
                     <PlayerProfileModal 
@@ -782,8 +1192,213 @@ impl Component for LobbyPage {
 }
 
 impl LobbyPage {
+    fn reset_create_room_form(&mut self) {
+        self.create_room_form = CreateRoomForm::default();
+        self.create_room_form.game_type = GameType::TexasHoldem;
+        self.create_room_form.max_players = 6;
+        self.create_room_form.small_blind = 10;
+        self.create_room_form.big_blind = 20;
+        self.create_room_form.min_buy_in = 1000;
+        self.create_room_form.max_buy_in = 10000;
+    }
+
+    /// Opens a table's game page, which sends the JoinRoom.
+    fn enter_room(&self, ctx: &Context<Self>, room_id: String) {
+        let navigator = ctx.link().navigator().unwrap();
+        navigator.push(&AppRoute::Game { room_id });
+    }
+
+    // Tables picked from the lobby list make up the Recent tab
+    fn record_join(&self, ctx: &Context<Self>, room_id: &str) {
+        if let Some((history, _)) = ctx.link().context::<RoomHistoryContext>(Callback::noop()) {
+            history.record_join.emit(room_id.to_string());
+        }
+    }
+
+    fn notify(&self, ctx: &Context<Self>, notification: NotificationMessage) {
+        if let Some((notifications, _)) = ctx.link().context::<NotificationContext>(Callback::noop()) {
+            notifications.notify.emit(notification);
+        }
+    }
+
+    fn send(&self, message: ClientMessage) {
+        if let Some(ref connection) = self.connection {
+            connection.send.emit(message);
+        }
+    }
+
+    fn render_waitlists(&self, ctx: &Context<Self>) -> Html {
+        let mut waitlists: Vec<_> = self.lobby_state.waitlists().collect();
+        if waitlists.is_empty() {
+            return html! {};
+        }
+        waitlists.sort_by_key(|&(room_id, _)| room_id);
+
+        html! {
+            <div class="waitlist-panel">
+                <span class="waitlist-label">{"⏳ Your waitlists"}</span>
+                {for waitlists.into_iter().map(|(room_id, entry)| {
+                    let name = self.lobby_state.room(room_id).map_or(room_id, |room| room.name.as_str());
+                    let status = match (entry.seat_offer, entry.position) {
+                        (Some(_), _) => "Seat open!".to_string(),
+                        (None, Some(position)) => format!("#{}", position),
+                        (None, None) => "Joining...".to_string(),
+                    };
+                    let room_id = room_id.to_string();
+                    html! {
+                        <div class={classes!("waitlist-entry", entry.seat_offer.is_some().then_some("seat-open"))}>
+                            <span class="waitlist-room">{name}</span>
+                            <span class="waitlist-position">{status}</span>
+                            <button
+                                class="waitlist-leave"
+                                title="Leave waitlist"
+                                onclick={ctx.link().callback(move |_| LobbyMsg::LeaveWaitlist(room_id.clone()))}
+                            >
+                                {"×"}
+                            </button>
+                        </div>
+                    }
+                })}
+            </div>
+        }
+    }
+
+    fn render_saved_rooms(&self, ctx: &Context<Self>, saved_rooms: &[SavedRoom], title: &str, empty_hint: &str) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <div class="rooms-section saved-rooms">
+                <div class="rooms-header">
+                    <h3>{format!("{} ({})", title, saved_rooms.len())}</h3>
+                </div>
+                if saved_rooms.is_empty() {
+                    <div class="no-rooms">
+                        <div class="empty-state">
+                            <h4>{"Nothing here yet"}</h4>
+                            <p>{empty_hint}</p>
+                        </div>
+                    </div>
+                } else {
+                    <ul class="saved-room-list">
+                        {for saved_rooms.iter().map(|saved| {
+                            let room = self.lobby_state.room(&saved.room_id);
+                            let favorite = self.lobby_state.is_favorite(&saved.room_id);
+                            let (availability, label) = match room {
+                                Some(room) if !room.is_active => ("closed", "Closed".to_string()),
+                                None => ("closed", "Not running".to_string()),
+                                Some(room) if room.current_players >= room.max_players => {
+                                    ("full", format!("Full · {} waiting", room.waitlist))
+                                }
+                                Some(room) => {
+                                    let open = room.max_players - room.current_players;
+                                    ("open", format!("{} seat{} open", open, if open == 1 { "" } else { "s" }))
+                                }
+                            };
+                            let room_id = saved.room_id.clone();
+                            let on_toggle_favorite = link.callback({
+                                let room_id = room_id.clone();
+                                move |_| LobbyMsg::ToggleFavorite(room_id.clone())
+                            });
+
+                            html! {
+                                <li key={saved.room_id.clone()} class={classes!("saved-room", availability)}>
+                                    <button
+                                        class={classes!("favorite-toggle", favorite.then_some("active"))}
+                                        title={if favorite { "Remove from favorites" } else { "Add to favorites" }}
+                                        aria-pressed={favorite.to_string()}
+                                        onclick={on_toggle_favorite}
+                                    >
+                                        {if favorite { "★" } else { "☆" }}
+                                    </button>
+                                    <div class="saved-room-details">
+                                        <span class="saved-room-name">
+                                            {&saved.name}
+                                            if room.is_some_and(|room| room.is_private) {
+                                                <span class="private-icon" title="Private Room">{" 🔒"}</span>
+                                            }
+                                        </span>
+                                        <span class="saved-room-meta">
+                                            if let Some(room) = room {
+                                                {format!("${}/{} · ", room.small_blind, room.big_blind)}
+                                            }
+                                            {saved.saved_at.format("%b %d, %H:%M").to_string()}
+                                        </span>
+                                    </div>
+                                    <span class={classes!("availability", availability)}>
+                                        <span class="status-indicator"></span>
+                                        {label}
+                                    </span>
+                                    {match availability {
+                                        "open" => html! {
+                                            <button
+                                                class="join-btn primary"
+                                                onclick={link.callback(move |_| LobbyMsg::JoinRoom(room_id.clone()))}
+                                            >
+                                                {"Join"}
+                                            </button>
+                                        },
+                                        "full" if self.lobby_state.waitlist(&room_id).is_none() => html! {
+                                            <button
+                                                class="join-btn secondary"
+                                                onclick={link.callback(move |_| LobbyMsg::JoinWaitlist(room_id.clone()))}
+                                            >
+                                                {"Waitlist"}
+                                            </button>
+                                        },
+                                        _ => html! {},
+                                    }}
+                                </li>
+                            }
+                        })}
+                    </ul>
+                }
+            </div>
+        }
+    }
+
+    fn render_tournaments(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let on_register = link.callback(LobbyMsg::RegisterTournament);
+        let on_unregister = link.callback(LobbyMsg::UnregisterTournament);
+        let tournaments = &self.lobby_state.tournaments;
+
+        html! {
+            <div class="tournaments-section">
+                if let Some(ref error) = self.lobby_state.registration_error {
+                    <div class="error-notification">
+                        <span class="error-text">{error}</span>
+                    </div>
+                }
+                <div class="rooms-header">
+                    <h3>{format!("Tournaments ({})", tournaments.len())}</h3>
+                </div>
+                if tournaments.is_empty() {
+                    <div class="no-rooms">
+                        <div class="empty-state">
+                            <h4>{"No tournaments scheduled"}</h4>
+                            <p>{"Check back soon for upcoming tournaments and sit & gos"}</p>
+                        </div>
+                    </div>
+                } else {
+                    <div class="tournaments-grid">
+                        {for tournaments.iter().map(|tournament| html! {
+                            <TournamentCard
+                                key={tournament.id.clone()}
+                                tournament={tournament.clone()}
+                                pending={self.lobby_state.is_pending(&tournament.id)}
+                                on_register={on_register.clone()}
+                                on_unregister={on_unregister.clone()}
+                            />
+                        })}
+                    </div>
+                }
+            </div>
+        }
+    }
+
     fn apply_filters(&mut self) {
-        self.filtered_rooms = self.available_rooms
+        let search = self.filter_criteria.search.trim().to_lowercase();
+        self.filtered_rooms = self.lobby_state.rooms
             .iter()
             .filter(|room| {
                 // Game type filter
@@ -792,6 +1407,12 @@ impl LobbyPage {
                     return false;
                 }
 
+                // Betting structure filter
+                if !self.filter_criteria.betting_structures.is_empty()
+                    && !self.filter_criteria.betting_structures.contains(&room.betting_structure) {
+                    return false;
+                }
+
                 // Stakes filter
                 if let Some(min_stakes) = self.filter_criteria.min_stakes {
                     if room.big_blind < min_stakes {
@@ -819,63 +1440,21 @@ impl LobbyPage {
                     return false;
                 }
 
+                // Name search
+                if !search.is_empty() && !room.name.to_lowercase().contains(&search) {
+                    return false;
+                }
+
                 true
             })
             .cloned()
             .collect();
-    }
-}
 
-// Mock data for development - replace with real WebSocket data
-impl LobbyPage {
-    #[allow(dead_code)]
-    fn load_mock_rooms(&mut self) {
-        use chrono::Utc;
-        
-        self.available_rooms = vec![
-            GameRoom {
-                id: "room1".to_string(),
-                name: "High Stakes Hold'em".to_string(),
-                game_type: GameType::TexasHoldem,
-                current_players: 4,
-                max_players: 9,
-                small_blind: 50,
-                big_blind: 100,
-                min_buy_in: 5000,
-                max_buy_in: 50000,
-                is_private: false,
-                is_active: true,
-                created_at: Utc::now(),
-            },
-            GameRoom {
-                id: "room2".to_string(),
-                name: "Beginner Friendly".to_string(),
-                game_type: GameType::TexasHoldem,
-                current_players: 2,
-                max_players: 6,
-                small_blind: 5,
-                big_blind: 10,
-                min_buy_in: 500,
-                max_buy_in: 2000,
-                is_private: false,
-                is_active: true,
-                created_at: Utc::now(),
-            },
-            GameRoom {
-                id: "room3".to_string(),
-                name: "Private VIP Room".to_string(),
-                game_type: GameType::TexasHoldem,
-                current_players: 6,
-                max_players: 6,
-                small_blind: 100,
-                big_blind: 200,
-                min_buy_in: 10000,
-                max_buy_in: 100000,
-                is_private: true,
-                is_active: true,
-                created_at: Utc::now(),
-            },
-        ];
-        self.apply_filters();
+        // Stable sort, so ties keep the server's order and live updates don't reshuffle them
+        let sort = self.room_sort;
+        self.filtered_rooms.sort_by(|a, b| {
+            let order = sort.key.value(a).cmp(&sort.key.value(b));
+            if sort.descending { order.reverse() } else { order }
+        });
     }
 }
@@ -1,12 +1,20 @@
 // Lobby module organization
 pub mod room_filters;
 pub mod room_card;
+pub mod tournament_card;
 pub mod main_lobby;
 pub mod player_sidebar;
 pub mod player_profile_modal;
+pub mod room_password_modal;
+pub mod seat_offers;
+pub mod quick_join_settings;
 
 pub use room_filters::*;
 pub use room_card::*;
+pub use tournament_card::*;
 pub use main_lobby::*;
 pub use player_sidebar::*;
 pub use player_profile_modal::*;
+pub use room_password_modal::*;
+pub use seat_offers::*;
+pub use quick_join_settings::*;
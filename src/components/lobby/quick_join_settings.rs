@@ -0,0 +1,130 @@
+use yew::prelude::*;
+use web_sys::HtmlInputElement;
+
+use crate::types::{GameRoom, GameType, QuickJoinSettings};
+
+const GAME_TYPES: [GameType; 4] = [GameType::TexasHoldem, GameType::Omaha, GameType::OmahaHiLo, GameType::SevenCardStud];
+
+#[derive(Properties, PartialEq)]
+pub struct QuickJoinSettingsPanelProps {
+    pub settings: QuickJoinSettings,
+    pub rooms: Vec<GameRoom>, // candidates for the block list
+    pub chips: i64,
+    pub on_change: Callback<QuickJoinSettings>,
+    pub on_close: Callback<()>,
+}
+
+// Preferences the Quick Join button ranks tables by
+#[function_component(QuickJoinSettingsPanel)]
+pub fn quick_join_settings_panel(props: &QuickJoinSettingsPanelProps) -> Html {
+    let settings = &props.settings;
+
+    // Every control edits one field of a copy and hands the whole thing back
+    let update = |edit: fn(&mut QuickJoinSettings, &HtmlInputElement)| {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut settings = settings.clone();
+            edit(&mut settings, &input);
+            on_change.emit(settings);
+        })
+    };
+    let on_buy_in = update(|settings, input| {
+        if let Ok(percent) = input.value().parse::<u32>() {
+            settings.buy_in_percent = percent.clamp(1, 100);
+        }
+    });
+    let on_game_type = update(|settings, input| {
+        settings.game_type = input.value().parse::<usize>().ok().and_then(|i| GAME_TYPES.get(i).cloned());
+    });
+    let on_short_tables = update(|settings, input| settings.avoid_short_tables = input.checked());
+    let on_recent = update(|settings, input| settings.prefer_recent = input.checked());
+    let on_close = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let buy_in = props.chips * settings.buy_in_percent as i64 / 100;
+    let public_rooms: Vec<&GameRoom> = props.rooms.iter().filter(|room| !room.is_private).collect();
+
+    html! {
+        <div class="modal-overlay" onclick={on_close.clone()}>
+            <div class="modal-content quick-join-settings" onclick={|e: MouseEvent| e.stop_propagation()}>
+                <div class="modal-header">
+                    <h3>{"⚡ Quick Join Settings"}</h3>
+                    <button class="close-btn" onclick={on_close.clone()}>{"×"}</button>
+                </div>
+                <div class="modal-body">
+                    <div class="form-group">
+                        <label>{"Buy-in (% of your chips)"}</label>
+                        <input
+                            type="number"
+                            min="1"
+                            max="100"
+                            value={settings.buy_in_percent.to_string()}
+                            onchange={on_buy_in}
+                        />
+                        <span class="form-hint">{format!("About {} chips per table", buy_in)}</span>
+                    </div>
+                    <div class="form-group">
+                        <label>{"Preferred Game"}</label>
+                        <select onchange={on_game_type}>
+                            <option value="any" selected={settings.game_type.is_none()}>{"Any game"}</option>
+                            {for GAME_TYPES.iter().enumerate().map(|(i, game_type)| html! {
+                                <option value={i.to_string()} selected={settings.game_type.as_ref() == Some(game_type)}>
+                                    {game_type.rules().name}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                    <div class="form-group">
+                        <label class="checkbox-label">
+                            <input type="checkbox" checked={settings.avoid_short_tables} onchange={on_short_tables} />
+                            <span>{"Avoid empty and heads-up tables"}</span>
+                        </label>
+                        <label class="checkbox-label">
+                            <input type="checkbox" checked={settings.prefer_recent} onchange={on_recent} />
+                            <span>{"Prefer tables I've played recently"}</span>
+                        </label>
+                    </div>
+                    <div class="form-group">
+                        <label>{"Never Quick Join"}</label>
+                        if public_rooms.is_empty() {
+                            <span class="form-hint">{"No tables in the lobby right now"}</span>
+                        } else {
+                            <div class="quick-join-blocklist">
+                                {for public_rooms.into_iter().map(|room| {
+                                    let blocked = settings.blocked_rooms.contains(&room.id);
+                                    let on_toggle = {
+                                        let settings = props.settings.clone();
+                                        let on_change = props.on_change.clone();
+                                        let room_id = room.id.clone();
+                                        Callback::from(move |_: Event| {
+                                            let mut settings = settings.clone();
+                                            if blocked {
+                                                settings.blocked_rooms.retain(|id| *id != room_id);
+                                            } else {
+                                                settings.blocked_rooms.push(room_id.clone());
+                                            }
+                                            on_change.emit(settings);
+                                        })
+                                    };
+                                    html! {
+                                        <label key={room.id.clone()} class={classes!("checkbox-label", blocked.then_some("blocked"))}>
+                                            <input type="checkbox" checked={blocked} onchange={on_toggle} />
+                                            <span>{format!("{} (${}/{})", room.name, room.small_blind, room.big_blind)}</span>
+                                        </label>
+                                    }
+                                })}
+                            </div>
+                        }
+                    </div>
+                </div>
+                <div class="modal-footer">
+                    <button class="create-btn primary" onclick={on_close}>{"Done"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -1,27 +1,70 @@
 use yew::prelude::*;
-use yew_router::prelude::*;
+use gloo_timers::callback::Timeout;
 
-use crate::types::{GameRoom, GameType, AppRoute};
+use crate::lobby::WaitlistEntry;
+use crate::types::{GameRoom, GameType};
 
 #[derive(Properties, PartialEq)]
 pub struct RoomCardProps {
     pub room: GameRoom,
     pub on_join: Callback<String>,
+    #[prop_or_default]
+    pub waitlist: Option<WaitlistEntry>, // our place in this room's queue, if we're in it
+    pub on_join_waitlist: Callback<String>,
+    pub on_leave_waitlist: Callback<String>,
+    #[prop_or_default]
+    pub favorite: bool,
+    pub on_toggle_favorite: Callback<String>,
 }
 
 #[function_component(RoomCard)]
 pub fn room_card(props: &RoomCardProps) -> Html {
     let room = &props.room;
-    let navigator = use_navigator().unwrap();
+    let previous_players = use_mut_ref(|| room.current_players);
+    let count_change = use_state(|| None::<&'static str>);
+
+    // Flash the player count when a live update changes it
+    {
+        let count_change = count_change.clone();
+        use_effect_with(room.current_players, move |&players| {
+            let previous = std::mem::replace(&mut *previous_players.borrow_mut(), players);
+            let timeout = (players != previous).then(|| {
+                count_change.set(Some(if players > previous { "count-up" } else { "count-down" }));
+                let count_change = count_change.clone();
+                Timeout::new(1200, move || count_change.set(None))
+            });
+            move || drop(timeout)
+        });
+    }
     
+    // The lobby navigates, after asking for a password if the room is private
     let on_join_click = {
         let room_id = room.id.clone();
         let on_join = props.on_join.clone();
-        let navigator = navigator.clone();
         
         Callback::from(move |_: MouseEvent| {
             on_join.emit(room_id.clone());
-            navigator.push(&AppRoute::Game { room_id: room_id.clone() });
+        })
+    };
+
+    let on_favorite_click = {
+        let room_id = room.id.clone();
+        let on_toggle_favorite = props.on_toggle_favorite.clone();
+        Callback::from(move |_: MouseEvent| on_toggle_favorite.emit(room_id.clone()))
+    };
+
+    let on_waitlist_click = {
+        let room_id = room.id.clone();
+        let queued = props.waitlist.is_some();
+        let on_join_waitlist = props.on_join_waitlist.clone();
+        let on_leave_waitlist = props.on_leave_waitlist.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            if queued {
+                on_leave_waitlist.emit(room_id.clone());
+            } else {
+                on_join_waitlist.emit(room_id.clone());
+            }
         })
     };
 
@@ -61,6 +104,14 @@ pub fn room_card(props: &RoomCardProps) -> Html {
             // Room Header
             <div class="room-header">
                 <div class="room-title">
+                    <button
+                        class={classes!("favorite-toggle", props.favorite.then_some("active"))}
+                        title={if props.favorite { "Remove from favorites" } else { "Add to favorites" }}
+                        aria-pressed={props.favorite.to_string()}
+                        onclick={on_favorite_click.clone()}
+                    >
+                        {if props.favorite { "★" } else { "☆" }}
+                    </button>
                     <h4 class="room-name">{&room.name}</h4>
                     {if room.is_private {
                         html! { <span class="private-icon" title="Private Room">{"🔒"}</span> }
@@ -79,6 +130,12 @@ pub fn room_card(props: &RoomCardProps) -> Html {
                 <div class="game-type">
                     <span class="game-icon">{game_icon}</span>
                     <span class="game-name">{game_name}</span>
+                    <span
+                        class={classes!("betting-structure", room.betting_structure.key())}
+                        title={room.betting_structure.to_string()}
+                    >
+                        {room.betting_structure.abbreviation()}
+                    </span>
                 </div>
                 
                 <div class="stakes-info">
@@ -91,12 +148,36 @@ pub fn room_card(props: &RoomCardProps) -> Html {
                         <span class="buy-in-value">{buy_in_range}</span>
                     </div>
                 </div>
+
+                <div class="room-stats">
+                    <span class="room-stat" title="Average pot">{format!("Avg pot ${}", room.avg_pot)}</span>
+                    <span class="room-stat" title="Hands per hour">{format!("{} hands/hr", room.hands_per_hour)}</span>
+                    if room.waitlist > 0 {
+                        <span class="room-stat waitlist" title="Players waiting">{format!("{} waiting", room.waitlist)}</span>
+                    }
+                </div>
+
+                if room.ante > 0 || room.straddle_allowed || room.run_it_twice {
+                    <div class="room-options">
+                        if room.ante > 0 {
+                            <span class="room-option">
+                                {if room.button_ante { format!("Button ante ${}", room.ante) } else { format!("Ante ${}", room.ante) }}
+                            </span>
+                        }
+                        if room.straddle_allowed {
+                            <span class="room-option">{"Straddle"}</span>
+                        }
+                        if room.run_it_twice {
+                            <span class="room-option">{"Run it twice"}</span>
+                        }
+                    </div>
+                }
             </div>
 
             // Player Info
             <div class="player-info">
                 <div class="player-count">
-                    <div class="count-display">
+                    <div class={classes!("count-display", *count_change)}>
                         <span class="current-players">{room.current_players}</span>
                         <span class="separator">{"/"}</span>
                         <span class="max-players">{room.max_players}</span>
@@ -120,10 +201,24 @@ pub fn room_card(props: &RoomCardProps) -> Html {
                     >
                         {"🚀 Join Game"}
                     </button>
-                } else if room.current_players == room.max_players {
-                    <button class="join-btn disabled" disabled={true}>
-                        {"Room Full"}
-                    </button>
+                } else if room.current_players == room.max_players && room.is_active {
+                    {match props.waitlist {
+                        Some(WaitlistEntry { position: Some(position), .. }) => html! {
+                            <button class="join-btn waitlisted" onclick={on_waitlist_click} title="Leave the waitlist">
+                                {format!("⏳ Waitlist #{} · Leave", position)}
+                            </button>
+                        },
+                        Some(_) => html! {
+                            <button class="join-btn disabled" disabled={true}>
+                                {"Joining waitlist..."}
+                            </button>
+                        },
+                        None => html! {
+                            <button class="join-btn secondary" onclick={on_waitlist_click}>
+                                {"📋 Join Waitlist"}
+                            </button>
+                        },
+                    }}
                 } else if !room.is_active {
                     <button class="join-btn disabled" disabled={true}>
                         {"Inactive"}
@@ -138,7 +233,9 @@ pub fn room_card(props: &RoomCardProps) -> Html {
                 <div class="room-menu">
                     <button class="menu-toggle">{"⋯"}</button>
                     <div class="menu-dropdown">
-                        <button class="menu-item">{"⭐ Add to Favorites"}</button>
+                        <button class="menu-item" onclick={on_favorite_click}>
+                            {if props.favorite { "⭐ Remove from Favorites" } else { "⭐ Add to Favorites" }}
+                        </button>
                         <button class="menu-item">{"📊 Room Stats"}</button>
                         {if !room.is_private {
                             html! {
@@ -2,20 +2,30 @@ use yew::prelude::*;
 use web_sys::HtmlInputElement;
 use wasm_bindgen::JsCast;
 use gloo_storage::{LocalStorage, Storage};
+use uuid::Uuid;
 
-use crate::types::{RoomFilter, GameType};
+use crate::lobby::user_storage_key;
+use crate::types::{RoomFilter, GameType, BettingStructure, FilterPreset};
+
+const FILTERS_KEY: &str = "primo_poker_room_filters";
+const PRESETS_KEY: &str = "primo_poker_filter_presets";
 
 #[derive(Properties, PartialEq)]
 pub struct RoomFiltersProps {
     pub current_filter: RoomFilter,
     pub on_filter_change: Callback<RoomFilter>,
     pub on_reset_filters: Callback<()>,
+    #[prop_or_default]
+    pub user_id: Option<Uuid>, // filters and presets are saved per user
 }
 
 pub struct RoomFilters {
     filter: RoomFilter,
     stakes_min_input: String,
     stakes_max_input: String,
+    presets: Vec<FilterPreset>,
+    preset_name_input: String,
+    renaming: Option<(usize, String)>, // preset index, name being typed
 }
 
 pub enum RoomFiltersMsg {
@@ -26,8 +36,18 @@ pub enum RoomFiltersMsg {
     ToggleEmptyRooms,
     ToggleFullRooms,
     ToggleGameType(GameType),
+    ToggleBettingStructure(BettingStructure),
     ResetFilters,
     SaveFilters,
+    UpdateSearch(String),
+    UpdatePresetName(String),
+    SavePreset,
+    ApplyPreset(usize),
+    StartRename(usize),
+    UpdateRename(String),
+    CommitRename,
+    CancelRename,
+    DeletePreset(usize),
 }
 
 impl Component for RoomFilters {
@@ -36,17 +56,26 @@ impl Component for RoomFilters {
 
     fn create(ctx: &Context<Self>) -> Self {
         let props = ctx.props();
-        let filter = props.current_filter.clone();
-        
-        // Load saved filters from localStorage if available
-        let saved_filter = LocalStorage::get::<RoomFilter>("primo_poker_room_filters")
-            .unwrap_or_else(|_| filter.clone());
-
-        Self {
-            filter: saved_filter.clone(),
-            stakes_min_input: saved_filter.min_stakes.map(|v| v.to_string()).unwrap_or_default(),
-            stakes_max_input: saved_filter.max_stakes.map(|v| v.to_string()).unwrap_or_default(),
+        let mut filters = Self {
+            filter: props.current_filter.clone(),
+            stakes_min_input: String::new(),
+            stakes_max_input: String::new(),
+            presets: Vec::new(),
+            preset_name_input: String::new(),
+            renaming: None,
+        };
+        filters.load_saved(props.user_id);
+        props.on_filter_change.emit(filters.filter.clone());
+        filters
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        // The signed-in user usually arrives after the lobby first renders
+        if ctx.props().user_id != old_props.user_id {
+            self.load_saved(ctx.props().user_id);
+            ctx.props().on_filter_change.emit(self.filter.clone());
         }
+        true
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -67,6 +96,15 @@ impl Component for RoomFilters {
                 self.emit_filter_change(ctx);
                 true
             }
+            RoomFiltersMsg::ToggleBettingStructure(structure) => {
+                if self.filter.betting_structures.contains(&structure) {
+                    self.filter.betting_structures.retain(|s| *s != structure);
+                } else {
+                    self.filter.betting_structures.push(structure);
+                }
+                self.emit_filter_change(ctx);
+                true
+            }
             RoomFiltersMsg::UpdateStakesMin(value) => {
                 self.stakes_min_input = value.clone();
                 self.filter.min_stakes = if value.is_empty() {
@@ -108,14 +146,16 @@ impl Component for RoomFilters {
                     max_stakes: None,
                     max_players_filter: None,
                     game_types: vec![GameType::TexasHoldem],
+                    betting_structures: Vec::new(),
                     show_full_rooms: true,
                     show_empty_rooms: true,
+                    search: String::new(),
                 };
                 self.stakes_min_input.clear();
                 self.stakes_max_input.clear();
                 
                 // Clear saved filters
-                let _ = LocalStorage::delete("primo_poker_room_filters");
+                LocalStorage::delete(self.storage_key(ctx, FILTERS_KEY));
                 
                 ctx.props().on_reset_filters.emit(());
                 self.emit_filter_change(ctx);
@@ -123,9 +163,76 @@ impl Component for RoomFilters {
             }
             RoomFiltersMsg::SaveFilters => {
                 // Save current filters to localStorage
-                let _ = LocalStorage::set("primo_poker_room_filters", &self.filter);
+                let _ = LocalStorage::set(self.storage_key(ctx, FILTERS_KEY), &self.filter);
                 false
             }
+            RoomFiltersMsg::UpdateSearch(search) => {
+                self.filter.search = search;
+                self.emit_filter_change(ctx);
+                true
+            }
+            RoomFiltersMsg::UpdatePresetName(name) => {
+                self.preset_name_input = name;
+                true
+            }
+            RoomFiltersMsg::SavePreset => {
+                let name = self.preset_name_input.trim().to_string();
+                if name.is_empty() {
+                    return false;
+                }
+                // Saving under an existing name overwrites that preset
+                let preset = FilterPreset { name, filter: self.filter.clone() };
+                match self.presets.iter_mut().find(|existing| existing.name == preset.name) {
+                    Some(existing) => *existing = preset,
+                    None => self.presets.push(preset),
+                }
+                self.preset_name_input.clear();
+                self.save_presets(ctx);
+                true
+            }
+            RoomFiltersMsg::ApplyPreset(index) => {
+                let Some(preset) = self.presets.get(index) else {
+                    return false;
+                };
+                self.set_filter(preset.filter.clone());
+                self.emit_filter_change(ctx);
+                true
+            }
+            RoomFiltersMsg::StartRename(index) => {
+                self.renaming = self.presets.get(index).map(|preset| (index, preset.name.clone()));
+                true
+            }
+            RoomFiltersMsg::UpdateRename(name) => {
+                if let Some((_, ref mut pending)) = self.renaming {
+                    *pending = name;
+                }
+                false
+            }
+            RoomFiltersMsg::CommitRename => {
+                if let Some((index, name)) = self.renaming.take() {
+                    let name = name.trim().to_string();
+                    let taken = self.presets.iter().enumerate().any(|(i, preset)| i != index && preset.name == name);
+                    if !name.is_empty() && !taken {
+                        if let Some(preset) = self.presets.get_mut(index) {
+                            preset.name = name;
+                            self.save_presets(ctx);
+                        }
+                    }
+                }
+                true
+            }
+            RoomFiltersMsg::CancelRename => {
+                self.renaming = None;
+                true
+            }
+            RoomFiltersMsg::DeletePreset(index) => {
+                if index < self.presets.len() {
+                    self.presets.remove(index);
+                    self.renaming = None;
+                    self.save_presets(ctx);
+                }
+                true
+            }
         }
     }
 
@@ -167,6 +274,10 @@ impl Component for RoomFilters {
         let on_toggle_empty = link.callback(|_| RoomFiltersMsg::ToggleEmptyRooms);
         let on_toggle_full = link.callback(|_| RoomFiltersMsg::ToggleFullRooms);
         let on_reset = link.callback(|_| RoomFiltersMsg::ResetFilters);
+        let on_search = link.callback(|e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            RoomFiltersMsg::UpdateSearch(input.value())
+        });
 
         // Stake range presets
         let preset_stakes = vec![
@@ -186,6 +297,18 @@ impl Component for RoomFilters {
                     </button>
                 </div>
 
+                <div class="filter-search">
+                    <input
+                        type="search"
+                        class="room-search-input"
+                        placeholder="🔍 Search room names"
+                        value={self.filter.search.clone()}
+                        oninput={on_search}
+                    />
+                </div>
+
+                {self.render_presets(ctx)}
+
                 <div class="filter-sections">
                     // Game Type Filter
                     <div class="filter-section">
@@ -208,6 +331,26 @@ impl Component for RoomFilters {
                         </div>
                     </div>
 
+                    // Betting Structure Filter
+                    <div class="filter-section">
+                        <label class="filter-label">{"📐 Betting Structure"}</label>
+                        <div class="toggle-options">
+                            {for BettingStructure::ALL.iter().map(|structure| {
+                                let structure = *structure;
+                                html! {
+                                    <label class="toggle-option">
+                                        <input 
+                                            type="checkbox" 
+                                            checked={self.filter.betting_structures.contains(&structure)}
+                                            onchange={link.callback(move |_| RoomFiltersMsg::ToggleBettingStructure(structure))}
+                                        />
+                                        <span class="toggle-label">{structure.to_string()}</span>
+                                    </label>
+                                }
+                            })}
+                        </div>
+                    </div>
+
                     // Stakes Range Filter
                     <div class="filter-section">
                         <label class="filter-label">{"💰 Stakes Range"}</label>
@@ -318,18 +461,122 @@ impl Component for RoomFilters {
 }
 
 impl RoomFilters {
+    fn storage_key(&self, ctx: &Context<Self>, base: &str) -> String {
+        user_storage_key(base, ctx.props().user_id)
+    }
+
+    fn load_saved(&mut self, user_id: Option<Uuid>) {
+        // Fall back to the pre-account single saved filter
+        let saved_filter = LocalStorage::get::<RoomFilter>(user_storage_key(FILTERS_KEY, user_id))
+            .or_else(|_| LocalStorage::get::<RoomFilter>(FILTERS_KEY));
+        if let Ok(filter) = saved_filter {
+            self.set_filter(filter);
+        }
+        self.presets = LocalStorage::get(user_storage_key(PRESETS_KEY, user_id)).unwrap_or_default();
+        self.renaming = None;
+    }
+
+    fn set_filter(&mut self, filter: RoomFilter) {
+        self.stakes_min_input = filter.min_stakes.map(|v| v.to_string()).unwrap_or_default();
+        self.stakes_max_input = filter.max_stakes.map(|v| v.to_string()).unwrap_or_default();
+        self.filter = filter;
+    }
+
+    fn save_presets(&self, ctx: &Context<Self>) {
+        let _ = LocalStorage::set(self.storage_key(ctx, PRESETS_KEY), &self.presets);
+    }
+
     fn emit_filter_change(&self, ctx: &Context<Self>) {
         // Save filters automatically when they change
-        let _ = LocalStorage::set("primo_poker_room_filters", &self.filter);
+        let _ = LocalStorage::set(self.storage_key(ctx, FILTERS_KEY), &self.filter);
         
         // Emit the change to parent component
         ctx.props().on_filter_change.emit(self.filter.clone());
     }
 
+    fn render_presets(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let on_name_input = link.callback(|e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            RoomFiltersMsg::UpdatePresetName(input.value())
+        });
+        let on_save = link.callback(|e: SubmitEvent| {
+            e.prevent_default();
+            RoomFiltersMsg::SavePreset
+        });
+
+        html! {
+            <div class="filter-presets">
+                <label class="filter-label">{"⭐ Saved Filters"}</label>
+                <ul class="preset-list">
+                    {for self.presets.iter().enumerate().map(|(index, preset)| {
+                        match self.renaming {
+                            Some((renaming, ref name)) if renaming == index => html! {
+                                <li class="preset-item renaming">
+                                    <form onsubmit={link.callback(|e: SubmitEvent| {
+                                        e.prevent_default();
+                                        RoomFiltersMsg::CommitRename
+                                    })}>
+                                        <input
+                                            type="text"
+                                            class="preset-name-input"
+                                            value={name.clone()}
+                                            oninput={link.callback(|e: InputEvent| {
+                                                let input: HtmlInputElement = e.target_unchecked_into();
+                                                RoomFiltersMsg::UpdateRename(input.value())
+                                            })}
+                                        />
+                                        <button type="submit" class="preset-action">{"✓"}</button>
+                                        <button type="button" class="preset-action" onclick={link.callback(|_| RoomFiltersMsg::CancelRename)}>
+                                            {"✕"}
+                                        </button>
+                                    </form>
+                                </li>
+                            },
+                            _ => html! {
+                                <li class={classes!("preset-item", (preset.filter == self.filter).then_some("active"))}>
+                                    <button class="preset-name" onclick={link.callback(move |_| RoomFiltersMsg::ApplyPreset(index))}>
+                                        {&preset.name}
+                                    </button>
+                                    <button class="preset-action" title="Rename" onclick={link.callback(move |_| RoomFiltersMsg::StartRename(index))}>
+                                        {"✎"}
+                                    </button>
+                                    <button class="preset-action" title="Delete" onclick={link.callback(move |_| RoomFiltersMsg::DeletePreset(index))}>
+                                        {"🗑"}
+                                    </button>
+                                </li>
+                            },
+                        }
+                    })}
+                </ul>
+                <form class="preset-save" onsubmit={on_save}>
+                    <input
+                        type="text"
+                        class="preset-name-input"
+                        placeholder="Name these filters"
+                        value={self.preset_name_input.clone()}
+                        oninput={on_name_input}
+                    />
+                    <button type="submit" class="preset-btn" disabled={self.preset_name_input.trim().is_empty()}>
+                        {"Save"}
+                    </button>
+                </form>
+            </div>
+        }
+    }
+
     fn render_active_filter_tags(&self, ctx: &Context<Self>) -> Html {
         let link = ctx.link();
         let mut tags = Vec::new();
 
+        if !self.filter.search.trim().is_empty() {
+            tags.push(html! {
+                <span class="filter-tag search-tag">
+                    {format!("\"{}\"", self.filter.search.trim())}
+                </span>
+            });
+        }
+
         // Game type tags
         for game_type in &self.filter.game_types {
             let game_name = match game_type {
@@ -345,6 +592,15 @@ impl RoomFilters {
             });
         }
 
+        // Betting structure tags
+        for structure in &self.filter.betting_structures {
+            tags.push(html! {
+                <span class="filter-tag structure-tag">
+                    {structure.to_string()}
+                </span>
+            });
+        }
+
         // Stakes range tag
         match (self.filter.min_stakes, self.filter.max_stakes) {
             (Some(min), Some(max)) => {
@@ -0,0 +1,73 @@
+use yew::prelude::*;
+use web_sys::HtmlInputElement;
+
+#[derive(Properties, PartialEq)]
+pub struct RoomPasswordModalProps {
+    pub room_name: String,
+    #[prop_or_default]
+    pub error: Option<String>,
+    pub on_submit: Callback<String>,
+    pub on_cancel: Callback<()>,
+}
+
+// Password prompt shown before joining a private room
+#[function_component(RoomPasswordModal)]
+pub fn room_password_modal(props: &RoomPasswordModalProps) -> Html {
+    let password = use_state(String::new);
+
+    let on_input = {
+        let password = password.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            password.set(input.value());
+        })
+    };
+    let on_submit = {
+        let password = password.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if !password.trim().is_empty() {
+                on_submit.emit((*password).clone());
+            }
+        })
+    };
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_: MouseEvent| on_cancel.emit(()))
+    };
+
+    html! {
+        <div class="modal-overlay" onclick={on_cancel.clone()}>
+            <div class="modal-content room-password-modal" onclick={|e: MouseEvent| e.stop_propagation()}>
+                <div class="modal-header">
+                    <h3>{format!("🔒 {}", props.room_name)}</h3>
+                    <button class="close-btn" onclick={on_cancel.clone()}>{"×"}</button>
+                </div>
+                <form onsubmit={on_submit}>
+                    <div class="modal-body">
+                        <div class="form-group">
+                            <label>{"Room Password"}</label>
+                            <input
+                                type="password"
+                                placeholder="Enter the room password"
+                                value={(*password).clone()}
+                                oninput={on_input}
+                                autofocus={true}
+                            />
+                        </div>
+                        if let Some(ref error) = props.error {
+                            <div class="form-error">{error}</div>
+                        }
+                    </div>
+                    <div class="modal-footer">
+                        <button type="button" class="cancel-btn" onclick={on_cancel}>{"Cancel"}</button>
+                        <button type="submit" class="create-btn primary" disabled={password.trim().is_empty()}>
+                            {"Join Room"}
+                        </button>
+                    </div>
+                </form>
+            </div>
+        </div>
+    }
+}
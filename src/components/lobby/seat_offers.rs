@@ -0,0 +1,89 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use chrono::Utc;
+use gloo_timers::callback::Interval;
+
+use crate::lobby::LobbyState;
+use crate::services::websocket_service::ConnectionContext;
+use crate::types::{AppRoute, ClientMessage};
+
+// Seat offers from waitlisted tables, each with a countdown to take the seat
+#[function_component(SeatOffers)]
+pub fn seat_offers() -> Html {
+    let lobby = use_context::<LobbyState>();
+    let connection = use_context::<ConnectionContext>();
+    let navigator = use_navigator();
+    let now = use_state(Utc::now);
+
+    let offers: Vec<(String, String, i64)> = lobby
+        .as_ref()
+        .map(|lobby| {
+            lobby
+                .seat_offers()
+                .into_iter()
+                .map(|(room_id, expires_at)| {
+                    let name = lobby.room(room_id).map_or(room_id.to_string(), |room| room.name.clone());
+                    (room_id.to_string(), name, (expires_at - *now).num_seconds())
+                })
+                .filter(|&(_, _, remaining)| remaining > 0)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Only tick while an offer is on screen
+    {
+        let now = now.clone();
+        use_effect_with(offers.is_empty(), move |&idle| {
+            now.set(Utc::now());
+            let interval = (!idle).then(|| Interval::new(1000, move || now.set(Utc::now())));
+            move || drop(interval)
+        });
+    }
+
+    if offers.is_empty() {
+        return html! {};
+    }
+
+    let send = move |message: ClientMessage| {
+        if let Some(ref connection) = connection {
+            connection.send.emit(message);
+        }
+    };
+
+    html! {
+        <div class="toast-container seat-offers" role="alert">
+            {for offers.into_iter().map(|(room_id, name, remaining)| {
+                let on_accept = {
+                    let send = send.clone();
+                    let navigator = navigator.clone();
+                    let room_id = room_id.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        send(ClientMessage::AcceptSeat { room_id: room_id.clone() });
+                        if let Some(ref navigator) = navigator {
+                            navigator.push(&AppRoute::Game { room_id: room_id.clone() });
+                        }
+                    })
+                };
+                let on_decline = {
+                    let send = send.clone();
+                    let room_id = room_id.clone();
+                    Callback::from(move |_: MouseEvent| send(ClientMessage::DeclineSeat { room_id: room_id.clone() }))
+                };
+
+                html! {
+                    <div key={room_id.clone()} class={classes!("toast", "toast-info", "seat-offer", (remaining <= 10).then_some("ending"))}>
+                        <div class="toast-icon">{"🪑"}</div>
+                        <div class="toast-content">
+                            <span class="toast-message">{format!("A seat is open at {}", name)}</span>
+                            <span class="seat-offer-countdown">{format!("{}:{:02} to take it", remaining / 60, remaining % 60)}</span>
+                        </div>
+                        <div class="seat-offer-actions">
+                            <button class="btn btn-primary" onclick={on_accept}>{"Take Seat"}</button>
+                            <button class="btn btn-secondary" onclick={on_decline}>{"Pass"}</button>
+                        </div>
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,158 @@
+use yew::prelude::*;
+
+use crate::types::{TournamentFormat, TournamentInfo, TournamentStatus};
+
+#[derive(Properties, PartialEq)]
+pub struct TournamentCardProps {
+    pub tournament: TournamentInfo,
+    pub pending: bool, // registration request in flight
+    pub on_register: Callback<String>,
+    pub on_unregister: Callback<String>,
+}
+
+#[function_component(TournamentCard)]
+pub fn tournament_card(props: &TournamentCardProps) -> Html {
+    let tournament = &props.tournament;
+    let config = &tournament.config;
+    let show_details = use_state(|| false);
+
+    let on_toggle_details = {
+        let show_details = show_details.clone();
+        Callback::from(move |_: MouseEvent| show_details.set(!*show_details))
+    };
+
+    let on_action = {
+        let tournament_id = tournament.id.clone();
+        let registered = tournament.is_registered;
+        let on_register = props.on_register.clone();
+        let on_unregister = props.on_unregister.clone();
+        Callback::from(move |_: MouseEvent| {
+            if registered {
+                on_unregister.emit(tournament_id.clone());
+            } else {
+                on_register.emit(tournament_id.clone());
+            }
+        })
+    };
+
+    let format_label = match config.format {
+        TournamentFormat::MultiTable => "MTT",
+        TournamentFormat::SitAndGo => "Sit & Go",
+    };
+
+    let (status_class, status_text) = match tournament.status {
+        TournamentStatus::Registering => ("registering", "Registering".to_string()),
+        TournamentStatus::Running if tournament.is_late_registration() => (
+            "late-registration",
+            format!("Late reg · Level {}/{}", tournament.current_level, config.late_registration_levels),
+        ),
+        TournamentStatus::Running => ("running", format!("Running · Level {}", tournament.current_level)),
+        TournamentStatus::Completed => ("completed", "Completed".to_string()),
+        TournamentStatus::Cancelled => ("cancelled", "Cancelled".to_string()),
+    };
+
+    let start_text = match config.starts_at {
+        Some(starts_at) => starts_at.format("%b %d, %H:%M UTC").to_string(),
+        None => format!("When {} players register", config.max_entrants),
+    };
+
+    // Once registration closes only players already in may act (to unregister before the start)
+    let unregister_allowed = tournament.status == TournamentStatus::Registering;
+    let action_enabled = !props.pending
+        && if tournament.is_registered { unregister_allowed } else { tournament.registration_open() };
+    let action_label = match (props.pending, tournament.is_registered) {
+        (true, _) => "Please wait...",
+        (false, true) => "Unregister",
+        (false, false) if tournament.is_late_registration() => "Late Register",
+        (false, false) => "Register",
+    };
+
+    html! {
+        <div class={classes!("tournament-card", status_class, tournament.is_registered.then_some("registered"))}>
+            <div class="tournament-header">
+                <div class="tournament-title">
+                    <h4 class="tournament-name">{&config.name}</h4>
+                    <span class="tournament-format">{format_label}</span>
+                </div>
+                <div class={classes!("tournament-status", status_class)}>{status_text}</div>
+            </div>
+
+            <div class="tournament-info">
+                <div class="info-item">
+                    <span class="info-label">{"Buy-in"}</span>
+                    <span class="info-value">{format!("${} + ${}", config.buy_in, config.fee)}</span>
+                </div>
+                <div class="info-item">
+                    <span class="info-label">{"Starting stack"}</span>
+                    <span class="info-value">{config.starting_stack}</span>
+                </div>
+                <div class="info-item">
+                    <span class="info-label">{"Entrants"}</span>
+                    <span class="info-value">{format!("{}/{}", tournament.registered_players, config.max_entrants)}</span>
+                </div>
+                <div class="info-item">
+                    <span class="info-label">{"Prize pool"}</span>
+                    <span class="info-value">{format!("${}", tournament.prize_pool())}</span>
+                </div>
+                <div class="info-item">
+                    <span class="info-label">{"Starts"}</span>
+                    <span class="info-value">{start_text}</span>
+                </div>
+            </div>
+
+            if *show_details {
+                <div class="tournament-details">
+                    <table class="blind-structure">
+                        <thead>
+                            <tr><th>{"Level"}</th><th>{"Blinds"}</th><th>{"Ante"}</th><th>{"Minutes"}</th></tr>
+                        </thead>
+                        <tbody>
+                            {for config.blind_structure.iter().map(|level| html! {
+                                <tr class={classes!((level.level == tournament.current_level).then_some("current"))}>
+                                    <td>{level.level}</td>
+                                    <td>{format!("{}/{}", level.small_blind, level.big_blind)}</td>
+                                    <td>{level.ante}</td>
+                                    <td>{level.duration_minutes}</td>
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
+                    <table class="payout-table">
+                        <thead>
+                            <tr><th>{"Place"}</th><th>{"Share"}</th><th>{"Prize"}</th></tr>
+                        </thead>
+                        <tbody>
+                            {for config.payouts.iter().map(|tier| {
+                                let places = if tier.first_place == tier.last_place {
+                                    tier.first_place.to_string()
+                                } else {
+                                    format!("{}-{}", tier.first_place, tier.last_place)
+                                };
+                                html! {
+                                    <tr>
+                                        <td>{places}</td>
+                                        <td>{format!("{}%", tier.percentage)}</td>
+                                        <td>{format!("${}", tournament.payout_for_place(tier.first_place))}</td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                </div>
+            }
+
+            <div class="tournament-actions">
+                <button class="details-btn secondary" onclick={on_toggle_details}>
+                    {if *show_details { "Hide details" } else { "Structure & payouts" }}
+                </button>
+                <button
+                    class={classes!("register-btn", if tournament.is_registered { "secondary" } else { "primary" })}
+                    onclick={on_action}
+                    disabled={!action_enabled}
+                >
+                    {action_label}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,269 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlElement;
+use uuid::Uuid;
+
+use crate::components::game::{card_view, sized_raise, HandResultBanner, TablePromptBar};
+use crate::game::{GameState, TablesContext};
+use crate::services::websocket_service::ConnectionContext;
+use crate::types::{AppRoute, BettingStructure, ClientMessage, GamePhase, PlayerAction};
+
+const LAYOUT_STORAGE_KEY: &str = "primo_poker_table_layout";
+const CASCADE_OFFSET_PX: usize = 36;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TableLayout {
+    #[default]
+    Tiled,
+    Cascaded,
+}
+
+// Multi-Table Page Component - every open table at once, tiled or cascaded
+#[function_component(MultiTablePage)]
+pub fn multi_table_page() -> Html {
+    let tables_ctx = use_context::<TablesContext>();
+    let layout = use_state(|| LocalStorage::get::<TableLayout>(LAYOUT_STORAGE_KEY).unwrap_or_default());
+
+    let set_layout = |next: TableLayout| {
+        let layout = layout.clone();
+        Callback::from(move |_: MouseEvent| {
+            let _ = LocalStorage::set(LAYOUT_STORAGE_KEY, next);
+            layout.set(next);
+        })
+    };
+
+    let Some(tables_ctx) = tables_ctx else {
+        return html! {};
+    };
+    let tables = &tables_ctx.tables;
+
+    if tables.is_empty() {
+        return html! {
+            <div class="multi-table-page empty">
+                <p>{"You aren't seated at any tables yet."}</p>
+                <Link<AppRoute> to={AppRoute::Lobby} classes="btn btn-primary">
+                    {"Find a table"}
+                </Link<AppRoute>>
+            </div>
+        };
+    }
+
+    let count = tables.len();
+    let pending = tables.pending_action_count();
+    let (layout_class, grid_style) = match *layout {
+        TableLayout::Tiled => {
+            let columns = (count as f64).sqrt().ceil() as usize;
+            ("tiled", format!("grid-template-columns: repeat({}, minmax(0, 1fr));", columns))
+        }
+        TableLayout::Cascaded => ("cascaded", String::new()),
+    };
+
+    html! {
+        <div class="multi-table-page">
+            <div class="multi-table-toolbar">
+                <h1>{format!("Tables ({})", count)}</h1>
+                if pending > 0 {
+                    <span class="action-count">{format!("{} waiting on you", pending)}</span>
+                }
+                <div class="layout-toggle" role="group" aria-label="Table layout">
+                    <button
+                        class={classes!("btn", "btn-secondary", (*layout == TableLayout::Tiled).then_some("active"))}
+                        onclick={set_layout(TableLayout::Tiled)}
+                    >
+                        {"Tile"}
+                    </button>
+                    <button
+                        class={classes!("btn", "btn-secondary", (*layout == TableLayout::Cascaded).then_some("active"))}
+                        onclick={set_layout(TableLayout::Cascaded)}
+                    >
+                        {"Cascade"}
+                    </button>
+                </div>
+            </div>
+            <div class={classes!("table-grid", layout_class)} style={grid_style}>
+                {for tables.iter().enumerate().map(|(index, (room_id, state))| {
+                    let focused = tables.focused() == Some(room_id);
+                    let style = match *layout {
+                        TableLayout::Tiled => String::new(),
+                        TableLayout::Cascaded => {
+                            // The focused table always sits on top of the stack
+                            let z_index = if focused { count + 1 } else { index + 1 };
+                            let offset = index * CASCADE_OFFSET_PX;
+                            format!("top: {}px; left: {}px; z-index: {};", offset, offset, z_index)
+                        }
+                    };
+                    html! {
+                        <TableTile
+                            key={room_id.to_string()}
+                            room_id={room_id.to_string()}
+                            state={state.clone()}
+                            hero_id={tables.hero_id()}
+                            focused={focused}
+                            style={style}
+                            on_focus={tables_ctx.focus.clone()}
+                        />
+                    }
+                })}
+            </div>
+        </div>
+    }
+}
+
+// Table Tile Component - compact table with its own action bar
+#[derive(Properties, PartialEq)]
+pub struct TableTileProps {
+    pub room_id: String,
+    pub state: GameState,
+    pub hero_id: Option<Uuid>,
+    pub focused: bool,
+    #[prop_or_default]
+    pub style: String,
+    pub on_focus: Callback<String>,
+}
+
+#[function_component(TableTile)]
+pub fn table_tile(props: &TableTileProps) -> Html {
+    let connection = use_context::<ConnectionContext>();
+    let tile_ref = use_node_ref();
+    let state = &props.state;
+
+    // Bring the table that needs a decision under the keyboard and into view
+    {
+        let tile_ref = tile_ref.clone();
+        use_effect_with((props.focused, state.action_required), move |(focused, action_required)| {
+            if *focused && *action_required {
+                if let Some(tile) = tile_ref.cast::<HtmlElement>() {
+                    let _ = tile.focus();
+                }
+            }
+        });
+    }
+
+    let send = |message: ClientMessage| {
+        let connection = connection.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            if let Some(ref connection) = connection {
+                connection.send.emit(message.clone());
+            }
+        })
+    };
+    let act = |action: PlayerAction| {
+        send(ClientMessage::PlayerAction {
+            room_id: props.room_id.clone(),
+            action,
+        })
+    };
+
+    let on_focus = {
+        let on_focus = props.on_focus.clone();
+        let room_id = props.room_id.clone();
+        Callback::from(move |_: MouseEvent| on_focus.emit(room_id.clone()))
+    };
+
+    let (title, blinds) = match state.current_room {
+        Some(ref room) => (room.name.clone(), format!("${}/${}", room.small_blind, room.big_blind)),
+        None => (format!("Table {}", props.room_id), String::new()),
+    };
+    // Same sizing as the full table's GameActions, minus the slider
+    let structure = state.betting_structure();
+    let betting = state.betting_context(props.hero_id);
+    let limits = structure.bet_limits(&betting);
+    let to_call = betting.to_call();
+    let (raise_action, raise_label) = sized_raise(&betting, &limits, limits.min_to);
+    let pot_raise = (structure != BettingStructure::FixedLimit && !limits.capped)
+        .then(|| limits.clamp(betting.pot_fraction_to(1.0)))
+        .filter(|to| *to > limits.min_to);
+    let all_in = structure == BettingStructure::NoLimit && !limits.capped && limits.max_to > limits.min_to;
+
+    let tile_classes = classes!(
+        "table-tile",
+        props.focused.then_some("focused"),
+        state.action_required.then_some("action-required"),
+    );
+
+    html! {
+        <div class={tile_classes} style={props.style.clone()} ref={tile_ref} tabindex="0" onclick={on_focus}>
+            <div class="table-tile-header">
+                <span class="table-tile-name">{title}</span>
+                <span class="table-tile-blinds">{blinds}</span>
+                if let Some(ref clock) = state.blind_clock {
+                    <span class="table-tile-level">{format!("Level {}", clock.level.level)}</span>
+                }
+                <button
+                    class="table-tile-close"
+                    title="Leave table"
+                    onclick={send(ClientMessage::LeaveRoom { room_id: props.room_id.clone() })}
+                >
+                    {"×"}
+                </button>
+            </div>
+            <div class="table-tile-felt">
+                <div class="table-tile-phase">{phase_label(&state.game_phase)}</div>
+                <div class="table-tile-board">
+                    {for state.community_cards.iter().map(|card| card_view(card, classes!("community-card")))}
+                </div>
+                <div class="table-tile-pot">{format!("Pot: ${}", state.pot)}</div>
+                <div class="table-tile-seats">
+                    {format!("{} players · Hand #{}", state.get_player_count(), state.hand_number)}
+                </div>
+            </div>
+            if let Some(summary) = state.last_hand.as_ref().filter(|_| !state.is_in_hand()) {
+                <HandResultBanner
+                    key={summary.hand_number}
+                    room_id={props.room_id.clone()}
+                    summary={summary.clone()}
+                    hero_id={props.hero_id}
+                />
+            }
+            if let Some(prompt) = state.prompt.clone() {
+                <TablePromptBar room_id={props.room_id.clone()} prompt={prompt} />
+            }
+            if state.action_required {
+                <div class="table-tile-actions">
+                    if let Some(seconds) = state.time_bank {
+                        <span class="table-tile-timer">{format!("{}s", seconds)}</span>
+                    }
+                    <button class="action-btn fold-btn" onclick={act(PlayerAction::Fold)}>{"Fold"}</button>
+                    if to_call > 0 {
+                        <button class="action-btn call-btn" onclick={act(PlayerAction::Call)}>
+                            {format!("Call ${}", to_call)}
+                        </button>
+                    } else {
+                        <button class="action-btn call-btn" onclick={act(PlayerAction::Check)}>{"Check"}</button>
+                    }
+                    <button class="action-btn raise-btn" onclick={act(raise_action)} disabled={limits.capped}>
+                        {raise_label}
+                    </button>
+                    if let Some(to) = pot_raise {
+                        <button class="action-btn raise-btn" onclick={act(sized_raise(&betting, &limits, to).0)}>
+                            {format!("Pot ${}", to)}
+                        </button>
+                    }
+                    if all_in {
+                        <button class="action-btn raise-btn" onclick={act(PlayerAction::AllIn)}>{"All-in"}</button>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
+
+fn phase_label(phase: &GamePhase) -> &'static str {
+    match phase {
+        GamePhase::WaitingForPlayers => "Waiting for players",
+        GamePhase::PreFlop => "Pre-flop",
+        GamePhase::Flop => "Flop",
+        GamePhase::Turn => "Turn",
+        GamePhase::River => "River",
+        GamePhase::ThirdStreet => "Third street",
+        GamePhase::FourthStreet => "Fourth street",
+        GamePhase::FifthStreet => "Fifth street",
+        GamePhase::SixthStreet => "Sixth street",
+        GamePhase::SeventhStreet => "Seventh street",
+        GamePhase::Showdown => "Showdown",
+        GamePhase::HandComplete => "Hand complete",
+    }
+}
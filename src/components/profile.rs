@@ -1,11 +1,693 @@
 use yew::prelude::*;
+use web_sys::HtmlInputElement;
+use gloo_storage::{LocalStorage, Storage};
+use qrcode::{Color, QrCode};
+
+use crate::types::{TwoFactorEnrollment, User};
+use crate::services::auth_service::AuthService;
+use crate::services::alert_service::{AlertContext, AlertPreferences, DesktopPermission};
+use crate::services::sound_service::{Sound, SoundCategory, SoundContext, SoundSettings};
+use crate::graphics::animations::MotionPreference;
+use crate::graphics::particle_effects::{EffectBudget, EffectSettings};
+use crate::graphics::theme::{CardBack, Felt, ThemeName, ThemeSettings};
 
 #[function_component(ProfilePage)]
 pub fn profile_page() -> Html {
+    let user = LocalStorage::get::<User>("primo_poker_user").ok();
+
     html! {
         <div class="profile-page">
             <h1>{"Profile"}</h1>
-            <p>{"User profile will be implemented here"}</p>
+            if let Some(user) = user {
+                <section class="profile-section">
+                    <h2>{"Security"}</h2>
+                    <TwoFactorSettings user={user} />
+                </section>
+                <section class="profile-section">
+                    <h2>{"Alerts"}</h2>
+                    <AlertSettings />
+                </section>
+                <section class="profile-section">
+                    <h2>{"Sound"}</h2>
+                    <SoundSettingsPanel />
+                </section>
+                <section class="profile-section">
+                    <h2>{"Table theme"}</h2>
+                    <ThemeSettingsPanel />
+                </section>
+                <section class="profile-section">
+                    <h2>{"Animations"}</h2>
+                    <MotionSettings />
+                </section>
+                <section class="profile-section">
+                    <h2>{"Celebrations"}</h2>
+                    <EffectSettingsPanel />
+                </section>
+            } else {
+                <p>{"Sign in to manage your profile"}</p>
+            }
+        </div>
+    }
+}
+
+// Alert Settings Component - what may call the player back to a background tab
+#[function_component(AlertSettings)]
+pub fn alert_settings() -> Html {
+    let Some(alerts) = use_context::<AlertContext>() else {
+        return html! {};
+    };
+
+    let toggle = |edit: fn(&mut AlertPreferences, bool)| {
+        let preferences = alerts.preferences;
+        let set_preferences = alerts.set_preferences.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut preferences = preferences;
+            edit(&mut preferences, input.checked());
+            set_preferences.emit(preferences);
+        })
+    };
+    let on_request_permission = {
+        let request_permission = alerts.request_permission.clone();
+        Callback::from(move |_: MouseEvent| request_permission.emit(()))
+    };
+    let preferences = alerts.preferences;
+
+    html! {
+        <div class="alert-settings">
+            <p>{"When the tab is in the background the title flashes and the icon shows a badge. Nothing fires while you're looking at the page."}</p>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={preferences.your_turn} onchange={toggle(|p, on| p.your_turn = on)} />
+                <span>{"It's my turn to act"}</span>
+            </label>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={preferences.invitations} onchange={toggle(|p, on| p.invitations = on)} />
+                <span>{"Someone invites me to a table"}</span>
+            </label>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={preferences.seat_available} onchange={toggle(|p, on| p.seat_available = on)} />
+                <span>{"A waitlisted seat opens up"}</span>
+            </label>
+
+            <h3>{"Desktop Notifications"}</h3>
+            {match alerts.permission {
+                DesktopPermission::Unsupported => html! {
+                    <p class="form-hint">{"This browser doesn't support desktop notifications."}</p>
+                },
+                DesktopPermission::Denied => html! {
+                    <p class="form-hint">{"Notifications are blocked for this site. Allow them in your browser's site settings to use them here."}</p>
+                },
+                permission => html! {
+                    <>
+                        <label class="checkbox-label">
+                            <input type="checkbox" checked={preferences.desktop} onchange={toggle(|p, on| p.desktop = on)} />
+                            <span>{"Also show a desktop notification"}</span>
+                        </label>
+                        if preferences.desktop && permission == DesktopPermission::NotAsked {
+                            <button class="btn btn-secondary" onclick={on_request_permission}>
+                                {"Allow notifications"}
+                            </button>
+                        }
+                    </>
+                },
+            }}
+        </div>
+    }
+}
+
+// Sound Settings Component - master and per-category volume, with a preview on release
+#[function_component(SoundSettingsPanel)]
+pub fn sound_settings_panel() -> Html {
+    let Some(sounds) = use_context::<SoundContext>() else {
+        return html! {};
+    };
+    if !sounds.available {
+        return html! {
+            <div class="sound-settings">
+                <p class="form-hint">{"This browser doesn't support Web Audio, so table sounds are off."}</p>
+            </div>
+        };
+    }
+
+    let settings = sounds.settings;
+    let on_mute = {
+        let set_settings = sounds.set_settings.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            set_settings.emit(SoundSettings { muted: input.checked(), ..settings });
+        })
+    };
+    // Sliders report 0-100; each change plays a sample at the new level
+    let slider = |label: &'static str, value: f32, category: Option<SoundCategory>| {
+        let set_settings = sounds.set_settings.clone();
+        let preview = sounds.preview.clone();
+        let on_change = Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let volume = input.value().parse::<f32>().unwrap_or(0.0) / 100.0;
+            let mut settings = settings;
+            match category {
+                Some(category) => settings.set_volume(category, volume),
+                None => settings.master = volume.clamp(0.0, 1.0),
+            }
+            set_settings.emit(settings);
+            preview.emit(category.map_or(Sound::Chips, SoundCategory::sample));
+        });
+        html! {
+            <label class="volume-slider">
+                <span>{label}</span>
+                <input
+                    type="range"
+                    min="0"
+                    max="100"
+                    value={((value * 100.0).round() as i32).to_string()}
+                    disabled={settings.muted}
+                    onchange={on_change}
+                />
+                <span class="volume-value">{format!("{}%", (value * 100.0).round())}</span>
+            </label>
+        }
+    };
+
+    html! {
+        <div class="sound-settings">
+            <label class="checkbox-label">
+                <input type="checkbox" checked={settings.muted} onchange={on_mute} />
+                <span>{"Mute all sounds"}</span>
+            </label>
+            {slider("Master", settings.master, None)}
+            {for SoundCategory::ALL.into_iter().map(|category| slider(category.label(), settings.volume(category), Some(category)))}
         </div>
     }
 }
+
+// Motion Settings Component - whether the canvas table animates deals, bets and pots
+#[function_component(MotionSettings)]
+pub fn motion_settings() -> Html {
+    let preference = use_state(MotionPreference::load);
+
+    let option = |choice: MotionPreference| {
+        let on_change = {
+            let preference = preference.clone();
+            Callback::from(move |_: Event| {
+                choice.save();
+                preference.set(choice);
+            })
+        };
+        html! {
+            <label class="checkbox-label">
+                <input type="radio" name="motion" checked={*preference == choice} onchange={on_change} />
+                <span>{choice.label()}</span>
+            </label>
+        }
+    };
+
+    html! {
+        <div class="motion-settings">
+            <p>{"Cards slide out from the dealer, bets sweep into the pot and stacks count up when you win. Changes apply the next time a table opens."}</p>
+            {for MotionPreference::ALL.into_iter().map(option)}
+        </div>
+    }
+}
+
+// Theme Settings Component - colours, felt, card backs and deck
+#[function_component(ThemeSettingsPanel)]
+pub fn theme_settings_panel() -> Html {
+    let settings = use_state(ThemeSettings::load);
+
+    // The DOM table follows at once through the CSS variables
+    let choose = |changed: ThemeSettings| {
+        let settings = settings.clone();
+        Callback::from(move |_: Event| {
+            changed.save();
+            changed.apply();
+            settings.set(changed);
+        })
+    };
+    let theme = |choice: ThemeName| html! {
+        <label class="checkbox-label">
+            <input
+                type="radio"
+                name="theme"
+                checked={settings.theme == choice}
+                onchange={choose(ThemeSettings { theme: choice, ..*settings })}
+            />
+            <span>{choice.label()}</span>
+        </label>
+    };
+    let felt = |choice: Felt| html! {
+        <label class="theme-swatch" title={choice.label()}>
+            <input
+                type="radio"
+                name="felt"
+                checked={settings.felt == choice}
+                onchange={choose(ThemeSettings { felt: choice, ..*settings })}
+            />
+            <span class="swatch" style={format!("background: {};", choice.color())}></span>
+            <span>{choice.label()}</span>
+        </label>
+    };
+    let card_back = |choice: CardBack| {
+        let (color, light) = choice.colors();
+        let preview = match choice.artwork() {
+            Some(url) => format!("background: url(\"{}\") center / cover, {};", url, color),
+            None => format!("background: linear-gradient(45deg, {}, {});", color, light),
+        };
+        html! {
+            <label class="theme-swatch">
+                <input
+                    type="radio"
+                    name="card-back"
+                    checked={settings.card_back == choice}
+                    onchange={choose(ThemeSettings { card_back: choice, ..*settings })}
+                />
+                <span class="swatch card-back-swatch" style={preview}></span>
+                <span>{choice.label()}</span>
+            </label>
+        }
+    };
+    let on_four_color = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let changed = ThemeSettings { four_color: input.checked(), ..*settings };
+            changed.save();
+            changed.apply();
+            settings.set(changed);
+        })
+    };
+
+    html! {
+        <div class="theme-settings">
+            <p>{"Canvas tables pick up changes the next time one opens."}</p>
+            {for ThemeName::ALL.into_iter().map(theme)}
+            <span class="theme-group-label">{"Felt"}</span>
+            <div class="theme-swatches">{for Felt::ALL.into_iter().map(felt)}</div>
+            <span class="theme-group-label">{"Card backs"}</span>
+            <div class="theme-swatches">{for CardBack::ALL.into_iter().map(card_back)}</div>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={settings.four_color} onchange={on_four_color} />
+                <span>{"Four-colour deck (blue diamonds, green clubs)"}</span>
+            </label>
+        </div>
+    }
+}
+
+// Effect Settings Component - confetti and chip bursts for big moments
+#[function_component(EffectSettingsPanel)]
+pub fn effect_settings_panel() -> Html {
+    let settings = use_state(EffectSettings::load);
+
+    let update = |edit: fn(&mut EffectSettings, &HtmlInputElement)| {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut changed = *settings;
+            edit(&mut changed, &input);
+            changed.save();
+            settings.set(changed);
+        })
+    };
+    let on_toggle = update(|settings, input| settings.enabled = input.checked());
+    let on_multiple = update(|settings, input| {
+        if let Ok(multiple) = input.value().parse() {
+            settings.big_pot_multiple = multiple;
+        }
+    });
+    let budget = |choice: EffectBudget| {
+        let on_change = {
+            let settings = settings.clone();
+            Callback::from(move |_: Event| {
+                let changed = EffectSettings { budget: choice, ..*settings };
+                changed.save();
+                settings.set(changed);
+            })
+        };
+        html! {
+            <label class="checkbox-label">
+                <input
+                    type="radio"
+                    name="effect-budget"
+                    checked={settings.budget == choice}
+                    disabled={!settings.enabled}
+                    onchange={on_change}
+                />
+                <span>{choice.label()}</span>
+            </label>
+        }
+    };
+
+    html! {
+        <div class="effect-settings">
+            <p>{"Confetti for straight and royal flushes and tournament wins, and a burst of chips for big pots. Nothing plays while animations are reduced."}</p>
+            <label class="checkbox-label">
+                <input type="checkbox" checked={settings.enabled} onchange={on_toggle} />
+                <span>{"Play celebrations"}</span>
+            </label>
+            <label class="effect-multiple">
+                <span>{"A big pot is at least"}</span>
+                <select onchange={on_multiple} disabled={!settings.enabled}>
+                    {for EffectSettings::POT_MULTIPLES.into_iter().map(|multiple| html! {
+                        <option value={multiple.to_string()} selected={settings.big_pot_multiple == multiple}>
+                            {format!("{} big blinds", multiple)}
+                        </option>
+                    })}
+                </select>
+            </label>
+            <span class="effect-budget-label">{"Detail"}</span>
+            {for EffectBudget::ALL.into_iter().map(budget)}
+        </div>
+    }
+}
+
+// Two-Factor Settings Component
+#[derive(Properties, PartialEq)]
+pub struct TwoFactorSettingsProps {
+    pub user: User,
+}
+
+pub struct TwoFactorSettings {
+    enabled: bool,
+    enrollment: Option<TwoFactorEnrollment>,
+    recovery_codes: Option<Vec<String>>, // shown once, right after enrollment
+    disabling: bool,
+    code: String,
+    loading: bool,
+    error_message: Option<String>,
+    auth_service: AuthService,
+}
+
+pub enum TwoFactorSettingsMsg {
+    StartEnrollment,
+    EnrollmentStarted(TwoFactorEnrollment),
+    ConfirmEnrollment,
+    EnrollmentConfirmed,
+    StartDisable,
+    ConfirmDisable,
+    Disabled,
+    Cancel,
+    UpdateCode(String),
+    DismissRecoveryCodes,
+    Error(String),
+}
+
+impl Component for TwoFactorSettings {
+    type Message = TwoFactorSettingsMsg;
+    type Properties = TwoFactorSettingsProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let auth_service = AuthService::new(Callback::noop(), Callback::noop());
+
+        Self {
+            enabled: auth_service.is_two_factor_enabled(&ctx.props().user.username),
+            enrollment: None,
+            recovery_codes: None,
+            disabling: false,
+            code: String::new(),
+            loading: false,
+            error_message: None,
+            auth_service,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            TwoFactorSettingsMsg::StartEnrollment => {
+                self.loading = true;
+                self.error_message = None;
+
+                let auth_service = self.auth_service.clone();
+                let user = ctx.props().user.clone();
+                let link = ctx.link().clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    match auth_service.begin_two_factor_enrollment(&user).await {
+                        Ok(enrollment) => link.send_message(TwoFactorSettingsMsg::EnrollmentStarted(enrollment)),
+                        Err(error) => link.send_message(TwoFactorSettingsMsg::Error(error.to_string())),
+                    }
+                });
+                true
+            }
+            TwoFactorSettingsMsg::EnrollmentStarted(enrollment) => {
+                self.loading = false;
+                self.code.clear();
+                self.enrollment = Some(enrollment);
+                true
+            }
+            TwoFactorSettingsMsg::ConfirmEnrollment => {
+                if self.code.trim().is_empty() {
+                    self.error_message = Some("Enter the code shown in your authenticator app".to_string());
+                    return true;
+                }
+
+                self.loading = true;
+                self.error_message = None;
+
+                let auth_service = self.auth_service.clone();
+                let user = ctx.props().user.clone();
+                let code = self.code.clone();
+                let link = ctx.link().clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    match auth_service.confirm_two_factor_enrollment(&user, code).await {
+                        Ok(()) => link.send_message(TwoFactorSettingsMsg::EnrollmentConfirmed),
+                        Err(error) => link.send_message(TwoFactorSettingsMsg::Error(error.to_string())),
+                    }
+                });
+                true
+            }
+            TwoFactorSettingsMsg::EnrollmentConfirmed => {
+                self.loading = false;
+                self.enabled = true;
+                self.code.clear();
+                self.recovery_codes = self.enrollment.take().map(|e| e.recovery_codes);
+                true
+            }
+            TwoFactorSettingsMsg::StartDisable => {
+                self.disabling = true;
+                self.code.clear();
+                self.error_message = None;
+                true
+            }
+            TwoFactorSettingsMsg::ConfirmDisable => {
+                if self.code.trim().is_empty() {
+                    self.error_message = Some("Enter an authentication or recovery code".to_string());
+                    return true;
+                }
+
+                self.loading = true;
+                self.error_message = None;
+
+                let auth_service = self.auth_service.clone();
+                let user = ctx.props().user.clone();
+                let code = self.code.clone();
+                let link = ctx.link().clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    match auth_service.disable_two_factor(&user, code).await {
+                        Ok(()) => link.send_message(TwoFactorSettingsMsg::Disabled),
+                        Err(error) => link.send_message(TwoFactorSettingsMsg::Error(error.to_string())),
+                    }
+                });
+                true
+            }
+            TwoFactorSettingsMsg::Disabled => {
+                self.loading = false;
+                self.enabled = false;
+                self.disabling = false;
+                self.code.clear();
+                true
+            }
+            TwoFactorSettingsMsg::Cancel => {
+                self.enrollment = None;
+                self.disabling = false;
+                self.code.clear();
+                self.error_message = None;
+                true
+            }
+            TwoFactorSettingsMsg::UpdateCode(code) => {
+                self.code = code;
+                self.error_message = None;
+                true
+            }
+            TwoFactorSettingsMsg::DismissRecoveryCodes => {
+                self.recovery_codes = None;
+                true
+            }
+            TwoFactorSettingsMsg::Error(error) => {
+                self.loading = false;
+                self.error_message = Some(error);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        let body = if let Some(ref codes) = self.recovery_codes {
+            self.render_recovery_codes(ctx, codes)
+        } else if let Some(ref enrollment) = self.enrollment {
+            self.render_enrollment(ctx, enrollment)
+        } else if self.disabling {
+            self.render_disable(ctx)
+        } else if self.enabled {
+            html! {
+                <div class="two-factor-status">
+                    <span class="status-badge enabled">{"Enabled"}</span>
+                    <p>{"A code from your authenticator app is required each time you sign in."}</p>
+                    <button class="btn btn-danger" onclick={link.callback(|_| TwoFactorSettingsMsg::StartDisable)}>
+                        {"Disable two-factor authentication"}
+                    </button>
+                </div>
+            }
+        } else {
+            html! {
+                <div class="two-factor-status">
+                    <span class="status-badge disabled">{"Disabled"}</span>
+                    <p>{"Protect your chips by requiring a code from an authenticator app when signing in."}</p>
+                    <button
+                        class="btn btn-primary"
+                        onclick={link.callback(|_| TwoFactorSettingsMsg::StartEnrollment)}
+                        disabled={self.loading}
+                    >
+                        {"Enable two-factor authentication"}
+                    </button>
+                </div>
+            }
+        };
+
+        html! {
+            <div class="two-factor-settings">
+                <h3>{"Two-Factor Authentication"}</h3>
+                if let Some(ref error) = self.error_message {
+                    <div class="form-error">{error}</div>
+                }
+                {body}
+            </div>
+        }
+    }
+}
+
+impl TwoFactorSettings {
+    fn render_code_input(&self, ctx: &Context<Self>) -> Html {
+        let on_code_input = ctx.link().callback(|e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            TwoFactorSettingsMsg::UpdateCode(input.value())
+        });
+
+        html! {
+            <input
+                type="text"
+                class="form-input totp-code-input"
+                placeholder="123456"
+                value={self.code.clone()}
+                oninput={on_code_input}
+                disabled={self.loading}
+                autocomplete="one-time-code"
+                inputmode="numeric"
+            />
+        }
+    }
+
+    fn render_enrollment(&self, ctx: &Context<Self>, enrollment: &TwoFactorEnrollment) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <div class="two-factor-enrollment">
+                <ol class="enrollment-steps">
+                    <li>{"Scan this QR code with your authenticator app."}</li>
+                    <li>{"Enter the 6-digit code it shows to finish setup."}</li>
+                </ol>
+                <QrCodeSvg data={enrollment.otpauth_uri.clone()} />
+                <details class="manual-entry">
+                    <summary>{"Can't scan? Enter the key manually"}</summary>
+                    <code class="totp-secret">{&enrollment.secret}</code>
+                </details>
+                {self.render_code_input(ctx)}
+                <div class="two-factor-actions">
+                    <button class="btn btn-secondary" onclick={link.callback(|_| TwoFactorSettingsMsg::Cancel)} disabled={self.loading}>
+                        {"Cancel"}
+                    </button>
+                    <button class="btn btn-primary" onclick={link.callback(|_| TwoFactorSettingsMsg::ConfirmEnrollment)} disabled={self.loading}>
+                        {"Verify and enable"}
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn render_disable(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <div class="two-factor-disable">
+                <p>{"Enter a code from your authenticator app or a recovery code to turn off two-factor authentication."}</p>
+                {self.render_code_input(ctx)}
+                <div class="two-factor-actions">
+                    <button class="btn btn-secondary" onclick={link.callback(|_| TwoFactorSettingsMsg::Cancel)} disabled={self.loading}>
+                        {"Cancel"}
+                    </button>
+                    <button class="btn btn-danger" onclick={link.callback(|_| TwoFactorSettingsMsg::ConfirmDisable)} disabled={self.loading}>
+                        {"Disable"}
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn render_recovery_codes(&self, ctx: &Context<Self>, codes: &[String]) -> Html {
+        html! {
+            <div class="recovery-codes">
+                <p>
+                    {"Two-factor authentication is now enabled. Save these recovery codes somewhere safe — "}
+                    {"each one can be used once if you lose access to your authenticator app."}
+                </p>
+                <ul class="recovery-code-list">
+                    {for codes.iter().map(|code| html! { <li><code>{code}</code></li> })}
+                </ul>
+                <button class="btn btn-primary" onclick={ctx.link().callback(|_| TwoFactorSettingsMsg::DismissRecoveryCodes)}>
+                    {"I've saved my codes"}
+                </button>
+            </div>
+        }
+    }
+}
+
+// QR Code Component - renders the code as a single SVG path so no image
+// assets or network requests are involved
+#[derive(Properties, PartialEq)]
+pub struct QrCodeSvgProps {
+    pub data: String,
+}
+
+#[function_component(QrCodeSvg)]
+pub fn qr_code_svg(props: &QrCodeSvgProps) -> Html {
+    const QUIET_ZONE: usize = 4; // modules of white border required by the spec
+
+    let Ok(code) = QrCode::new(props.data.as_bytes()) else {
+        return html! { <div class="form-error">{"Unable to render QR code"}</div> };
+    };
+
+    let width = code.width();
+    let path: String = code
+        .to_colors()
+        .iter()
+        .enumerate()
+        .filter(|(_, color)| **color == Color::Dark)
+        .map(|(i, _)| format!("M{},{}h1v1h-1z", i % width + QUIET_ZONE, i / width + QUIET_ZONE))
+        .collect();
+    let size = width + QUIET_ZONE * 2;
+
+    html! {
+        <svg
+            class="qr-code"
+            viewBox={format!("0 0 {} {}", size, size)}
+            shape-rendering="crispEdges"
+            role="img"
+            aria-label="Two-factor enrollment QR code"
+        >
+            <rect width={size.to_string()} height={size.to_string()} fill="#ffffff" />
+            <path d={path} fill="#000000" />
+        </svg>
+    }
+}
@@ -1,8 +1,10 @@
 // Game state management
 use yew::prelude::*;
-use crate::types::{PlayerInfo, RoomInfo, GamePhase, Card, GameAction, HandWinner, PotWinner};
+use crate::types::{PlayerInfo, RoomInfo, GamePhase, Card, GameAction, HandWinner, PotWinner, ServerMessage, ShownHand, BlindLevel, GameType, PotShare, PlayerAction, BettingStructure};
+use crate::rules::{best_low, evaluate_low, BettingContext};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GameState {
@@ -19,6 +21,106 @@ pub struct GameState {
     pub hand_history: Vec<GameAction>,
     pub hand_number: u32,
     pub time_bank: Option<i32>, // seconds remaining for current player
+    pub action_required: bool, // the server is waiting on our action at this table
+    pub blind_clock: Option<BlindClock>, // tournament tables only
+    pub last_hand: Option<HandSummary>,
+    pub last_raise: i64,    // size of the last bet or raise this street
+    pub street_raises: u32, // bets and raises this street, for fixed-limit caps
+    pub prompt: Option<TablePrompt>,
+    pub straddle: Option<i64>,      // straddle posted this hand
+    pub run_it_twice: Option<bool>, // all-in players' decision, once made
+    pub second_board: Vec<Card>,    // only dealt when the hand is run twice
+    pub paused: bool,                       // the room owner has paused the game
+    pub pending_blinds: Option<(i64, i64)>, // small/big blind from the next hand on
+}
+
+// A decision the server offers us outside the normal action order
+#[derive(Debug, Clone, PartialEq)]
+pub enum TablePrompt {
+    Straddle { amount: i64, time_limit: i32 },
+    RunItTwice { time_limit: i32 },
+}
+
+// Result of the last completed hand, resolved for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandSummary {
+    pub hand_number: u32,
+    pub hi_lo: bool,
+    pub winners: Vec<HandResultLine>,
+    pub boards: Vec<Vec<Card>>, // both boards when the hand was run twice, otherwise empty
+    pub board: Vec<Card>,       // the final board, kept after the table clears for the next hand
+    pub shown: Vec<RevealedHand>,
+    pub winning_cards: Vec<Card>, // every winner's best five
+    pub uncontested: bool,        // everyone else folded, so nothing was shown
+}
+
+// Hole cards turned face up at showdown
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevealedHand {
+    pub player_id: Uuid,
+    pub player_name: String,
+    pub cards: Vec<Card>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandResultLine {
+    pub player_id: Uuid,
+    pub player_name: String,
+    pub share: PotShare,
+    pub amount: i64,
+    pub description: String, // e.g. "Flush" or "8-5-4-3-A low"
+    pub board: u8,
+}
+
+impl HandSummary {
+    /// In a hi/lo game with no qualifying low the high hand takes the whole pot.
+    pub fn low_qualified(&self) -> bool {
+        self.winners
+            .iter()
+            .any(|line| matches!(line.share, PotShare::Low | PotShare::Scoop))
+    }
+
+    pub fn shown_cards(&self, player_id: Uuid) -> Option<&[Card]> {
+        self.shown.iter().find(|hand| hand.player_id == player_id).map(|hand| hand.cards.as_slice())
+    }
+
+    /// Cards to pick out on the board and in hand. An uncontested win gives nothing away
+    /// until the winner shows.
+    pub fn is_winning_card(&self, card: &Card) -> bool {
+        (!self.uncontested || !self.shown.is_empty()) && *card != Card::Unknown && self.winning_cards.contains(card)
+    }
+
+    /// The hand name to announce for a winner, hidden while an uncontested winner keeps it to themselves.
+    pub fn hand_name<'a>(&'a self, line: &'a HandResultLine) -> &'a str {
+        if self.uncontested && self.shown_cards(line.player_id).is_none() {
+            "Uncontested"
+        } else {
+            &line.description
+        }
+    }
+
+    /// The hero may show or muck only after winning without a showdown, and only once.
+    pub fn hero_may_show(&self, hero_id: Option<Uuid>) -> bool {
+        let Some(hero_id) = hero_id else {
+            return false;
+        };
+        self.uncontested
+            && self.winners.iter().any(|line| line.player_id == hero_id)
+            && self.shown_cards(hero_id).is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlindClock {
+    pub level: BlindLevel,
+    pub next_level: Option<BlindLevel>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl BlindClock {
+    pub fn seconds_remaining(&self, now: DateTime<Utc>) -> i64 {
+        (self.ends_at - now).num_seconds().max(0)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -43,6 +145,17 @@ impl Default for GameState {
             hand_history: Vec::new(),
             hand_number: 0,
             time_bank: None,
+            action_required: false,
+            blind_clock: None,
+            last_hand: None,
+            last_raise: 0,
+            street_raises: 0,
+            prompt: None,
+            straddle: None,
+            run_it_twice: None,
+            second_board: Vec::new(),
+            paused: false,
+            pending_blinds: None,
         }
     }
 }
@@ -73,6 +186,17 @@ impl GameState {
     }
     
     pub fn update_game_phase(&mut self, phase: GamePhase, community_cards: Vec<Card>, pot: i64) {
+        if self.game_phase != phase {
+            // New street: betting starts over. Pre-flop the big blind is the
+            // opening bet, so it sets the price and counts toward the raise cap
+            let blind = match phase {
+                GamePhase::PreFlop => self.current_room.as_ref().map_or(0, |room| room.big_blind),
+                _ => 0,
+            };
+            self.current_bet = blind;
+            self.last_raise = blind;
+            self.street_raises = u32::from(blind > 0);
+        }
         self.game_phase = phase;
         self.community_cards = community_cards;
         self.pot = pot;
@@ -84,11 +208,48 @@ impl GameState {
     }
     
     pub fn add_action(&mut self, action: GameAction) {
+        match action.action {
+            PlayerAction::Bet(to) | PlayerAction::Raise(to) if to > self.current_bet => {
+                self.last_raise = to - self.current_bet;
+                self.current_bet = to;
+                self.street_raises += 1;
+            }
+            // The bring-in opens third street; completing it is the first full bet
+            PlayerAction::BringIn => {
+                let bring_in = self.current_room.as_ref().map_or(0, |room| room.bring_in);
+                if bring_in > self.current_bet {
+                    self.current_bet = bring_in;
+                    self.last_raise = bring_in;
+                }
+            }
+            _ => {}
+        }
         self.hand_history.push(action.clone());
         self.last_action = Some(action);
     }
     
-    pub fn complete_hand(&mut self, winners: Vec<HandWinner>, pot_distribution: Vec<PotWinner>) {
+    /// A straddle is a voluntary third blind, so it sets the bet to call and the minimum raise.
+    pub fn post_straddle(&mut self, player_id: Uuid, amount: i64) {
+        // Whatever the straddler already had in counts toward the straddle
+        let added = self.players.get_mut(&player_id).map_or(amount, |player| {
+            let added = amount - player.current_bet;
+            player.chips -= added;
+            player.current_bet = amount;
+            added
+        });
+        self.pot += added;
+        self.current_bet = amount;
+        self.last_raise = amount;
+        self.street_raises += 1; // a live raise of the big blind
+        self.straddle = Some(amount);
+        if matches!(self.prompt, Some(TablePrompt::Straddle { .. })) {
+            self.prompt = None;
+        }
+    }
+
+    pub fn complete_hand(&mut self, winners: Vec<HandWinner>, pot_distribution: Vec<PotWinner>, shown: Vec<ShownHand>) {
+        self.last_hand = Some(self.summarize_hand(&winners, &pot_distribution, shown));
+
         // Update player chip counts based on winnings
         for pot_winner in pot_distribution {
             if let Some(player) = self.players.get_mut(&pot_winner.player_id) {
@@ -105,8 +266,16 @@ impl GameState {
         self.active_player = None;
         self.last_action = None;
         self.hand_history.clear();
+        self.last_raise = 0;
+        self.street_raises = 0;
+        self.prompt = None;
+        self.straddle = None;
+        self.run_it_twice = None;
+        self.second_board.clear();
+        self.apply_pending_blinds();
         self.hand_number += 1;
         self.time_bank = None;
+        self.action_required = false;
         
         // Reset player hand states
         for player in self.players.values_mut() {
@@ -118,6 +287,140 @@ impl GameState {
         }
     }
     
+    /// Owners change stakes between hands; mid-hand changes wait for the hand to finish.
+    pub fn schedule_blinds(&mut self, small_blind: i64, big_blind: i64) {
+        self.pending_blinds = Some((small_blind, big_blind));
+        if !self.is_in_hand() {
+            self.apply_pending_blinds();
+        }
+    }
+
+    fn apply_pending_blinds(&mut self) {
+        if let (Some((small_blind, big_blind)), Some(room)) = (self.pending_blinds.take(), self.current_room.as_mut()) {
+            room.small_blind = small_blind;
+            room.big_blind = big_blind;
+        }
+    }
+
+    pub fn is_owner(&self, player_id: Option<Uuid>) -> bool {
+        player_id.is_some() && self.current_room.as_ref().is_some_and(|room| room.owner_id == player_id)
+    }
+
+    pub fn game_type(&self) -> GameType {
+        self.current_room
+            .as_ref()
+            .map_or_else(GameType::default, |room| room.game_type.clone())
+    }
+
+    pub fn betting_structure(&self) -> BettingStructure {
+        self.current_room
+            .as_ref()
+            .map_or_else(BettingStructure::default, |room| room.betting_structure)
+    }
+
+    pub fn betting_context(&self, hero_id: Option<Uuid>) -> BettingContext {
+        let hero = hero_id.and_then(|id| self.players.get(&id));
+        BettingContext {
+            pot: self.pot,
+            current_bet: self.current_bet,
+            player_bet: hero.map_or(0, |player| player.current_bet),
+            player_stack: hero.map_or(0, |player| player.chips),
+            big_blind: self.current_room.as_ref().map_or(0, |room| room.game_type.rules().small_bet(room)),
+            last_raise: self.last_raise,
+            raises: self.street_raises,
+            big_bet_street: self.game_type().rules().is_big_bet_street(&self.game_phase),
+        }
+    }
+
+    /// A winner's hole cards, turned up after the hand was won uncontested.
+    pub fn show_hand(&mut self, player_id: Uuid, cards: Vec<Card>) {
+        let player_name = self.player_name(player_id);
+        if let Some(summary) = self.last_hand.as_mut().filter(|summary| summary.shown_cards(player_id).is_none()) {
+            summary.shown.push(RevealedHand { player_id, player_name, cards });
+        }
+    }
+
+    fn player_name(&self, player_id: Uuid) -> String {
+        self.players
+            .get(&player_id)
+            .map_or_else(|| "Player".to_string(), |player| player.display_name.clone())
+    }
+
+    // Hole cards as shown down at showdown, else as far as this client knows them
+    fn hole_cards(&self, player_id: Uuid, shown: &[ShownHand]) -> Vec<Card> {
+        shown
+            .iter()
+            .find(|hand| hand.player_id == player_id)
+            .map(|hand| hand.cards.clone())
+            .or_else(|| self.players.get(&player_id).map(|player| player.hand.clone()))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|card| *card != Card::Unknown)
+            .collect()
+    }
+
+    fn summarize_hand(&self, winners: &[HandWinner], pot_distribution: &[PotWinner], shown: Vec<ShownHand>) -> HandSummary {
+        let rules = self.game_type().rules();
+        let lines = winners
+            .iter()
+            .map(|winner| {
+                let player_name = self.player_name(winner.player_id);
+                let amount = pot_distribution
+                    .iter()
+                    .filter(|pot| {
+                        pot.player_id == winner.player_id && pot.share == winner.share && pot.board == winner.board
+                    })
+                    .map(|pot| pot.amount)
+                    .sum();
+                // A low winner's cards are its low five; a scoop lists the high five,
+                // so its low comes from the hole cards and the board it played
+                let low = match winner.share {
+                    PotShare::Low => rules.low_qualifier.and_then(|qualifier| evaluate_low(&winner.cards, qualifier)),
+                    PotShare::Scoop => {
+                        let board = if winner.board == 1 { &self.second_board } else { &self.community_cards };
+                        best_low(rules, &self.hole_cards(winner.player_id, &shown), board)
+                    }
+                    _ => None,
+                }
+                .map(|low| format!("{} low", low));
+                let description = match (winner.share, low) {
+                    (PotShare::Low, Some(low)) => low,
+                    (PotShare::Scoop, Some(low)) => format!("{} and {}", winner.hand_type, low),
+                    _ => winner.hand_type.to_string(),
+                };
+                HandResultLine { player_id: winner.player_id, player_name, share: winner.share, amount, description, board: winner.board }
+            })
+            .collect();
+        let boards = if self.second_board.is_empty() {
+            Vec::new()
+        } else {
+            vec![self.community_cards.clone(), self.second_board.clone()]
+        };
+
+        let uncontested = shown.is_empty();
+        let shown = shown
+            .into_iter()
+            .map(|hand| RevealedHand { player_id: hand.player_id, player_name: self.player_name(hand.player_id), cards: hand.cards })
+            .collect();
+        let mut winning_cards: Vec<Card> = Vec::new();
+        for card in winners.iter().flat_map(|winner| &winner.cards) {
+            if !winning_cards.contains(card) {
+                winning_cards.push(*card);
+            }
+        }
+
+        HandSummary {
+            hand_number: self.hand_number,
+            hi_lo: rules.is_hi_lo(),
+            winners: lines,
+            boards,
+            board: self.community_cards.clone(),
+            shown,
+            winning_cards,
+            uncontested,
+        }
+    }
+
     pub fn get_seated_players(&self) -> Vec<&PlayerInfo> {
         let mut seated: Vec<_> = self.players.values().collect();
         seated.sort_by_key(|p| p.seat_position);
@@ -135,7 +438,16 @@ impl GameState {
     pub fn is_in_hand(&self) -> bool {
         matches!(
             self.game_phase,
-            GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn | GamePhase::River | GamePhase::Showdown
+            GamePhase::PreFlop
+                | GamePhase::Flop
+                | GamePhase::Turn
+                | GamePhase::River
+                | GamePhase::ThirdStreet
+                | GamePhase::FourthStreet
+                | GamePhase::FifthStreet
+                | GamePhase::SixthStreet
+                | GamePhase::SeventhStreet
+                | GamePhase::Showdown
         )
     }
     
@@ -143,3 +455,249 @@ impl GameState {
         self.active_player == Some(player_id) && self.is_in_hand()
     }
 }
+
+// Every table the player is seated at, keyed by room id
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableSet {
+    tables: HashMap<String, GameState>,
+    order: Vec<String>,             // join order, keeps tile placement stable
+    action_queue: VecDeque<String>, // tables waiting on us, oldest first
+    focused: Option<String>,
+    hero_id: Option<Uuid>,
+    moved: HashMap<String, String>, // tournament table moves, old room id -> new room id
+    kicked: HashSet<String>,        // rooms the host removed us from, until we join again
+}
+
+impl TableSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_hero(&mut self, hero_id: Option<Uuid>) {
+        self.hero_id = hero_id;
+    }
+
+    pub fn hero_id(&self) -> Option<Uuid> {
+        self.hero_id
+    }
+
+    pub fn get(&self, room_id: &str) -> Option<&GameState> {
+        self.tables.get(room_id)
+    }
+
+    pub fn contains(&self, room_id: &str) -> bool {
+        self.tables.contains_key(room_id)
+    }
+
+    /// Tables in the order they were joined.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &GameState)> {
+        self.order
+            .iter()
+            .filter_map(|room_id| self.tables.get(room_id).map(|state| (room_id.as_str(), state)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Where the server moved us if a tournament table we sat at was broken up.
+    pub fn moved_to(&self, room_id: &str) -> Option<&str> {
+        self.moved.get(room_id).map(String::as_str)
+    }
+
+    pub fn was_kicked(&self, room_id: &str) -> bool {
+        self.kicked.contains(room_id)
+    }
+
+    pub fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    pub fn focus(&mut self, room_id: &str) {
+        if self.contains(room_id) {
+            self.focused = Some(room_id.to_string());
+        }
+    }
+
+    pub fn pending_action_count(&self) -> usize {
+        self.action_queue.len()
+    }
+
+    /// Applies a room-scoped server message to the table it belongs to.
+    /// Returns false when the message doesn't concern any open table.
+    pub fn apply(&mut self, message: ServerMessage) -> bool {
+        match message {
+            ServerMessage::RoomJoined { room, players } => {
+                let room_id = room.id.clone();
+                self.kicked.remove(&room_id);
+                if !self.order.contains(&room_id) {
+                    self.order.push(room_id.clone());
+                }
+                self.tables.entry(room_id.clone()).or_default().join_room(room, players);
+                if self.focused.is_none() {
+                    self.focused = Some(room_id);
+                }
+                true
+            }
+            ServerMessage::RoomLeft { room_id } => {
+                if self.tables.remove(&room_id).is_none() {
+                    return false;
+                }
+                self.order.retain(|id| *id != room_id);
+                self.action_queue.retain(|id| *id != room_id);
+                if self.focused.as_deref() == Some(room_id.as_str()) {
+                    self.focused = self.action_queue.front().or(self.order.first()).cloned();
+                }
+                true
+            }
+            ServerMessage::PlayerJoined { room_id, player } => {
+                self.with_table(&room_id, |state| state.add_player(player))
+            }
+            ServerMessage::PlayerLeft { room_id, player_id } => {
+                self.with_table(&room_id, |state| state.remove_player(player_id))
+            }
+            ServerMessage::GameStateUpdate { room_id, phase, community_cards, pot } => {
+                self.with_table(&room_id, |state| state.update_game_phase(phase, community_cards, pot))
+            }
+            ServerMessage::BlindLevelChanged { room_id, level, next_level, ends_at } => {
+                self.with_table(&room_id, |state| {
+                    state.blind_clock = Some(BlindClock { level, next_level, ends_at });
+                })
+            }
+            ServerMessage::TableMoved { from_room_id, room, players, .. } => {
+                let Some(previous) = self.tables.remove(&from_room_id) else {
+                    return false;
+                };
+                let room_id = room.id.clone();
+                let mut state = GameState::new();
+                state.blind_clock = previous.blind_clock;
+                state.join_room(room, players);
+                self.tables.insert(room_id.clone(), state);
+
+                // The new table takes the old one's tile and focus
+                for id in self.order.iter_mut().filter(|id| **id == from_room_id) {
+                    *id = room_id.clone();
+                }
+                self.action_queue.retain(|id| *id != from_room_id);
+                if self.focused.as_deref() == Some(from_room_id.as_str()) {
+                    self.focused = Some(room_id.clone());
+                }
+                self.moved.insert(from_room_id, room_id);
+                true
+            }
+            ServerMessage::PlayerActionRequired { room_id, time_limit } => {
+                let hero_id = self.hero_id;
+                if !self.with_table(&room_id, |state| {
+                    state.action_required = true;
+                    state.set_active_player(hero_id, Some(time_limit));
+                }) {
+                    return false;
+                }
+                if !self.action_queue.contains(&room_id) {
+                    self.action_queue.push_back(room_id.clone());
+                }
+                // Don't pull focus away from a table that is itself waiting on us
+                let focused_waiting = self
+                    .focused
+                    .as_deref()
+                    .and_then(|id| self.tables.get(id))
+                    .is_some_and(|state| state.action_required);
+                if !focused_waiting {
+                    self.focused = Some(room_id);
+                }
+                true
+            }
+            ServerMessage::PlayerActionUpdate { room_id, action } => {
+                let ours = self.hero_id == Some(action.player_id);
+                let applied = self.with_table(&room_id, |state| state.add_action(action));
+                if applied && ours {
+                    self.action_taken(&room_id);
+                }
+                applied
+            }
+            ServerMessage::StraddleOffered { room_id, amount, time_limit } => {
+                self.with_table(&room_id, |state| state.prompt = Some(TablePrompt::Straddle { amount, time_limit }))
+            }
+            ServerMessage::StraddlePosted { room_id, player_id, amount } => {
+                self.with_table(&room_id, |state| state.post_straddle(player_id, amount))
+            }
+            ServerMessage::RunItTwiceOffered { room_id, time_limit } => {
+                self.with_table(&room_id, |state| state.prompt = Some(TablePrompt::RunItTwice { time_limit }))
+            }
+            ServerMessage::RunItTwiceDecided { room_id, agreed } => {
+                self.with_table(&room_id, |state| {
+                    state.prompt = None;
+                    state.run_it_twice = Some(agreed);
+                })
+            }
+            ServerMessage::SecondBoardUpdate { room_id, community_cards } => {
+                self.with_table(&room_id, |state| state.second_board = community_cards)
+            }
+            ServerMessage::PlayerKicked { room_id, player_id } => {
+                // Being removed by the owner closes the table like leaving it
+                if self.hero_id == Some(player_id) {
+                    self.kicked.insert(room_id.clone());
+                    return self.apply(ServerMessage::RoomLeft { room_id });
+                }
+                self.with_table(&room_id, |state| state.remove_player(player_id))
+            }
+            ServerMessage::RoomPaused { room_id, paused } => {
+                self.with_table(&room_id, |state| state.paused = paused)
+            }
+            ServerMessage::BlindsChangeScheduled { room_id, small_blind, big_blind } => {
+                self.with_table(&room_id, |state| state.schedule_blinds(small_blind, big_blind))
+            }
+            ServerMessage::HandResult { room_id, winners, pot_distribution, shown } => {
+                let applied = self.with_table(&room_id, |state| state.complete_hand(winners, pot_distribution, shown));
+                if applied {
+                    self.action_taken(&room_id);
+                }
+                applied
+            }
+            ServerMessage::HandShown { room_id, player_id, cards } => {
+                self.with_table(&room_id, |state| state.show_hand(player_id, cards))
+            }
+            _ => false,
+        }
+    }
+
+    /// Clears the pending action at a table and moves focus on to the next
+    /// table that is still waiting.
+    pub fn action_taken(&mut self, room_id: &str) {
+        if let Some(state) = self.tables.get_mut(room_id) {
+            state.action_required = false;
+        }
+        self.action_queue.retain(|id| id != room_id);
+        if self.focused.as_deref() == Some(room_id) {
+            if let Some(next) = self.action_queue.front() {
+                self.focused = Some(next.clone());
+            }
+        }
+    }
+
+    /// Hides a straddle or run-it-twice prompt once we've answered it.
+    pub fn prompt_answered(&mut self, room_id: &str) -> bool {
+        self.with_table(room_id, |state| state.prompt = None)
+    }
+
+    fn with_table(&mut self, room_id: &str, update: impl FnOnce(&mut GameState)) -> bool {
+        match self.tables.get_mut(room_id) {
+            Some(state) => {
+                update(state);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// Shared with every page that shows tables so they read the same per-room state
+#[derive(Clone, PartialEq)]
+pub struct TablesContext {
+    pub tables: TableSet,
+    pub focus: Callback<String>,
+}
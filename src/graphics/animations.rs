@@ -0,0 +1,413 @@
+// Table animations - tweens laid over the Scene between server updates: cards dealt from
+// the button, bets swept into the pot, the pot pushed to the winners and chip counts
+// ticking to their new value. Timing is plain milliseconds, so nothing here needs a browser.
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::HandSummary;
+use crate::graphics::scene::{chip_stack, Node, Point, Rect, Scene};
+use crate::types::{Animation, EaseFunction};
+
+const MOTION_KEY: &str = "primo_poker_motion";
+const DEAL_STAGGER: f64 = 80.0; // ms between cards of the same deal
+
+/// Whether the table animates, following the system's reduced motion setting by default.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum MotionPreference {
+    #[default]
+    System,
+    Reduced,
+    Full,
+}
+
+impl MotionPreference {
+    pub const ALL: [MotionPreference; 3] = [MotionPreference::System, MotionPreference::Reduced, MotionPreference::Full];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MotionPreference::System => "Match my system setting",
+            MotionPreference::Reduced => "Reduce motion",
+            MotionPreference::Full => "Full animations",
+        }
+    }
+
+    pub fn load() -> Self {
+        LocalStorage::get(MOTION_KEY).unwrap_or_default()
+    }
+
+    pub fn save(self) {
+        let _ = LocalStorage::set(MOTION_KEY, self);
+    }
+
+    pub fn reduce_motion(self) -> bool {
+        match self {
+            MotionPreference::Reduced => true,
+            MotionPreference::Full => false,
+            MotionPreference::System => gloo_utils::window()
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+                .is_some_and(|query| query.matches()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Motion {
+    Deal { from: Point, to: Rect },
+    Chips { from: Point, to: Point, amount: i64, radius: f64 },
+    Count { player_id: Uuid, from: i64, to: i64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Tween {
+    animation: Animation,
+    start: f64, // ms, delay included
+    motion: Motion,
+}
+
+impl Tween {
+    fn new(animation: Animation, start: f64, motion: Motion) -> Self {
+        Self { animation, start, motion }
+    }
+
+    /// None until the tween's start time.
+    fn progress(&self, now: f64) -> Option<f64> {
+        (now >= self.start).then(|| self.animation.progress(now - self.start))
+    }
+
+    fn end(&self) -> f64 {
+        self.start + self.animation.duration_ms()
+    }
+
+    fn count(&self, now: f64) -> Option<i64> {
+        match self.motion {
+            Motion::Count { from, to, .. } => {
+                let t = self.progress(now).unwrap_or(0.0);
+                Some(from + ((to - from) as f64 * t).round() as i64)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the tweens running over the latest scene; `frame` draws them.
+#[derive(Debug, Default)]
+pub struct Animator {
+    tweens: Vec<Tween>,
+    previous: Option<Scene>,
+    hand_number: Option<u32>, // the last hand whose pot has been pushed
+    reduce_motion: bool,
+}
+
+impl Animator {
+    pub fn new(reduce_motion: bool) -> Self {
+        Self { reduce_motion, ..Self::default() }
+    }
+
+    pub fn is_animating(&self, now: f64) -> bool {
+        self.tweens.iter().any(|tween| tween.end() > now)
+    }
+
+    /// Starts tweens for whatever changed since the last scene. A resized table starts over.
+    pub fn update(&mut self, scene: &Scene, last_hand: Option<&HandSummary>, now: f64) {
+        let hand_number = last_hand.map(|summary| summary.hand_number);
+        let hand_finished = hand_number != self.hand_number;
+        self.hand_number = hand_number;
+        let previous = self.previous.replace(scene.clone());
+        self.tweens.retain(|tween| tween.end() > now);
+        let Some(previous) = previous.filter(|previous| (previous.width, previous.height) == (scene.width, scene.height)) else {
+            self.tweens.clear();
+            return;
+        };
+        if self.reduce_motion {
+            self.tweens.clear();
+            return;
+        }
+
+        // New cards fly in from the dealer button, one after another
+        let dealer = scene
+            .nodes
+            .iter()
+            .find_map(|node| match node {
+                Node::DealerButton { center, .. } => Some(*center),
+                _ => None,
+            })
+            .unwrap_or(scene.pot);
+        let dealt_before: Vec<Rect> = previous.nodes.iter().filter_map(card_rect).collect();
+        let dealt = scene.nodes.iter().filter_map(card_rect).filter(|rect| !dealt_before.contains(rect));
+        for (i, to) in dealt.enumerate() {
+            let start = now + i as f64 * DEAL_STAGGER;
+            self.tweens.push(Tween::new(Animation::new("deal", 0.35, EaseFunction::EaseOut), start, Motion::Deal { from: dealer, to }));
+        }
+
+        // Bets that left the felt were swept into the pot
+        let bets_now: Vec<Point> = bets(&scene.nodes, scene.pot).map(|(center, ..)| center).collect();
+        let mut swept = false;
+        for (from, amount, radius) in bets(&previous.nodes, previous.pot) {
+            if !bets_now.contains(&from) {
+                let motion = Motion::Chips { from, to: scene.pot, amount, radius };
+                self.tweens.push(Tween::new(Animation::new("bet", 0.4, EaseFunction::EaseIn), now, motion));
+                swept = true;
+            }
+        }
+
+        // Then the pot goes to the winners, whose stacks count up as it lands
+        let mut paid_until = now;
+        if let Some(summary) = last_hand.filter(|_| hand_finished) {
+            let push = Animation::new("pot", 0.6, EaseFunction::EaseInOut);
+            let start = if swept { now + 400.0 } else { now };
+            paid_until = start + push.duration_ms();
+            let radius = previous
+                .nodes
+                .iter()
+                .find_map(|node| match node {
+                    Node::Chips { radius, .. } => Some(*radius),
+                    _ => None,
+                })
+                .unwrap_or(scene.width * 0.014);
+            for line in summary.winners.iter().filter(|line| line.amount > 0) {
+                if let Some(seat) = seat_center(scene, line.player_id) {
+                    let motion = Motion::Chips { from: previous.pot, to: seat, amount: line.amount, radius };
+                    self.tweens.push(Tween::new(push.clone(), start, motion));
+                }
+            }
+        }
+
+        for (player_id, to) in seat_chips(scene) {
+            let Some(from) = self.displayed_chips(&previous, player_id, now) else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            let target = format!("chips:{}", player_id);
+            self.tweens.retain(|tween| tween.animation.target != target);
+            let start = if to > from { paid_until } else { now };
+            let motion = Motion::Count { player_id, from, to };
+            self.tweens.push(Tween::new(Animation::new(target, 0.6, EaseFunction::EaseOut), start, motion));
+        }
+    }
+
+    /// What a seat shows right now: mid-count if its ticker is running.
+    fn displayed_chips(&self, scene: &Scene, player_id: Uuid, now: f64) -> Option<i64> {
+        self.tweens
+            .iter()
+            .find(|tween| matches!(tween.motion, Motion::Count { player_id: id, .. } if id == player_id))
+            .and_then(|tween| tween.count(now))
+            .or_else(|| seat_chips(scene).find(|(id, _)| *id == player_id).map(|(_, chips)| chips))
+    }
+
+    /// The scene as it looks `now`, with every running tween applied.
+    pub fn frame(&self, scene: &Scene, now: f64) -> Scene {
+        let mut nodes = Vec::with_capacity(scene.nodes.len());
+        let mut stale_labels = 0;
+        for node in &scene.nodes {
+            match node {
+                Node::Card { rect, card } => {
+                    let deal = self.tweens.iter().find_map(|tween| match tween.motion {
+                        Motion::Deal { from, to } if to == *rect && tween.end() > now => Some((tween, from)),
+                        _ => None,
+                    });
+                    if let Some((tween, from)) = deal {
+                        // Not dealt yet: leave the spot empty until the card's turn
+                        if let Some(t) = tween.progress(now) {
+                            let rect = Rect::centered(from.lerp(rect.center(), t), rect.width, rect.height);
+                            nodes.push(Node::Card { rect, card: *card });
+                        }
+                        continue;
+                    }
+                }
+                Node::Seat(seat) => {
+                    let ticking = seat.player.as_ref().and_then(|player| {
+                        self.tweens.iter().find_map(|tween| match tween.motion {
+                            Motion::Count { player_id, .. } if player_id == player.id && tween.end() > now => tween.count(now),
+                            _ => None,
+                        })
+                    });
+                    if let Some(chips) = ticking {
+                        let mut seat = seat.clone();
+                        stale_labels = seat.labels().len();
+                        if let Some(ref mut player) = seat.player {
+                            player.chips = chips;
+                        }
+                        let labels = seat.labels();
+                        nodes.push(Node::Seat(seat));
+                        nodes.extend(labels);
+                        continue;
+                    }
+                }
+                Node::Label(_) if stale_labels > 0 => {
+                    stale_labels -= 1;
+                    continue;
+                }
+                _ => {}
+            }
+            nodes.push(node.clone());
+        }
+
+        // Moving chips go on top; ones still waiting for their turn sit where they start
+        for tween in self.tweens.iter().filter(|tween| tween.end() > now) {
+            if let Motion::Chips { from, to, amount, radius } = tween.motion {
+                let at = from.lerp(to, tween.progress(now).unwrap_or(0.0));
+                nodes.extend(chip_stack(at, amount, radius));
+            }
+        }
+
+        Scene { nodes, ..scene.clone() }
+    }
+}
+
+fn card_rect(node: &Node) -> Option<Rect> {
+    match node {
+        Node::Card { rect, .. } => Some(*rect),
+        _ => None,
+    }
+}
+
+/// Chip stacks in front of the seats, leaving out the pot.
+fn bets(nodes: &[Node], pot: Point) -> impl Iterator<Item = (Point, i64, f64)> + '_ {
+    nodes.iter().filter_map(move |node| match node {
+        Node::Chips { center, amount, radius } if *center != pot => Some((*center, *amount, *radius)),
+        _ => None,
+    })
+}
+
+fn seat_chips(scene: &Scene) -> impl Iterator<Item = (Uuid, i64)> + '_ {
+    scene.nodes.iter().filter_map(|node| match node {
+        Node::Seat(seat) => seat.player.as_ref().map(|player| (player.id, player.chips)),
+        _ => None,
+    })
+}
+
+fn seat_center(scene: &Scene, player_id: Uuid) -> Option<Point> {
+    scene.nodes.iter().find_map(|node| match node {
+        Node::Seat(seat) if seat.player.as_ref().is_some_and(|player| player.id == player_id) => Some(seat.center),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::HandResultLine;
+    use crate::graphics::scene::{SeatNode, SeatPlayer, Text};
+    use crate::types::{Card, PotShare};
+
+    const EASES: [EaseFunction; 5] =
+        [EaseFunction::Linear, EaseFunction::EaseIn, EaseFunction::EaseOut, EaseFunction::EaseInOut, EaseFunction::Bounce];
+
+    fn seat(id: Uuid, chips: i64, center: Point) -> Node {
+        let player = SeatPlayer { id, name: "Player".to_string(), chips, acting: false, folded: false, all_in: false, hero: false };
+        Node::Seat(SeatNode { center, radius: 40.0, player: Some(player) })
+    }
+
+    fn scene(nodes: Vec<Node>) -> Scene {
+        Scene { width: 800.0, height: 500.0, pot: Point::new(400.0, 300.0), nodes }
+    }
+
+    fn labels(scene: &Scene) -> Vec<&str> {
+        scene
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                Node::Label(Text { text, .. }) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_curve_runs_from_zero_to_one() {
+        for ease in EASES {
+            assert!(ease.ease(0.0).abs() < 1e-9, "{:?} starts at {}", ease, ease.ease(0.0));
+            assert!((ease.ease(1.0) - 1.0).abs() < 1e-9, "{:?} ends at {}", ease, ease.ease(1.0));
+        }
+        assert_eq!(EaseFunction::Linear.ease(0.25), 0.25);
+        assert!(EaseFunction::EaseIn.ease(0.5) < 0.5 && EaseFunction::EaseOut.ease(0.5) > 0.5);
+        assert!((EaseFunction::EaseInOut.ease(0.5) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn progress_clamps_outside_the_duration() {
+        let animation = Animation::new("test", 0.5, EaseFunction::Linear);
+        assert_eq!(animation.progress(-100.0), 0.0);
+        assert_eq!(animation.progress(250.0), 0.5);
+        assert_eq!(animation.progress(900.0), 1.0);
+        assert_eq!(Animation::new("instant", 0.0, EaseFunction::Bounce).progress(0.0), 1.0);
+    }
+
+    #[test]
+    fn new_cards_are_dealt_in_turn() {
+        let rects = [Rect::centered(Point::new(380.0, 240.0), 36.0, 50.4), Rect::centered(Point::new(420.0, 240.0), 36.0, 50.4)];
+        let mut animator = Animator::new(false);
+        animator.update(&scene(vec![]), None, 0.0);
+        let dealt = scene(rects.iter().map(|&rect| Node::Card { rect, card: Card::Unknown }).collect());
+        animator.update(&dealt, None, 0.0);
+
+        // The second card waits its turn, the first is on its way from the pot spot
+        let frame = animator.frame(&dealt, 40.0);
+        assert_eq!(frame.nodes.len(), 1);
+        let Node::Card { rect, .. } = frame.nodes[0] else { panic!("expected a card") };
+        assert!(rect.center().y > 240.0 && rect.center().y < 300.0);
+        assert!(animator.is_animating(300.0));
+        assert_eq!(animator.frame(&dealt, 1000.0), dealt);
+        assert!(!animator.is_animating(1000.0));
+    }
+
+    #[test]
+    fn pot_is_pushed_to_the_winner_before_their_stack_counts_up() {
+        let winner = Uuid::new_v4();
+        let seat_at = Point::new(400.0, 420.0);
+        let before = scene(vec![seat(winner, 1000, seat_at), Node::Chips { center: Point::new(400.0, 300.0), amount: 400, radius: 10.0 }]);
+        let after = scene(vec![seat(winner, 1400, seat_at)]);
+        let summary = HandSummary {
+            hand_number: 1,
+            hi_lo: false,
+            winners: vec![HandResultLine {
+                player_id: winner,
+                player_name: "Player".to_string(),
+                share: PotShare::Scoop,
+                amount: 400,
+                description: "Flush".to_string(),
+                board: 0,
+            }],
+            boards: Vec::new(),
+            board: Vec::new(),
+            shown: Vec::new(),
+            winning_cards: Vec::new(),
+            uncontested: true,
+        };
+        let mut animator = Animator::new(false);
+        animator.update(&before, None, 0.0);
+        animator.update(&after, Some(&summary), 0.0);
+
+        let halfway = animator.frame(&after, 300.0);
+        let chips: Vec<(i64, i64)> = halfway
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                Node::Chips { center, .. } => Some((center.x.round() as i64, center.y.round() as i64)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(chips, vec![(400, 360)]);
+        assert_eq!(labels(&halfway), vec!["Player", "$1000", "$400"]);
+
+        assert_eq!(labels(&animator.frame(&after, 900.0)), vec!["Player", "$1350"]);
+        assert_eq!(animator.frame(&after, 1300.0), after);
+    }
+
+    #[test]
+    fn reduced_motion_jumps_straight_to_the_new_scene() {
+        let id = Uuid::new_v4();
+        let before = scene(vec![seat(id, 1000, Point::new(400.0, 420.0))]);
+        let after = scene(vec![seat(id, 900, Point::new(400.0, 420.0)), Node::Chips { center: Point::new(400.0, 380.0), amount: 100, radius: 10.0 }]);
+        let mut animator = Animator::new(true);
+        animator.update(&before, None, 0.0);
+        animator.update(&after, None, 0.0);
+        assert!(!animator.is_animating(0.0));
+        assert_eq!(animator.frame(&after, 0.0), after);
+    }
+}
@@ -0,0 +1,240 @@
+// Canvas2D table renderer - draws a Scene onto a <canvas>, scaled for HiDPI screens.
+// The drawing helpers are shared with the WebGL renderer, which paints its atlas with them.
+use std::f64::consts::TAU;
+use gloo_events::EventListener;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement};
+use yew::Callback;
+
+use crate::graphics::card_renderer::{draw_card, draw_highlight, rounded_rect};
+use crate::graphics::chips::{count_label, ChipStack, Denomination};
+use crate::graphics::scene::{Node, PlateStyle, Point, Rect, Scene, Text, RAIL_RATIO};
+use crate::graphics::theme::Palette;
+use crate::graphics::TableRenderer;
+
+const CHIP_EDGE_COLOR: &str = "rgba(0, 0, 0, 0.45)";
+const CHIP_COUNT_COLOR: &str = "#ffffff";
+
+pub struct CanvasRenderer {
+    canvas: HtmlCanvasElement,
+    context: CanvasRenderingContext2d,
+    palette: Palette,
+    felt: Option<HtmlImageElement>,
+    card_back: Option<HtmlImageElement>,
+    pixel_ratio: f64,
+    _asset_listeners: Vec<EventListener>,
+}
+
+impl CanvasRenderer {
+    /// `on_asset_loaded` fires as the theme's felt and card back artwork become ready, so
+    /// the owner can redraw with them.
+    pub fn new(canvas: HtmlCanvasElement, palette: Palette, on_asset_loaded: Callback<()>) -> Result<Self, JsValue> {
+        let context = context_2d(&canvas)?;
+        let mut asset_listeners = Vec::new();
+        let mut load = |url: Option<&str>| -> Result<Option<HtmlImageElement>, JsValue> {
+            let Some(url) = url else {
+                return Ok(None);
+            };
+            let image = HtmlImageElement::new()?;
+            let on_asset_loaded = on_asset_loaded.clone();
+            asset_listeners.push(EventListener::once(&image, "load", move |_| on_asset_loaded.emit(())));
+            image.set_src(url);
+            Ok(Some(image))
+        };
+        let felt = load(palette.felt_texture)?;
+        let card_back = load(palette.card_back_art)?;
+
+        Ok(Self { canvas, context, palette, felt, card_back, pixel_ratio: 1.0, _asset_listeners: asset_listeners })
+    }
+}
+
+impl TableRenderer for CanvasRenderer {
+    fn resize(&mut self, width: f64, height: f64, pixel_ratio: f64) {
+        self.pixel_ratio = pixel_ratio;
+        self.canvas.set_width((width * pixel_ratio).round() as u32);
+        self.canvas.set_height((height * pixel_ratio).round() as u32);
+    }
+
+    fn render(&mut self, scene: &Scene) {
+        let context = &self.context;
+        let palette = &self.palette;
+        // Resizing resets the transform, so set it on every frame and draw in CSS pixels
+        let _ = context.set_transform(self.pixel_ratio, 0.0, 0.0, self.pixel_ratio, 0.0, 0.0);
+        context.clear_rect(0.0, 0.0, scene.width, scene.height);
+
+        for node in &scene.nodes {
+            match node {
+                Node::Felt { center, radius_x, radius_y } => draw_felt(context, palette, self.felt.as_ref(), *center, *radius_x, *radius_y),
+                Node::Seat(seat) => draw_seat_plate(context, palette, seat.plate(), seat.style()),
+                Node::Card { rect, card } => draw_card(context, palette, self.card_back.as_ref(), *rect, card),
+                Node::Highlight { rect } => draw_highlight(context, palette, *rect),
+                Node::Chips { center, amount, radius } => draw_chips(context, *center, *amount, *radius),
+                Node::DealerButton { center, radius } => draw_dealer_button(context, palette, *center, *radius),
+                Node::Label(text) => draw_text(context, text),
+            }
+        }
+    }
+}
+
+pub fn context_2d(canvas: &HtmlCanvasElement) -> Result<CanvasRenderingContext2d, JsValue> {
+    canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d context unavailable"))?
+        .dyn_into()
+        .map_err(JsValue::from)
+}
+
+/// The felt inside its rail, textured once `felt` has loaded and plain cloth until then.
+pub fn draw_felt(
+    context: &CanvasRenderingContext2d,
+    palette: &Palette,
+    felt: Option<&HtmlImageElement>,
+    center: Point,
+    radius_x: f64,
+    radius_y: f64,
+) {
+    let rail = radius_x.min(radius_y) * RAIL_RATIO;
+
+    context.begin_path();
+    let _ = context.ellipse(center.x, center.y, radius_x + rail, radius_y + rail, 0.0, 0.0, TAU);
+    context.set_fill_style_str(palette.rail);
+    context.fill();
+
+    context.save();
+    context.begin_path();
+    let _ = context.ellipse(center.x, center.y, radius_x, radius_y, 0.0, 0.0, TAU);
+    context.clip();
+    context.set_fill_style_str(palette.felt);
+    context.fill();
+    if let Some(felt) = felt.filter(|felt| felt.complete() && felt.natural_width() > 0) {
+        let _ = context.draw_image_with_html_image_element_and_dw_and_dh(
+            felt,
+            center.x - radius_x,
+            center.y - radius_y,
+            radius_x * 2.0,
+            radius_y * 2.0,
+        );
+    }
+    // Darken toward the rail
+    if let Ok(shade) = context.create_radial_gradient(center.x, center.y, radius_y * 0.3, center.x, center.y, radius_x) {
+        let _ = shade.add_color_stop(0.0, "rgba(0, 0, 0, 0)");
+        let _ = shade.add_color_stop(1.0, "rgba(0, 0, 0, 0.35)");
+        context.set_fill_style_canvas_gradient(&shade);
+        context.fill_rect(center.x - radius_x, center.y - radius_y, radius_x * 2.0, radius_y * 2.0);
+    }
+    context.restore();
+}
+
+pub fn draw_seat_plate(context: &CanvasRenderingContext2d, palette: &Palette, plate: Rect, style: PlateStyle) {
+    context.save();
+    rounded_rect(context, plate, plate.height * 0.27);
+    if style == PlateStyle::Open {
+        let _ = context.set_line_dash(&js_sys::Array::of2(&4.0.into(), &4.0.into()));
+        context.set_stroke_style_str(palette.open_seat);
+    } else {
+        if style == PlateStyle::Folded {
+            context.set_global_alpha(0.5);
+        }
+        context.set_fill_style_str(palette.seat);
+        context.fill();
+        let acting = style == PlateStyle::Acting;
+        context.set_line_width(if acting { 3.0 } else { 1.5 });
+        context.set_stroke_style_str(if acting { palette.accent } else { palette.seat_border });
+    }
+    context.stroke();
+    context.restore();
+}
+
+pub fn draw_chips(context: &CanvasRenderingContext2d, center: Point, amount: i64, radius: f64) {
+    let stack = ChipStack::new(amount);
+    for chip in stack.place(center, radius) {
+        draw_chip(context, chip.center, radius, chip.denomination);
+    }
+    for (at, count) in stack.counts(center, radius) {
+        draw_text(context, &Text { at, text: count_label(count), size: radius * 0.9, color: CHIP_COUNT_COLOR, bold: true });
+    }
+}
+
+/// One chip seen from above, after assets/ui/chip-stack.svg: a dashed ring and an inner
+/// ring in the inlay colour, four edge spots and the value once there's room to read it.
+pub fn draw_chip(context: &CanvasRenderingContext2d, center: Point, radius: f64, denomination: Denomination) {
+    context.begin_path();
+    let _ = context.arc(center.x, center.y, radius, 0.0, TAU);
+    context.set_fill_style_str(denomination.color);
+    context.fill();
+    context.set_line_width((radius * 0.11).max(1.0));
+    context.set_stroke_style_str(CHIP_EDGE_COLOR);
+    context.stroke();
+
+    context.save();
+    context.set_stroke_style_str(denomination.inlay);
+    context.set_fill_style_str(denomination.inlay);
+    context.set_line_width((radius * 0.06).max(0.5));
+    let dash = radius * 0.11;
+    let _ = context.set_line_dash(&js_sys::Array::of2(&dash.into(), &(dash / 2.0).into()));
+    context.begin_path();
+    let _ = context.arc(center.x, center.y, radius * 0.78, 0.0, TAU);
+    context.stroke();
+    let _ = context.set_line_dash(&js_sys::Array::new());
+    context.begin_path();
+    let _ = context.arc(center.x, center.y, radius * 0.55, 0.0, TAU);
+    context.stroke();
+    for spot in 0..4 {
+        let angle = f64::from(spot) * TAU / 4.0;
+        context.begin_path();
+        let _ = context.arc(center.x + radius * 0.67 * angle.cos(), center.y + radius * 0.67 * angle.sin(), radius * 0.08, 0.0, TAU);
+        context.fill();
+    }
+    context.restore();
+
+    if radius >= 14.0 {
+        let size = radius * if denomination.label.len() > 3 { 0.38 } else { 0.5 };
+        draw_text(context, &Text { at: center, text: denomination.label.to_string(), size, color: denomination.inlay, bold: true });
+    }
+}
+
+/// A chip painted from the assets/ui/chip-stack.svg artwork itself: greyed, multiplied
+/// by the denomination's colour so the rings come out as a darker shade of it, and with
+/// the artwork's printed "100" covered by the denomination's own value.
+pub fn draw_chip_art(context: &CanvasRenderingContext2d, art: &HtmlImageElement, center: Point, radius: f64, denomination: Denomination) {
+    // The artwork is 40px across with the chip's outer stroke ending at 19px, its inner
+    // ring's at 9.5px
+    let scale = radius / 19.0;
+    context.save();
+    context.begin_path();
+    let _ = context.arc(center.x, center.y, radius, 0.0, TAU);
+    context.clip();
+    context.set_filter("grayscale(1) brightness(1.3)");
+    let _ = context.draw_image_with_html_image_element_and_dw_and_dh(art, center.x - 20.0 * scale, center.y - 20.0 * scale, 40.0 * scale, 40.0 * scale);
+    context.set_filter("none");
+    let _ = context.set_global_composite_operation("multiply");
+    context.set_fill_style_str(denomination.color);
+    context.fill();
+    let _ = context.set_global_composite_operation("source-over");
+    context.begin_path();
+    let _ = context.arc(center.x, center.y, 9.5 * scale, 0.0, TAU);
+    context.fill();
+    context.restore();
+
+    if radius >= 14.0 {
+        let size = radius * if denomination.label.len() > 3 { 0.38 } else { 0.5 };
+        draw_text(context, &Text { at: center, text: denomination.label.to_string(), size, color: denomination.inlay, bold: true });
+    }
+}
+
+pub fn draw_dealer_button(context: &CanvasRenderingContext2d, palette: &Palette, center: Point, radius: f64) {
+    context.begin_path();
+    let _ = context.arc(center.x, center.y, radius, 0.0, TAU);
+    context.set_fill_style_str(palette.accent);
+    context.fill();
+    draw_text(context, &Text { at: center, text: "D".to_string(), size: radius * 1.1, color: palette.accent_text, bold: true });
+}
+
+pub fn draw_text(context: &CanvasRenderingContext2d, text: &Text) {
+    let weight = if text.bold { "bold " } else { "" };
+    context.set_font(&format!("{}{}px sans-serif", weight, text.size.round().max(8.0)));
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+    context.set_fill_style_str(text.color);
+    let _ = context.fill_text(&text.text, text.at.x, text.at.y);
+}
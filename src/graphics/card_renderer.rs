@@ -0,0 +1,156 @@
+// Card renderer - playing card faces and backs drawn straight onto a 2D canvas
+use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
+
+use crate::graphics::scene::Rect;
+use crate::graphics::theme::Palette;
+use crate::types::{Card, Rank, Suit};
+
+const BACK_STRIPE_COLOR: &str = "rgba(255, 255, 255, 0.15)";
+/// Left to right in assets/ui/card-suits.svg, one square cell each.
+const SUIT_SHEET_ORDER: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+
+/// A card in the palette's deck colours; backs use `back` once the artwork has loaded.
+pub fn draw_card(context: &CanvasRenderingContext2d, palette: &Palette, back: Option<&HtmlImageElement>, rect: Rect, card: &Card) {
+    match card {
+        Card::Card { suit, rank } => draw_card_face(context, palette, None, rect, *suit, *rank),
+        Card::Unknown => {
+            let radius = draw_blank(context, palette.card_back.0, rect);
+            match back.filter(|back| back.complete() && back.natural_width() > 0) {
+                Some(back) => {
+                    context.save();
+                    rounded_rect(context, rect, radius);
+                    context.clip();
+                    let _ = context.draw_image_with_html_image_element_and_dw_and_dh(back, rect.x, rect.y, rect.width, rect.height);
+                    context.restore();
+                }
+                None => draw_back_pattern(context, rect, radius),
+            }
+        }
+    }
+}
+
+/// A face-up card; the suit comes from the assets/ui/card-suits.svg sheet once it has
+/// loaded, recoloured to the deck's suit colour, and from the font until then.
+pub fn draw_card_face(
+    context: &CanvasRenderingContext2d,
+    palette: &Palette,
+    suits: Option<&HtmlImageElement>,
+    rect: Rect,
+    suit: Suit,
+    rank: Rank,
+) {
+    draw_blank(context, palette.card_face, rect);
+    let color = palette.suit_color(suit);
+    context.set_fill_style_str(color);
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+    let center = rect.center();
+    context.set_font(&format!("bold {}px sans-serif", (rect.width * 0.42).round()));
+    let _ = context.fill_text(&rank.to_string(), center.x, rect.y + rect.height * 0.32);
+
+    let glyph = rect.width * 0.5;
+    match suits.filter(|suits| suits.complete() && suits.natural_width() > 0) {
+        Some(suits) => {
+            let at = Rect { x: center.x - glyph / 2.0, y: rect.y + rect.height * 0.7 - glyph / 2.0, width: glyph, height: glyph };
+            draw_suit_glyph(context, suits, suit, at, color);
+        }
+        None => {
+            context.set_font(&format!("{}px sans-serif", (rect.width * 0.48).round()));
+            let _ = context.fill_text(&suit.to_string(), center.x, rect.y + rect.height * 0.7);
+        }
+    }
+}
+
+/// The card's shadowed, rounded body; returns the corner radius.
+fn draw_blank(context: &CanvasRenderingContext2d, fill: &str, rect: Rect) -> f64 {
+    let radius = rect.width * 0.12;
+    context.save();
+    context.set_shadow_color("rgba(0, 0, 0, 0.35)");
+    context.set_shadow_blur(rect.width * 0.1);
+    context.set_shadow_offset_y(rect.width * 0.04);
+    rounded_rect(context, rect, radius);
+    context.set_fill_style_str(fill);
+    context.fill();
+    context.restore();
+    radius
+}
+
+/// One glyph from the suit sheet, in `color` whatever the sheet's own colours. The glyph
+/// is drawn off to the side and clipped away, leaving only its shadow - an exact
+/// silhouette - on `at`.
+fn draw_suit_glyph(context: &CanvasRenderingContext2d, suits: &HtmlImageElement, suit: Suit, at: Rect, color: &str) {
+    let cell = f64::from(suits.natural_height());
+    let column = SUIT_SHEET_ORDER.iter().position(|&sheet| sheet == suit).unwrap_or(0) as f64;
+    let offset = at.x + at.width * 2.0;
+    context.save();
+    context.begin_path();
+    context.rect(at.x, at.y, at.width, at.height);
+    context.clip();
+    context.set_shadow_color(color);
+    context.set_shadow_offset_x(offset);
+    let _ = context.draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+        suits,
+        column * cell,
+        0.0,
+        cell,
+        cell,
+        at.x - offset,
+        at.y,
+        at.width,
+        at.height,
+    );
+    context.restore();
+}
+
+/// A glowing outline for a card that plays in the winning hand; `rect` is the outline's
+/// outer edge, a little larger than the card.
+pub fn draw_highlight(context: &CanvasRenderingContext2d, palette: &Palette, rect: Rect) {
+    let line_width = (rect.width * 0.07).max(1.5);
+    let inner = Rect {
+        x: rect.x + line_width / 2.0,
+        y: rect.y + line_width / 2.0,
+        width: rect.width - line_width,
+        height: rect.height - line_width,
+    };
+    context.save();
+    context.set_shadow_color(palette.accent);
+    context.set_shadow_blur(rect.width * 0.12);
+    rounded_rect(context, inner, inner.width * 0.14);
+    context.set_line_width(line_width);
+    context.set_stroke_style_str(palette.accent);
+    context.stroke();
+    context.restore();
+}
+
+/// Diagonal stripes inside an inset border, clipped to the card.
+fn draw_back_pattern(context: &CanvasRenderingContext2d, rect: Rect, radius: f64) {
+    let inset = rect.width * 0.1;
+    let inner = Rect { x: rect.x + inset, y: rect.y + inset, width: rect.width - inset * 2.0, height: rect.height - inset * 2.0 };
+    context.save();
+    rounded_rect(context, inner, radius * 0.6);
+    context.clip();
+    context.set_stroke_style_str(BACK_STRIPE_COLOR);
+    context.set_line_width((rect.width * 0.06).max(1.0));
+    let step = rect.width * 0.16;
+    let mut offset = -inner.height;
+    while offset < inner.width {
+        context.begin_path();
+        context.move_to(inner.x + offset, inner.y + inner.height);
+        context.line_to(inner.x + offset + inner.height, inner.y);
+        context.stroke();
+        offset += step;
+    }
+    context.restore();
+}
+
+pub fn rounded_rect(context: &CanvasRenderingContext2d, rect: Rect, radius: f64) {
+    let Rect { x, y, width, height } = rect;
+    let radius = radius.min(width / 2.0).min(height / 2.0);
+    context.begin_path();
+    context.move_to(x + radius, y);
+    let _ = context.arc_to(x + width, y, x + width, y + height, radius);
+    let _ = context.arc_to(x + width, y + height, x, y + height, radius);
+    let _ = context.arc_to(x, y + height, x, y, radius);
+    let _ = context.arc_to(x, y, x + width, y, radius);
+    context.close_path();
+}
@@ -0,0 +1,191 @@
+// Chip stacks - breaks an amount into standard casino chips and stands them in columns,
+// largest denomination on the left. Huge amounts keep a few short columns, each labelled
+// with its real chip count, instead of towers that would cover the table.
+use crate::graphics::scene::Point;
+
+pub const MAX_COLUMNS: usize = 4;
+pub const MAX_COLUMN_HEIGHT: usize = 8; // chips drawn per column before it's compacted
+const LAYER_OFFSET: f64 = 0.22; // rise per chip, against the chip radius
+const COLUMN_GAP: f64 = 0.2; // between columns, against the chip radius
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Denomination {
+    pub value: i64,
+    pub label: &'static str, // printed on the chip
+    pub color: &'static str,
+    pub inlay: &'static str, // edge spots and rings
+}
+
+/// The standard set, largest first.
+pub const DENOMINATIONS: [Denomination; 10] = [
+    Denomination { value: 1_000_000, label: "1M", color: "#b45309", inlay: "#fde68a" },
+    Denomination { value: 100_000, label: "100K", color: "#0ea5e9", inlay: "#f8fafc" },
+    Denomination { value: 25_000, label: "25K", color: "#db2777", inlay: "#f8fafc" },
+    Denomination { value: 5_000, label: "5K", color: "#ea580c", inlay: "#f8fafc" },
+    Denomination { value: 1_000, label: "1K", color: "#ffc107", inlay: "#0f5132" },
+    Denomination { value: 500, label: "500", color: "#7c3aed", inlay: "#f8fafc" },
+    Denomination { value: 100, label: "100", color: "#1f2937", inlay: "#f8fafc" },
+    Denomination { value: 25, label: "25", color: "#16a34a", inlay: "#f8fafc" },
+    Denomination { value: 5, label: "5", color: "#dc2626", inlay: "#f8fafc" },
+    Denomination { value: 1, label: "1", color: "#f8fafc", inlay: "#1e40af" },
+];
+
+/// The fewest chips that make up `amount`, largest denomination first. Nothing for
+/// zero or negative amounts.
+pub fn decompose(amount: i64) -> Vec<(Denomination, i64)> {
+    let mut left = amount.max(0);
+    DENOMINATIONS
+        .iter()
+        .filter_map(|&denomination| {
+            let count = left / denomination.value;
+            left %= denomination.value;
+            (count > 0).then_some((denomination, count))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChipColumn {
+    pub denomination: Denomination,
+    pub count: i64,
+}
+
+impl ChipColumn {
+    pub fn height(&self) -> usize {
+        usize::try_from(self.count).map_or(MAX_COLUMN_HEIGHT, |count| count.min(MAX_COLUMN_HEIGHT))
+    }
+
+    /// Drawn shorter than it really is, so it carries a count.
+    pub fn compacted(&self) -> bool {
+        self.count > MAX_COLUMN_HEIGHT as i64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedChip {
+    pub center: Point,
+    pub denomination: Denomination,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChipStack {
+    pub columns: Vec<ChipColumn>,
+}
+
+impl ChipStack {
+    /// Only the largest denominations get a column; the amount printed beside the stack
+    /// stays exact.
+    pub fn new(amount: i64) -> Self {
+        let columns = decompose(amount)
+            .into_iter()
+            .take(MAX_COLUMNS)
+            .map(|(denomination, count)| ChipColumn { denomination, count })
+            .collect();
+        Self { columns }
+    }
+
+    /// Where each column's bottom chip sits, for a stack standing on `center`.
+    pub fn column_bases(&self, center: Point, radius: f64) -> Vec<Point> {
+        let pitch = radius * (2.0 + COLUMN_GAP);
+        let first = center.x - pitch * (self.columns.len() as f64 - 1.0) / 2.0;
+        (0..self.columns.len()).map(|i| Point::new(first + pitch * i as f64, center.y)).collect()
+    }
+
+    /// Every chip to draw, bottom to top within each column.
+    pub fn place(&self, center: Point, radius: f64) -> Vec<PlacedChip> {
+        self.columns
+            .iter()
+            .zip(self.column_bases(center, radius))
+            .flat_map(|(column, base)| {
+                (0..column.height()).map(move |layer| PlacedChip {
+                    center: Point::new(base.x, base.y - layer as f64 * radius * LAYER_OFFSET),
+                    denomination: column.denomination,
+                })
+            })
+            .collect()
+    }
+
+    /// Just above each compacted column, with the count to print there.
+    pub fn counts(&self, center: Point, radius: f64) -> Vec<(Point, i64)> {
+        self.columns
+            .iter()
+            .zip(self.column_bases(center, radius))
+            .filter(|(column, _)| column.compacted())
+            .map(|(column, base)| {
+                let top = base.y - (column.height() as f64 - 1.0) * radius * LAYER_OFFSET;
+                (Point::new(base.x, top - radius * 1.6), column.count)
+            })
+            .collect()
+    }
+}
+
+/// "×12" - how a compacted column's count is printed.
+pub fn count_label(count: i64) -> String {
+    format!("×{}", count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(amount: i64) -> Vec<(i64, i64)> {
+        decompose(amount).into_iter().map(|(denomination, count)| (denomination.value, count)).collect()
+    }
+
+    fn total(amount: i64) -> i64 {
+        decompose(amount).into_iter().map(|(denomination, count)| denomination.value * count).sum()
+    }
+
+    #[test]
+    fn denominations_run_largest_first() {
+        assert!(DENOMINATIONS.windows(2).all(|pair| pair[0].value > pair[1].value));
+        assert_eq!(DENOMINATIONS.last().map(|denomination| denomination.value), Some(1));
+    }
+
+    #[test]
+    fn uses_the_fewest_chips() {
+        assert_eq!(values(1), vec![(1, 1)]);
+        assert_eq!(values(150), vec![(100, 1), (25, 2)]);
+        assert_eq!(values(2_385), vec![(1_000, 2), (100, 3), (25, 3), (5, 2)]);
+        assert_eq!(values(1_131_006), vec![(1_000_000, 1), (100_000, 1), (25_000, 1), (5_000, 1), (1_000, 1), (5, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn chips_always_add_up_to_the_amount() {
+        for amount in [1, 4, 5, 24, 99, 499, 12_345, 987_654_321, i64::MAX] {
+            assert_eq!(total(amount), amount, "{} decomposed wrong", amount);
+        }
+    }
+
+    #[test]
+    fn nothing_for_empty_or_negative_amounts() {
+        assert!(decompose(0).is_empty());
+        assert!(decompose(-500).is_empty());
+        assert!(ChipStack::new(-1).place(Point::default(), 10.0).is_empty());
+    }
+
+    #[test]
+    fn huge_amounts_compact_to_a_few_short_columns() {
+        let stack = ChipStack::new(i64::MAX);
+        assert_eq!(stack.columns.len(), MAX_COLUMNS);
+        assert_eq!(stack.columns[0].count, i64::MAX / 1_000_000);
+        assert!(stack.columns[0].compacted());
+        assert_eq!(stack.place(Point::default(), 10.0).len(), stack.columns.iter().map(ChipColumn::height).sum::<usize>());
+        assert!(stack.place(Point::default(), 10.0).len() <= MAX_COLUMNS * MAX_COLUMN_HEIGHT);
+
+        let counts = stack.counts(Point::default(), 10.0);
+        assert_eq!(counts[0].1, i64::MAX / 1_000_000);
+        assert_eq!(count_label(12), "×12");
+    }
+
+    #[test]
+    fn columns_stand_side_by_side_around_the_center() {
+        let stack = ChipStack::new(150); // one black chip, two greens
+        let center = Point::new(100.0, 50.0);
+        let chips = stack.place(center, 10.0);
+        let spots: Vec<(f64, f64, i64)> =
+            chips.iter().map(|chip| (chip.center.x, (chip.center.y * 10.0).round() / 10.0, chip.denomination.value)).collect();
+        assert_eq!(spots, vec![(89.0, 50.0, 100), (111.0, 50.0, 25), (111.0, 47.8, 25)]);
+        assert!(stack.counts(center, 10.0).is_empty());
+    }
+}
@@ -1,5 +1,59 @@
 // Graphics and animation module for enhanced visual experience
-// pub mod canvas;
-// pub mod animations;
-// pub mod card_renderer;
-// pub mod particle_effects;
+pub mod scene;
+pub mod animations;
+pub mod canvas;
+pub mod chips;
+pub mod card_renderer;
+pub mod sprites;
+#[cfg(feature = "webgl")]
+pub mod webgl;
+pub mod particle_effects;
+pub mod theme;
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use web_sys::HtmlCanvasElement;
+use yew::Callback;
+
+use crate::graphics::canvas::CanvasRenderer;
+use crate::graphics::scene::Scene;
+use crate::graphics::theme::Palette;
+
+const TABLE_VIEW_KEY: &str = "primo_poker_table_view";
+
+/// Something that can draw a table Scene onto the page.
+pub trait TableRenderer {
+    /// Matches the drawing buffer to the element's CSS size at the screen's pixel ratio.
+    fn resize(&mut self, width: f64, height: f64, pixel_ratio: f64);
+    fn render(&mut self, scene: &Scene);
+}
+
+/// The fastest renderer this browser supports: WebGL2 in builds with the `webgl`
+/// feature, Canvas2D otherwise or when WebGL2 isn't available. Either draws in `palette`.
+pub fn create_renderer(canvas: HtmlCanvasElement, palette: Palette, on_asset_loaded: Callback<()>) -> Result<Box<dyn TableRenderer>, JsValue> {
+    #[cfg(feature = "webgl")]
+    match webgl::WebGlRenderer::new(canvas.clone(), palette, on_asset_loaded.clone()) {
+        Ok(renderer) => return Ok(Box::new(renderer)),
+        Err(e) => log::info!("Drawing the table with Canvas2D, WebGL2 renderer unavailable: {:?}", e),
+    }
+    Ok(Box::new(CanvasRenderer::new(canvas, palette, on_asset_loaded)?))
+}
+
+/// How GamePage draws the table.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TableView {
+    #[default]
+    Dom,
+    Canvas,
+}
+
+impl TableView {
+    pub fn load() -> Self {
+        LocalStorage::get(TABLE_VIEW_KEY).unwrap_or_default()
+    }
+
+    pub fn save(self) {
+        let _ = LocalStorage::set(TABLE_VIEW_KEY, self);
+    }
+}
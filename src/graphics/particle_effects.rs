@@ -0,0 +1,446 @@
+// Particle effects - confetti, chip bursts and sparkle laid over the whole page when something
+// worth celebrating happens. The simulation is plain numbers stepped in milliseconds; only
+// `draw_particles` touches the browser.
+use std::f64::consts::{PI, TAU};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use web_sys::CanvasRenderingContext2d;
+
+use crate::game::TableSet;
+use crate::graphics::canvas::draw_chip;
+use crate::graphics::chips::{decompose, Denomination, DENOMINATIONS};
+use crate::graphics::scene::Point;
+use crate::types::{HandType, HandWinner, ServerMessage};
+
+const EFFECTS_KEY: &str = "primo_poker_effects";
+const SLOW_FRAME_MS: f64 = 50.0; // a frame this long means the page is struggling
+const MAX_STEP_MS: f64 = 50.0; // longer gaps (a background tab) don't fling particles off screen
+const MIN_LIMIT: usize = 20; // slow frames never shed the budget below this
+const CONFETTI_COLORS: [&str; 6] = ["#fbbf24", "#ef4444", "#10b981", "#3b82f6", "#a855f7", "#f8fafc"];
+const SPARKLE_COLOR: &str = "#fef3c7";
+
+/// How many particles may be alive at once, across every celebration on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum EffectBudget {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl EffectBudget {
+    pub const ALL: [EffectBudget; 3] = [EffectBudget::Low, EffectBudget::Medium, EffectBudget::High];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EffectBudget::Low => "Light - for older devices",
+            EffectBudget::Medium => "Balanced",
+            EffectBudget::High => "Lavish",
+        }
+    }
+
+    pub fn max_particles(self) -> usize {
+        match self {
+            EffectBudget::Low => 60,
+            EffectBudget::Medium => 180,
+            EffectBudget::High => 400,
+        }
+    }
+}
+
+/// Whether and when celebrations play.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EffectSettings {
+    pub enabled: bool,
+    pub big_pot_multiple: i64, // pots of at least this many big blinds get a chip burst
+    pub budget: EffectBudget,
+}
+
+impl Default for EffectSettings {
+    fn default() -> Self {
+        Self { enabled: true, big_pot_multiple: 100, budget: EffectBudget::default() }
+    }
+}
+
+impl EffectSettings {
+    pub const POT_MULTIPLES: [i64; 4] = [25, 50, 100, 250];
+
+    pub fn load() -> Self {
+        LocalStorage::get(EFFECTS_KEY).unwrap_or_default()
+    }
+
+    pub fn save(self) {
+        let _ = LocalStorage::set(EFFECTS_KEY, self);
+    }
+}
+
+/// Something worth celebrating, strongest first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Celebration {
+    TournamentWin,
+    RoyalFlush,
+    StraightFlush,
+    BigPot { amount: i64 },
+}
+
+impl Celebration {
+    /// Royal and straight flushes won at any of our tables, pots of at least
+    /// `big_pot_multiple` big blinds, and winning a tournament outright. Checked before
+    /// `tables` applies the message, while the hand's table is still there to read.
+    pub fn from_server_message(message: &ServerMessage, tables: &TableSet, settings: &EffectSettings) -> Option<Self> {
+        if !settings.enabled {
+            return None;
+        }
+        match message {
+            ServerMessage::HandResult { room_id, winners, pot_distribution, .. } => {
+                let big_blind = tables
+                    .get(room_id)
+                    .and_then(|state| state.current_room.as_ref())
+                    .map_or(0, |room| room.big_blind);
+                let pot = pot_distribution.iter().map(|win| win.amount).sum();
+                Self::for_hand(winners, pot, big_blind, settings.big_pot_multiple)
+            }
+            ServerMessage::TournamentFinished { position: 1, .. } => Some(Celebration::TournamentWin),
+            _ => None,
+        }
+    }
+
+    /// The best hand shown outranks the size of the pot.
+    pub fn for_hand(winners: &[HandWinner], pot: i64, big_blind: i64, big_pot_multiple: i64) -> Option<Self> {
+        if winners.iter().any(|winner| winner.hand_type == HandType::RoyalFlush) {
+            Some(Celebration::RoyalFlush)
+        } else if winners.iter().any(|winner| winner.hand_type == HandType::StraightFlush) {
+            Some(Celebration::StraightFlush)
+        } else if big_blind > 0 && pot >= big_blind.saturating_mul(big_pot_multiple) {
+            Some(Celebration::BigPot { amount: pot })
+        } else {
+            None
+        }
+    }
+
+    /// The effects to fire and how many particles each asks for.
+    fn bursts(self) -> Vec<(Effect, usize)> {
+        match self {
+            Celebration::TournamentWin => vec![(Effect::Confetti, 220), (Effect::Chips(1_000_000), 40)],
+            Celebration::RoyalFlush => vec![(Effect::Sparkle, 70), (Effect::Confetti, 120)],
+            Celebration::StraightFlush => vec![(Effect::Confetti, 120), (Effect::Sparkle, 25)],
+            Celebration::BigPot { amount } => vec![(Effect::Chips(amount), 45)],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Effect {
+    Confetti,
+    Chips(i64), // thrown chips are the pot's own denominations
+    Sparkle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParticleKind {
+    Confetti(&'static str),
+    Chip(Denomination),
+    Sparkle,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Particle {
+    pub kind: ParticleKind,
+    pub position: Point,
+    pub velocity: Point, // px per second
+    pub age: f64,        // ms
+    pub life: f64,       // ms
+    pub size: f64,
+    pub rotation: f64,
+    pub spin: f64, // radians per second
+}
+
+impl Particle {
+    fn gravity(&self) -> f64 {
+        match self.kind {
+            ParticleKind::Confetti(_) => 260.0,
+            ParticleKind::Chip(_) => 1500.0,
+            ParticleKind::Sparkle => -12.0, // drifts gently upward
+        }
+    }
+
+    /// Paper flutters down slowly; chips and sparkle barely notice the air.
+    fn drag(&self) -> f64 {
+        match self.kind {
+            ParticleKind::Confetti(_) => 1.6,
+            ParticleKind::Chip(_) => 0.1,
+            ParticleKind::Sparkle => 0.8,
+        }
+    }
+
+    fn step(&mut self, dt: f64) {
+        let seconds = dt / 1000.0;
+        let damping = (-self.drag() * seconds).exp();
+        self.velocity = Point::new(self.velocity.x * damping, self.velocity.y * damping + self.gravity() * seconds);
+        self.position = Point::new(self.position.x + self.velocity.x * seconds, self.position.y + self.velocity.y * seconds);
+        self.rotation += self.spin * seconds;
+        self.age += dt;
+    }
+
+    pub fn alive(&self) -> bool {
+        self.age < self.life
+    }
+
+    /// Full strength until the last third of its life, then fading out. Sparkle twinkles.
+    pub fn opacity(&self) -> f64 {
+        let left = ((self.life - self.age) / self.life).clamp(0.0, 1.0);
+        let fade = (left * 3.0).min(1.0);
+        match self.kind {
+            ParticleKind::Sparkle => fade * (0.55 + 0.45 * (self.age / 90.0 + self.rotation).sin()),
+            _ => fade,
+        }
+    }
+}
+
+/// Every particle on screen, within a shared budget that shrinks when frames run slow.
+#[derive(Debug, Clone)]
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+    limit: usize,
+    seed: u32,
+}
+
+impl ParticleSystem {
+    /// `seed` only varies the scatter; zero is replaced, since xorshift would stick there.
+    pub fn new(budget: EffectBudget, seed: u32) -> Self {
+        Self { particles: Vec::new(), limit: budget.max_particles(), seed: seed.max(1) }
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Fires a celebration over a `width` x `height` area. Bursts share whatever room is
+    /// left in the budget, scaled down together rather than the first one taking it all.
+    pub fn celebrate(&mut self, celebration: Celebration, width: f64, height: f64) {
+        let bursts = celebration.bursts();
+        let wanted: usize = bursts.iter().map(|(_, count)| count).sum();
+        let room = self.limit.saturating_sub(self.particles.len());
+        for (effect, count) in bursts {
+            let count = if wanted > room { count * room / wanted } else { count };
+            for _ in 0..count {
+                let particle = self.spawn(effect, width, height);
+                self.particles.push(particle);
+            }
+        }
+    }
+
+    /// Advances every particle by `dt` ms and drops the finished ones. A slow frame sheds
+    /// a quarter of the budget, oldest particles first, and the budget stays lowered.
+    pub fn step(&mut self, dt: f64) {
+        if dt > SLOW_FRAME_MS && !self.particles.is_empty() {
+            self.limit = (self.limit * 3 / 4).max(MIN_LIMIT);
+            let excess = self.particles.len().saturating_sub(self.limit);
+            self.particles.drain(..excess);
+        }
+        let dt = dt.clamp(0.0, MAX_STEP_MS);
+        for particle in &mut self.particles {
+            particle.step(dt);
+        }
+        self.particles.retain(Particle::alive);
+    }
+
+    fn spawn(&mut self, effect: Effect, width: f64, height: f64) -> Particle {
+        match effect {
+            // Drifting down from just above the top edge
+            Effect::Confetti => Particle {
+                kind: ParticleKind::Confetti(CONFETTI_COLORS[self.index(CONFETTI_COLORS.len())]),
+                position: Point::new(self.between(0.0, width), self.between(-height * 0.25, 0.0)),
+                velocity: Point::new(self.between(-90.0, 90.0), self.between(40.0, 220.0)),
+                age: 0.0,
+                life: self.between(2600.0, 4200.0),
+                size: self.between(7.0, 13.0),
+                rotation: self.between(0.0, TAU),
+                spin: self.between(-9.0, 9.0),
+            },
+            // Thrown up in a fan from below the middle of the screen, then falling back
+            Effect::Chips(amount) => {
+                let angle = -PI / 2.0 + self.between(-0.9, 0.9);
+                let speed = self.between(height * 0.9, height * 1.5);
+                Particle {
+                    kind: ParticleKind::Chip(self.denomination(amount)),
+                    position: Point::new(width / 2.0 + self.between(-30.0, 30.0), height * 0.62),
+                    velocity: Point::new(angle.cos() * speed, angle.sin() * speed),
+                    age: 0.0,
+                    life: self.between(1500.0, 2200.0),
+                    size: self.between(9.0, 14.0),
+                    rotation: 0.0,
+                    spin: 0.0,
+                }
+            }
+            // Twinkling in an oval around the centre of the screen
+            Effect::Sparkle => {
+                let angle = self.between(0.0, TAU);
+                let reach = self.between(0.0, 1.0).sqrt();
+                Particle {
+                    kind: ParticleKind::Sparkle,
+                    position: Point::new(width / 2.0 + angle.cos() * reach * width * 0.32, height / 2.0 + angle.sin() * reach * height * 0.28),
+                    velocity: Point::new(self.between(-15.0, 15.0), self.between(-25.0, 5.0)),
+                    age: 0.0,
+                    life: self.between(900.0, 2400.0),
+                    size: self.between(5.0, 12.0),
+                    rotation: self.between(0.0, TAU),
+                    spin: self.between(-2.0, 2.0),
+                }
+            }
+        }
+    }
+
+    /// One of the denominations making up `amount`, or a spread of small chips without one.
+    fn denomination(&mut self, amount: i64) -> Denomination {
+        let fits: Vec<Denomination> = decompose(amount).into_iter().map(|(denomination, _)| denomination).take(3).collect();
+        if fits.is_empty() {
+            DENOMINATIONS[DENOMINATIONS.len() - 4 + self.index(4)]
+        } else {
+            fits[self.index(fits.len())]
+        }
+    }
+
+    // xorshift32 - plenty for scattering particles, and repeatable in tests
+    fn next(&mut self) -> u32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+        self.seed
+    }
+
+    fn between(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * f64::from(self.next()) / f64::from(u32::MAX)
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        self.next() as usize % len
+    }
+}
+
+pub fn draw_particles(context: &CanvasRenderingContext2d, particles: &[Particle]) {
+    for particle in particles {
+        context.save();
+        context.set_global_alpha(particle.opacity());
+        match particle.kind {
+            ParticleKind::Confetti(color) => {
+                // A strip of paper tumbling: rotated, and flattened as it turns edge-on
+                let _ = context.translate(particle.position.x, particle.position.y);
+                let _ = context.rotate(particle.rotation);
+                let _ = context.scale(1.0, particle.rotation.cos().abs().max(0.15));
+                context.set_fill_style_str(color);
+                context.fill_rect(-particle.size / 2.0, -particle.size / 4.0, particle.size, particle.size / 2.0);
+            }
+            ParticleKind::Chip(denomination) => draw_chip(context, particle.position, particle.size, denomination),
+            ParticleKind::Sparkle => draw_sparkle(context, particle),
+        }
+        context.restore();
+    }
+}
+
+/// A four-pointed star with a soft glow.
+fn draw_sparkle(context: &CanvasRenderingContext2d, particle: &Particle) {
+    let center = particle.position;
+    context.begin_path();
+    for point in 0..8 {
+        let reach = if point % 2 == 0 { particle.size } else { particle.size * 0.28 };
+        let angle = particle.rotation + f64::from(point) * PI / 4.0;
+        let (x, y) = (center.x + reach * angle.cos(), center.y + reach * angle.sin());
+        if point == 0 {
+            context.move_to(x, y);
+        } else {
+            context.line_to(x, y);
+        }
+    }
+    context.close_path();
+    context.set_shadow_color(SPARKLE_COLOR);
+    context.set_shadow_blur(particle.size);
+    context.set_fill_style_str(SPARKLE_COLOR);
+    context.fill();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PotShare;
+
+    fn winner(hand_type: HandType) -> HandWinner {
+        HandWinner { player_id: uuid::Uuid::new_v4(), hand_type, cards: Vec::new(), share: PotShare::High, board: 0 }
+    }
+
+    #[test]
+    fn the_best_hand_outranks_the_pot() {
+        let royal = [winner(HandType::Pair), winner(HandType::RoyalFlush)];
+        assert_eq!(Celebration::for_hand(&royal, 50_000, 100, 100), Some(Celebration::RoyalFlush));
+        assert_eq!(Celebration::for_hand(&[winner(HandType::StraightFlush)], 200, 100, 100), Some(Celebration::StraightFlush));
+        assert_eq!(Celebration::for_hand(&[winner(HandType::FourOfAKind)], 200, 100, 100), None);
+    }
+
+    #[test]
+    fn big_pots_are_measured_in_big_blinds() {
+        let flush = [winner(HandType::Flush)];
+        assert_eq!(Celebration::for_hand(&flush, 9_999, 100, 100), None);
+        assert_eq!(Celebration::for_hand(&flush, 10_000, 100, 100), Some(Celebration::BigPot { amount: 10_000 }));
+        assert_eq!(Celebration::for_hand(&flush, 2_500, 100, 25), Some(Celebration::BigPot { amount: 2_500 }));
+        assert_eq!(Celebration::for_hand(&flush, 10_000, 0, 100), None, "no blinds known, nothing to measure against");
+    }
+
+    #[test]
+    fn celebrations_stay_within_the_budget() {
+        let mut system = ParticleSystem::new(EffectBudget::Low, 7);
+        system.celebrate(Celebration::TournamentWin, 1280.0, 720.0);
+        assert!(system.particles().len() <= EffectBudget::Low.max_particles());
+        assert!(system.particles().len() >= EffectBudget::Low.max_particles() - 2, "the budget should be used, not wasted");
+        assert!(system.particles().iter().any(|particle| matches!(particle.kind, ParticleKind::Chip(_))), "every burst gets a share");
+
+        let before = system.particles().len();
+        system.celebrate(Celebration::RoyalFlush, 1280.0, 720.0);
+        assert_eq!(system.particles().len(), before, "a full budget takes nothing more");
+    }
+
+    #[test]
+    fn slow_frames_shed_the_oldest_particles() {
+        let mut system = ParticleSystem::new(EffectBudget::High, 7);
+        system.celebrate(Celebration::StraightFlush, 1280.0, 720.0);
+        let newest = system.particles().last().cloned();
+        system.step(120.0);
+        assert_eq!(system.limit(), EffectBudget::High.max_particles() * 3 / 4);
+        assert!(system.particles().len() <= system.limit());
+        assert_eq!(system.particles().last().map(|particle| particle.kind), newest.map(|particle| particle.kind));
+
+        for _ in 0..40 {
+            system.celebrate(Celebration::StraightFlush, 1280.0, 720.0);
+            system.step(200.0);
+        }
+        assert_eq!(system.limit(), MIN_LIMIT);
+    }
+
+    #[test]
+    fn particles_fall_fade_and_finish() {
+        let mut system = ParticleSystem::new(EffectBudget::Medium, 42);
+        system.celebrate(Celebration::BigPot { amount: 30_000 }, 1000.0, 800.0);
+        assert!(system.particles().iter().all(|particle| match particle.kind {
+            ParticleKind::Chip(denomination) => denomination.value == 25_000 || denomination.value == 5_000,
+            _ => false,
+        }));
+        assert!(system.particles().iter().all(|particle| particle.velocity.y < 0.0), "chips are thrown upward");
+        assert!(system.particles().iter().all(|particle| particle.opacity() == 1.0));
+
+        for _ in 0..60 {
+            system.step(16.0);
+        }
+        assert!(system.particles().iter().any(|particle| particle.velocity.y > 0.0), "and fall back down");
+        for _ in 0..200 {
+            system.step(16.0);
+        }
+        assert!(system.is_idle());
+    }
+}
@@ -0,0 +1,579 @@
+// Table scene - lays a GameState out as a flat, back-to-front list of nodes in CSS
+// pixels, so every renderer draws the same table and layout stays free of web APIs
+use std::f64::consts::{FRAC_PI_2, TAU};
+use uuid::Uuid;
+
+use crate::game::GameState;
+use crate::types::Card;
+
+pub const CARD_ASPECT: f64 = 1.4; // height / width
+pub const RAIL_RATIO: f64 = 0.08; // rail width against the felt's shorter radius
+const DEFAULT_SEATS: u8 = 6;
+
+const NAME_COLOR: &str = "#fbbf24";
+const CHIPS_COLOR: &str = "#10b981";
+const FOLDED_COLOR: &str = "#64748b";
+const OPEN_SEAT_COLOR: &str = "#94a3b8";
+const AMOUNT_COLOR: &str = "#ffffff";
+const NOTICE_COLOR: &str = "#fbbf24";
+const HAND_NAME_COLOR: &str = "#fbbf24";
+const HIGHLIGHT_GROWTH: f64 = 1.14; // the glow's size against its card
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// The point `t` of the way from here to `other`.
+    pub fn lerp(self, other: Point, t: f64) -> Point {
+        Point::new(self.x + (other.x - self.x) * t, self.y + (other.y - self.y) * t)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn centered(center: Point, width: f64, height: f64) -> Self {
+        Self { x: center.x - width / 2.0, y: center.y - height / 2.0, width, height }
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Felt { center: Point, radius_x: f64, radius_y: f64 },
+    Seat(SeatNode),
+    Card { rect: Rect, card: Card }, // Card::Unknown draws the back
+    Highlight { rect: Rect },        // glow around a card in a winning hand, drawn over it
+    Chips { center: Point, amount: i64, radius: f64 },
+    DealerButton { center: Point, radius: f64 },
+    Label(Text),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Text {
+    pub at: Point, // centre of the text
+    pub text: String,
+    pub size: f64,
+    pub color: &'static str,
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatNode {
+    pub center: Point,
+    pub radius: f64,
+    pub player: Option<SeatPlayer>, // None for an open seat
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatPlayer {
+    pub id: Uuid,
+    pub name: String,
+    pub chips: i64,
+    pub acting: bool,
+    pub folded: bool,
+    pub all_in: bool,
+    pub hero: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlateStyle {
+    Open,
+    Seated,
+    Acting,
+    Folded,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scene {
+    pub width: f64,
+    pub height: f64,
+    pub pot: Point, // where the pot stack sits, whether or not there is one
+    pub nodes: Vec<Node>,
+}
+
+impl Scene {
+    /// Lays out one table, turned so the hero's seat sits at the bottom. Between hands
+    /// the last showdown stays up: the final board, the hands that were shown and the
+    /// winning cards picked out.
+    pub fn from_state(state: &GameState, hero_id: Option<Uuid>, width: f64, height: f64) -> Self {
+        let mut nodes = Vec::new();
+        let center = Point::new(width / 2.0, height / 2.0);
+        let radius_x = width * 0.40;
+        let radius_y = height * 0.34;
+        nodes.push(Node::Felt { center, radius_x, radius_y });
+
+        let rules = state.game_type().rules();
+        let showdown = state.last_hand.as_ref().filter(|_| !state.is_in_hand());
+        let card_nodes = |rect: Rect, card: Card| {
+            let mut nodes = vec![Node::Card { rect, card }];
+            if showdown.is_some_and(|summary| summary.is_winning_card(&card)) {
+                nodes.push(Node::Highlight { rect: Rect::centered(rect.center(), rect.width * HIGHLIGHT_GROWTH, rect.height * HIGHLIGHT_GROWTH) });
+            }
+            nodes
+        };
+        let seats = state.current_room.as_ref().map_or(DEFAULT_SEATS, |room| room.max_players).max(2);
+        let seat_index = |seat_position: u8| usize::from(seat_position.saturating_sub(1)) % usize::from(seats);
+        let hero_seat = hero_id
+            .and_then(|id| state.players.get(&id))
+            .map_or(0, |hero| seat_index(hero.seat_position));
+
+        let unit = width.min(height * 1.6);
+        let seat_radius = unit * 0.055;
+        let card_width = unit * 0.045;
+        let chip_radius = unit * 0.014;
+        let seat_center = |index: usize| {
+            let turn = (index + usize::from(seats) - hero_seat) % usize::from(seats);
+            let angle = FRAC_PI_2 + turn as f64 * TAU / f64::from(seats);
+            Point::new(center.x + radius_x * angle.cos(), center.y + radius_y * angle.sin())
+        };
+
+        // Seats first, then everything that sits on the felt in front of them
+        let mut players: Vec<_> = state.players.values().collect();
+        players.sort_by_key(|player| player.seat_position);
+        for index in 0..usize::from(seats) {
+            let seat = SeatNode {
+                center: seat_center(index),
+                radius: seat_radius,
+                player: players
+                    .iter()
+                    .find(|player| seat_index(player.seat_position) == index)
+                    .map(|player| SeatPlayer {
+                        id: player.id,
+                        name: player.display_name.clone(),
+                        chips: player.chips,
+                        acting: state.active_player == Some(player.id),
+                        folded: player.is_folded,
+                        all_in: player.is_all_in,
+                        hero: hero_id == Some(player.id),
+                    }),
+            };
+            let labels = seat.labels();
+            nodes.push(Node::Seat(seat));
+            nodes.extend(labels);
+        }
+
+        for player in &players {
+            let seat = seat_center(seat_index(player.seat_position));
+
+            let cards = if state.is_in_hand() {
+                match (player.is_folded, player.hand.is_empty()) {
+                    (true, _) => None,
+                    (false, true) => Some(vec![Card::Unknown; rules.hole_cards]),
+                    (false, false) => Some(player.hand.clone()),
+                }
+            } else {
+                showdown.and_then(|summary| summary.shown_cards(player.id)).map(<[Card]>::to_vec)
+            };
+            let hand_center = seat.lerp(center, 0.3);
+            if let Some(cards) = cards {
+                let step = card_width * 0.55;
+                let first = hand_center.x - step * (cards.len() as f64 - 1.0) / 2.0;
+                for (i, card) in cards.into_iter().enumerate() {
+                    let at = Point::new(first + step * i as f64, hand_center.y);
+                    nodes.extend(card_nodes(Rect::centered(at, card_width, card_width * CARD_ASPECT), card));
+                }
+            }
+            if let Some((summary, line)) = showdown.and_then(|summary| {
+                summary.winners.iter().find(|line| line.player_id == player.id).map(|line| (summary, line))
+            }) {
+                nodes.push(Node::Label(Text {
+                    at: Point::new(hand_center.x, hand_center.y + card_width * CARD_ASPECT * 0.5 + unit * 0.018),
+                    text: summary.hand_name(line).to_string(),
+                    size: unit * 0.02,
+                    color: HAND_NAME_COLOR,
+                    bold: true,
+                }));
+            }
+
+            if player.current_bet > 0 {
+                nodes.extend(chip_stack(seat.lerp(center, 0.55), player.current_bet, chip_radius));
+            }
+
+            let is_dealer = player.is_dealer || state.dealer_position == Some(player.seat_position);
+            if is_dealer {
+                let beside = Point::new(seat.x + seat_radius * 1.4, seat.y);
+                nodes.push(Node::DealerButton { center: beside.lerp(center, 0.2), radius: seat_radius * 0.32 });
+            }
+        }
+
+        // The board, then the pot just below it
+        let board_width = card_width * 1.25;
+        let gap = board_width * 0.15;
+        let board = showdown.map_or(&state.community_cards, |summary| &summary.board);
+        let row_width = board.len() as f64 * (board_width + gap) - gap;
+        for (i, card) in board.iter().enumerate() {
+            let x = center.x - row_width / 2.0 + i as f64 * (board_width + gap) + board_width / 2.0;
+            let rect = Rect::centered(Point::new(x, center.y - board_width * 0.2), board_width, board_width * CARD_ASPECT);
+            nodes.extend(card_nodes(rect, *card));
+        }
+        let pot = Point::new(center.x, center.y + board_width * CARD_ASPECT * 0.6 + unit * 0.03);
+        if state.pot > 0 {
+            nodes.extend(chip_stack(pot, state.pot, chip_radius));
+        }
+
+        if state.paused {
+            nodes.push(Node::Label(Text {
+                at: Point::new(center.x, center.y - radius_y * 0.6),
+                text: "Game paused by the host".to_string(),
+                size: unit * 0.026,
+                color: NOTICE_COLOR,
+                bold: true,
+            }));
+        }
+
+        Self { width, height, pot, nodes }
+    }
+}
+
+impl SeatNode {
+    /// The plate the seat is drawn as; text sits on top of it.
+    pub fn plate(&self) -> Rect {
+        Rect::centered(self.center, self.radius * 2.6, self.radius * 1.5)
+    }
+
+    pub fn style(&self) -> PlateStyle {
+        match self.player {
+            None => PlateStyle::Open,
+            Some(ref player) if player.folded => PlateStyle::Folded,
+            Some(ref player) if player.acting => PlateStyle::Acting,
+            Some(_) => PlateStyle::Seated,
+        }
+    }
+
+    /// The text printed on the seat's plate.
+    pub(crate) fn labels(&self) -> Vec<Node> {
+        let (center, radius) = (self.center, self.radius);
+        let Some(ref player) = self.player else {
+            return vec![Node::Label(Text {
+                at: center,
+                text: "Open".to_string(),
+                size: radius * 0.4,
+                color: OPEN_SEAT_COLOR,
+                bold: false,
+            })];
+        };
+        let name = if player.hero { format!("{} (you)", player.name) } else { player.name.clone() };
+        let chips = if player.all_in { "ALL IN".to_string() } else { format!("${}", player.chips) };
+        let (name_color, chips_color) = if player.folded { (FOLDED_COLOR, FOLDED_COLOR) } else { (NAME_COLOR, CHIPS_COLOR) };
+        vec![
+            Node::Label(Text {
+                at: Point::new(center.x, center.y - radius * 0.28),
+                text: name,
+                size: radius * 0.4,
+                color: name_color,
+                bold: true,
+            }),
+            Node::Label(Text {
+                at: Point::new(center.x, center.y + radius * 0.32),
+                text: chips,
+                size: radius * 0.36,
+                color: chips_color,
+                bold: false,
+            }),
+        ]
+    }
+}
+
+/// Chips with the amount printed underneath.
+pub(crate) fn chip_stack(center: Point, amount: i64, radius: f64) -> [Node; 2] {
+    [
+        Node::Chips { center, amount, radius },
+        Node::Label(Text {
+            at: Point::new(center.x, center.y + radius * 1.9),
+            text: format!("${}", amount),
+            size: radius * 0.95,
+            color: AMOUNT_COLOR,
+            bold: true,
+        }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::types::{BettingStructure, GamePhase, GameRoom, GameType, HandType, HandWinner, PlayerInfo, PotShare, PotWinner, Rank, ShownHand, Suit};
+
+    fn room(max_players: u8) -> GameRoom {
+        GameRoom {
+            id: "table".to_string(),
+            name: "Table".to_string(),
+            game_type: GameType::TexasHoldem,
+            betting_structure: BettingStructure::NoLimit,
+            small_blind: 25,
+            big_blind: 50,
+            min_buy_in: 1000,
+            max_buy_in: 5000,
+            current_players: 0,
+            max_players,
+            is_private: false,
+            is_active: true,
+            tournament_id: None,
+            ante: 0,
+            bring_in: 0,
+            button_ante: false,
+            straddle_allowed: false,
+            run_it_twice: false,
+            owner_id: None,
+            avg_pot: 0,
+            hands_per_hour: 0,
+            waitlist: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn player(name: &str, seat_position: u8, chips: i64) -> PlayerInfo {
+        PlayerInfo {
+            id: Uuid::new_v4(),
+            username: name.to_lowercase(),
+            display_name: name.to_string(),
+            avatar_url: None,
+            chips,
+            seat_position,
+            is_active: true,
+            is_dealer: false,
+            is_small_blind: false,
+            is_big_blind: false,
+            hand: Vec::new(),
+            current_bet: 0,
+            is_all_in: false,
+            is_folded: false,
+            action_time_left: None,
+        }
+    }
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::Card { suit, rank }
+    }
+
+    /// One line per node, rounded to whole pixels, so layouts compare as readable text.
+    fn snapshot(scene: &Scene) -> Vec<String> {
+        let point = |p: &Point| format!("{:.0},{:.0}", p.x, p.y);
+        scene
+            .nodes
+            .iter()
+            .map(|node| match node {
+                Node::Felt { center, radius_x, radius_y } => format!("felt {} {:.0}x{:.0}", point(center), radius_x, radius_y),
+                Node::Seat(seat) => match seat.player {
+                    Some(ref player) => format!("seat {} {} {:?}", point(&seat.center), player.name, seat.style()),
+                    None => format!("seat {} open", point(&seat.center)),
+                },
+                Node::Card { rect, card } => format!("card {} {}", point(&rect.center()), card),
+                Node::Highlight { rect } => format!("highlight {}", point(&rect.center())),
+                Node::Chips { center, amount, .. } => format!("chips {} {}", point(center), amount),
+                Node::DealerButton { center, .. } => format!("button {}", point(center)),
+                Node::Label(text) => format!("label {} {:?}", point(&text.at), text.text),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty_table_is_felt_and_open_seats() {
+        let scene = Scene::from_state(&GameState::new(), None, 800.0, 500.0);
+        assert_eq!(
+            snapshot(&scene),
+            vec![
+                "felt 400,250 320x170",
+                "seat 400,420 open",
+                "label 400,420 \"Open\"",
+                "seat 123,335 open",
+                "label 123,335 \"Open\"",
+                "seat 123,165 open",
+                "label 123,165 \"Open\"",
+                "seat 400,80 open",
+                "label 400,80 \"Open\"",
+                "seat 677,165 open",
+                "label 677,165 \"Open\"",
+                "seat 677,335 open",
+                "label 677,335 \"Open\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn hand_in_progress_from_the_heros_seat() {
+        let mut hero = player("Hero", 3, 1500);
+        hero.hand = vec![card(Rank::Ace, Suit::Spades), card(Rank::King, Suit::Spades)];
+        let mut villain = player("Villain", 1, 2200);
+        villain.current_bet = 100;
+        villain.is_dealer = true;
+        let mut folded = player("Folded", 2, 800);
+        folded.is_folded = true;
+        let (hero_id, villain_id) = (hero.id, villain.id);
+
+        let mut state = GameState::new();
+        state.join_room(room(4), vec![hero, villain, folded]);
+        state.update_game_phase(
+            GamePhase::Flop,
+            vec![card(Rank::Two, Suit::Hearts), card(Rank::Seven, Suit::Clubs), card(Rank::Jack, Suit::Diamonds)],
+            300,
+        );
+        state.active_player = Some(villain_id);
+
+        let scene = Scene::from_state(&state, Some(hero_id), 800.0, 500.0);
+        assert_eq!(
+            snapshot(&scene),
+            vec![
+                "felt 400,250 320x170",
+                "seat 400,80 Villain Acting",
+                "label 400,68 \"Villain\"",
+                "label 400,94 \"$2200\"",
+                "seat 720,250 Folded Folded",
+                "label 720,238 \"Folded\"",
+                "label 720,264 \"$800\"",
+                "seat 400,420 Hero Seated",
+                "label 400,408 \"Hero (you)\"",
+                "label 400,434 \"$1500\"",
+                "seat 80,250 open",
+                "label 80,250 \"Open\"",
+                // Opponents' cards stay face down; folded players have none
+                "card 390,131 🂠",
+                "card 410,131 🂠",
+                "chips 400,174 100",
+                "label 400,195 \"$100\"",
+                "button 449,114",
+                "card 390,369 A♠",
+                "card 410,369 K♠",
+                "card 348,241 2♥",
+                "card 400,241 7♣",
+                "card 452,241 J♦",
+                "chips 400,312 300",
+                "label 400,333 \"$300\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn showdown_stays_up_until_the_next_hand() {
+        let mut hero = player("Hero", 1, 1000);
+        hero.hand = vec![card(Rank::Ace, Suit::Spades), card(Rank::Two, Suit::Clubs)];
+        let villain = player("Villain", 2, 1000);
+        let (hero_id, villain_id) = (hero.id, villain.id);
+
+        let mut state = GameState::new();
+        state.join_room(room(2), vec![hero, villain]);
+        let board = vec![
+            card(Rank::King, Suit::Spades),
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Jack, Suit::Spades),
+            card(Rank::Three, Suit::Hearts),
+            card(Rank::Four, Suit::Diamonds),
+        ];
+        state.update_game_phase(GamePhase::River, board, 400);
+        let villain_hand = vec![card(Rank::Ten, Suit::Spades), card(Rank::Nine, Suit::Hearts)];
+        state.complete_hand(
+            vec![HandWinner {
+                player_id: villain_id,
+                hand_type: HandType::Straight,
+                cards: vec![
+                    card(Rank::King, Suit::Spades),
+                    card(Rank::Queen, Suit::Spades),
+                    card(Rank::Jack, Suit::Spades),
+                    card(Rank::Ten, Suit::Spades),
+                    card(Rank::Nine, Suit::Hearts),
+                ],
+                share: PotShare::Scoop,
+                board: 0,
+            }],
+            vec![PotWinner { player_id: villain_id, amount: 400, share: PotShare::Scoop, board: 0 }],
+            vec![
+                ShownHand { player_id: hero_id, cards: vec![card(Rank::Ace, Suit::Spades), card(Rank::Two, Suit::Clubs)] },
+                ShownHand { player_id: villain_id, cards: villain_hand },
+            ],
+        );
+
+        let scene = Scene::from_state(&state, Some(hero_id), 800.0, 500.0);
+        let nodes = snapshot(&scene);
+        assert_eq!(
+            nodes[7..],
+            [
+                // Both hands face up, the winner's cards picked out and its name underneath
+                "card 390,369 A♠",
+                "card 410,369 2♣",
+                "card 390,131 10♠",
+                "highlight 390,131",
+                "card 410,131 9♥",
+                "highlight 410,131",
+                "label 400,171 \"Straight\"",
+                "card 296,241 K♠",
+                "highlight 296,241",
+                "card 348,241 Q♠",
+                "highlight 348,241",
+                "card 400,241 J♠",
+                "highlight 400,241",
+                "card 452,241 3♥",
+                "card 504,241 4♦",
+            ]
+        );
+
+        // Dealing the next hand clears it
+        state.update_game_phase(GamePhase::PreFlop, Vec::new(), 0);
+        let next = Scene::from_state(&state, Some(hero_id), 800.0, 500.0);
+        assert!(!snapshot(&next).iter().any(|node| node.starts_with("highlight") || node.starts_with("label 400,171")));
+    }
+
+    #[test]
+    fn uncontested_wins_give_nothing_away() {
+        let hero = player("Hero", 1, 1000);
+        let hero_id = hero.id;
+        let mut state = GameState::new();
+        state.join_room(room(2), vec![hero, player("Villain", 2, 1000)]);
+        state.update_game_phase(GamePhase::Flop, vec![card(Rank::Ace, Suit::Hearts), card(Rank::Two, Suit::Clubs), card(Rank::Nine, Suit::Spades)], 150);
+        state.complete_hand(
+            vec![HandWinner { player_id: hero_id, hand_type: HandType::Pair, cards: vec![card(Rank::Ace, Suit::Hearts)], share: PotShare::Scoop, board: 0 }],
+            vec![PotWinner { player_id: hero_id, amount: 150, share: PotShare::Scoop, board: 0 }],
+            Vec::new(),
+        );
+
+        let summary = state.last_hand.clone().unwrap();
+        assert!(summary.uncontested && summary.hero_may_show(Some(hero_id)));
+        let nodes = snapshot(&Scene::from_state(&state, Some(hero_id), 800.0, 500.0));
+        assert!(nodes.contains(&"label 400,409 \"Uncontested\"".to_string()));
+        assert!(!nodes.iter().any(|node| node.starts_with("highlight")));
+
+        // Choosing to show turns the cards up and names the hand
+        state.show_hand(hero_id, vec![card(Rank::Ace, Suit::Spades), card(Rank::King, Suit::Clubs)]);
+        assert!(!state.last_hand.as_ref().unwrap().hero_may_show(Some(hero_id)));
+        let nodes = snapshot(&Scene::from_state(&state, Some(hero_id), 800.0, 500.0));
+        assert!(nodes.contains(&"label 400,409 \"Pair\"".to_string()));
+        assert!(nodes.contains(&"highlight 348,241".to_string()));
+    }
+
+    #[test]
+    fn paused_tables_say_so() {
+        let mut state = GameState::new();
+        state.join_room(room(2), Vec::new());
+        state.paused = true;
+        let scene = Scene::from_state(&state, None, 800.0, 500.0);
+        assert_eq!(snapshot(&scene).last().map(String::as_str), Some("label 400,148 \"Game paused by the host\""));
+    }
+
+    #[test]
+    fn layout_scales_with_the_canvas() {
+        let small = Scene::from_state(&GameState::new(), None, 400.0, 250.0);
+        let large = Scene::from_state(&GameState::new(), None, 1200.0, 750.0);
+        for (small, large) in small.nodes.iter().zip(&large.nodes) {
+            if let (Node::Seat(small), Node::Seat(large)) = (small, large) {
+                assert!((large.center.x - small.center.x * 3.0).abs() < 1e-9);
+                assert!((large.radius - small.radius * 3.0).abs() < 1e-9);
+            }
+        }
+    }
+}
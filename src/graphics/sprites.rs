@@ -0,0 +1,259 @@
+// Sprite batching - turns a Scene into textured quads over one texture atlas, so the
+// WebGL renderer draws a whole table in a single call. Nothing here touches the GPU.
+use crate::graphics::chips::{count_label, ChipStack, Denomination, DENOMINATIONS};
+use crate::graphics::scene::{Node, PlateStyle, Rect, Scene, Text, RAIL_RATIO};
+use crate::types::{Card, Rank, Suit};
+
+pub const ATLAS_SIZE: f64 = 2048.0;
+const PADDING: f64 = 2.0; // between cells, so filtering never bleeds a neighbour in
+const CARD_CELL: (f64, f64) = (96.0, 134.4);
+const CHIP_CELL: f64 = 64.0;
+const PLATE_CELL: (f64, f64) = (208.0, 120.0);
+const FELT_CELL: (f64, f64) = (960.0, 512.0);
+const MISC_ROW: f64 = 560.0;
+const CHIP_ROW: f64 = MISC_ROW + CARD_CELL.1 + PADDING; // under the card back, the row's tallest piece
+const CHIP_COUNT_COLOR: &str = "#ffffff";
+const FELT_ROW: f64 = 1024.0;
+
+pub const SUITS: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+pub const RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+const PLATE_STYLES: [PlateStyle; 4] = [PlateStyle::Open, PlateStyle::Seated, PlateStyle::Acting, PlateStyle::Folded];
+
+/// One picture in the atlas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frame {
+    Felt, // rail included
+    CardFace(Suit, Rank),
+    CardBack,
+    Highlight,
+    Chip(Denomination),
+    DealerButton,
+    Plate(PlateStyle),
+}
+
+impl Frame {
+    pub fn all() -> Vec<Frame> {
+        let mut frames = vec![Frame::Felt, Frame::CardBack, Frame::Highlight, Frame::DealerButton];
+        frames.extend(DENOMINATIONS.iter().map(|&denomination| Frame::Chip(denomination)));
+        frames.extend(SUITS.iter().flat_map(|&suit| RANKS.iter().map(move |&rank| Frame::CardFace(suit, rank))));
+        frames.extend(PLATE_STYLES.iter().map(|&style| Frame::Plate(style)));
+        frames
+    }
+
+    pub fn for_card(card: &Card) -> Frame {
+        match *card {
+            Card::Card { suit, rank } => Frame::CardFace(suit, rank),
+            Card::Unknown => Frame::CardBack,
+        }
+    }
+
+    /// Where the frame is painted, in atlas pixels.
+    ///
+    /// Card faces fill a 13 x 4 grid at the top left, the small pieces share the row
+    /// under it with the chips in a row of their own below, and the felt has the bottom
+    /// half to itself.
+    pub fn cell(self) -> Rect {
+        let (card_width, card_height) = CARD_CELL;
+        let grid = |column: usize, row: usize| Rect {
+            x: column as f64 * (card_width + PADDING),
+            y: row as f64 * (card_height + PADDING),
+            width: card_width,
+            height: card_height,
+        };
+        match self {
+            Frame::CardFace(suit, rank) => {
+                let column = RANKS.iter().position(|&r| r == rank).unwrap_or(0);
+                let row = SUITS.iter().position(|&s| s == suit).unwrap_or(0);
+                grid(column, row)
+            }
+            Frame::CardBack => Rect { x: 0.0, y: MISC_ROW, width: card_width, height: card_height },
+            Frame::Chip(denomination) => {
+                let index = DENOMINATIONS.iter().position(|&d| d == denomination).unwrap_or(0);
+                Rect { x: index as f64 * (CHIP_CELL + PADDING), y: CHIP_ROW, width: CHIP_CELL, height: CHIP_CELL }
+            }
+            Frame::DealerButton => Rect { x: card_width + PADDING, y: MISC_ROW, width: CHIP_CELL, height: CHIP_CELL },
+            Frame::Highlight => Rect {
+                x: card_width + CHIP_CELL + PADDING * 2.0 + PLATE_STYLES.len() as f64 * (PLATE_CELL.0 + PADDING),
+                y: MISC_ROW,
+                width: card_width,
+                height: card_height,
+            },
+            Frame::Plate(style) => {
+                let index = PLATE_STYLES.iter().position(|&s| s == style).unwrap_or(0);
+                Rect {
+                    x: card_width + CHIP_CELL + PADDING * 2.0 + index as f64 * (PLATE_CELL.0 + PADDING),
+                    y: MISC_ROW,
+                    width: PLATE_CELL.0,
+                    height: PLATE_CELL.1,
+                }
+            }
+            Frame::Felt => Rect { x: 0.0, y: FELT_ROW, width: FELT_CELL.0, height: FELT_CELL.1 },
+        }
+    }
+
+    /// The cell as texture coordinates: left, top, right, bottom.
+    pub fn uv(self) -> [f32; 4] {
+        let cell = self.cell();
+        [
+            (cell.x / ATLAS_SIZE) as f32,
+            (cell.y / ATLAS_SIZE) as f32,
+            ((cell.x + cell.width) / ATLAS_SIZE) as f32,
+            ((cell.y + cell.height) / ATLAS_SIZE) as f32,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quad {
+    pub frame: Frame,
+    pub rect: Rect, // CSS pixels
+}
+
+/// A scene split into what the GPU draws and the text drawn over it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpriteBatch {
+    pub quads: Vec<Quad>,
+    pub labels: Vec<Text>,
+}
+
+impl SpriteBatch {
+    pub const FLOATS_PER_VERTEX: usize = 4; // x, y, u, v
+    pub const VERTICES_PER_QUAD: usize = 6;
+
+    pub fn from_scene(scene: &Scene) -> Self {
+        let mut batch = SpriteBatch::default();
+        for node in &scene.nodes {
+            match node {
+                Node::Felt { center, radius_x, radius_y } => {
+                    let rail = radius_x.min(*radius_y) * RAIL_RATIO;
+                    batch.push(Frame::Felt, Rect::centered(*center, (radius_x + rail) * 2.0, (radius_y + rail) * 2.0));
+                }
+                Node::Seat(seat) => batch.push(Frame::Plate(seat.style()), seat.plate()),
+                Node::Card { rect, card } => batch.push(Frame::for_card(card), *rect),
+                Node::Highlight { rect } => batch.push(Frame::Highlight, *rect),
+                Node::Chips { center, amount, radius } => {
+                    let stack = ChipStack::new(*amount);
+                    for chip in stack.place(*center, *radius) {
+                        batch.push(Frame::Chip(chip.denomination), Rect::centered(chip.center, radius * 2.0, radius * 2.0));
+                    }
+                    batch.labels.extend(stack.counts(*center, *radius).into_iter().map(|(at, count)| Text {
+                        at,
+                        text: count_label(count),
+                        size: radius * 0.9,
+                        color: CHIP_COUNT_COLOR,
+                        bold: true,
+                    }));
+                }
+                Node::DealerButton { center, radius } => {
+                    batch.push(Frame::DealerButton, Rect::centered(*center, radius * 2.0, radius * 2.0));
+                }
+                Node::Label(text) => batch.labels.push(text.clone()),
+            }
+        }
+        batch
+    }
+
+    fn push(&mut self, frame: Frame, rect: Rect) {
+        self.quads.push(Quad { frame, rect });
+    }
+
+    /// Two triangles per quad, ready for one drawArrays call.
+    pub fn vertices(&self) -> Vec<f32> {
+        let mut vertices = Vec::with_capacity(self.quads.len() * Self::VERTICES_PER_QUAD * Self::FLOATS_PER_VERTEX);
+        for quad in &self.quads {
+            let [u0, v0, u1, v1] = quad.frame.uv();
+            let (x0, y0) = (quad.rect.x as f32, quad.rect.y as f32);
+            let (x1, y1) = ((quad.rect.x + quad.rect.width) as f32, (quad.rect.y + quad.rect.height) as f32);
+            vertices.extend_from_slice(&[
+                x0, y0, u0, v0, x1, y0, u1, v0, x0, y1, u0, v1, //
+                x0, y1, u0, v1, x1, y0, u1, v0, x1, y1, u1, v1,
+            ]);
+        }
+        vertices
+    }
+
+    pub fn vertex_count(&self) -> i32 {
+        (self.quads.len() * Self::VERTICES_PER_QUAD) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::scene::{Point, SeatNode};
+
+    fn overlaps(a: Rect, b: Rect) -> bool {
+        a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+    }
+
+    #[test]
+    fn atlas_cells_fit_and_never_overlap() {
+        let frames = Frame::all();
+        assert_eq!(frames.len(), 4 + DENOMINATIONS.len() + 52 + 4);
+        for (i, frame) in frames.iter().enumerate() {
+            let cell = frame.cell();
+            assert!(cell.x >= 0.0 && cell.y >= 0.0, "{:?} starts outside the atlas", frame);
+            assert!(cell.x + cell.width <= ATLAS_SIZE && cell.y + cell.height <= ATLAS_SIZE, "{:?} runs off the atlas", frame);
+            for other in &frames[i + 1..] {
+                assert!(!overlaps(cell, other.cell()), "{:?} overlaps {:?}", frame, other);
+            }
+        }
+    }
+
+    #[test]
+    fn batches_every_sprite_into_one_vertex_buffer() {
+        let center = Point::new(400.0, 250.0);
+        let scene = Scene {
+            width: 800.0,
+            height: 500.0,
+            pot: center,
+            nodes: vec![
+                Node::Felt { center, radius_x: 320.0, radius_y: 170.0 },
+                Node::Seat(SeatNode { center, radius: 40.0, player: None }),
+                Node::Card { rect: Rect::centered(center, 36.0, 50.4), card: Card::Unknown },
+                Node::Chips { center, amount: 150, radius: 10.0 },
+                Node::Label(Text { at: center, text: "$150".to_string(), size: 10.0, color: "#ffffff", bold: true }),
+            ],
+        };
+        let batch = SpriteBatch::from_scene(&scene);
+
+        // $150 stands as one black chip beside two greens
+        let frames: Vec<Frame> = batch.quads.iter().map(|quad| quad.frame).collect();
+        let chip = |value: i64| Frame::Chip(DENOMINATIONS.into_iter().find(|d| d.value == value).unwrap());
+        assert_eq!(
+            frames,
+            vec![Frame::Felt, Frame::Plate(PlateStyle::Open), Frame::CardBack, chip(100), chip(25), chip(25)]
+        );
+        assert_eq!(batch.labels.len(), 1);
+        assert_eq!(batch.vertex_count(), 36);
+        assert_eq!(batch.vertices().len(), 36 * SpriteBatch::FLOATS_PER_VERTEX);
+
+        // The felt quad grows by the rail on every side
+        let felt = batch.quads[0].rect;
+        assert_eq!((felt.width, felt.height), (2.0 * (320.0 + 170.0 * RAIL_RATIO), 2.0 * (170.0 + 170.0 * RAIL_RATIO)));
+    }
+
+    #[test]
+    fn card_quads_sample_their_own_cell() {
+        let ace = Frame::for_card(&Card::Card { suit: Suit::Spades, rank: Rank::Ace });
+        assert_eq!(ace, Frame::CardFace(Suit::Spades, Rank::Ace));
+        let [u0, v0, u1, v1] = ace.uv();
+        assert!(u0 < u1 && v0 < v1);
+        assert!(u1 <= 1.0 && v1 <= 1.0);
+        assert_ne!(ace.uv(), Frame::CardFace(Suit::Hearts, Rank::Ace).uv());
+    }
+}
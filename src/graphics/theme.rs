@@ -0,0 +1,360 @@
+// Themes - the table's colours, felt, card backs and deck, chosen on the profile page. The
+// DOM table reads them as CSS variables on the root element; the canvas renderers take the
+// resolved Palette directly, so both tables always match.
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+use crate::types::Suit;
+
+const THEME_KEY: &str = "primo_poker_theme";
+pub const FELT_URL: &str = "/assets/backgrounds/poker-table-felt.svg";
+pub const CARD_BACK_URL: &str = "/assets/backgrounds/card-back-pattern.svg";
+const BACK_STRIPES: &str = "repeating-linear-gradient(45deg, rgba(255, 255, 255, 0.1), rgba(255, 255, 255, 0.1) 2px, transparent 2px, transparent 6px)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ThemeName {
+    #[default]
+    Classic,
+    Dark,
+    HighContrast,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 3] = [ThemeName::Classic, ThemeName::Dark, ThemeName::HighContrast];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeName::Classic => "Classic green",
+            ThemeName::Dark => "Dark",
+            ThemeName::HighContrast => "High contrast",
+        }
+    }
+
+    /// Everything but the felt, card backs and deck, which are chosen separately.
+    fn base(self) -> Palette {
+        match self {
+            ThemeName::Classic => Palette {
+                page: "linear-gradient(135deg, #0f172a 0%, #1e293b 25%, #0f5132 75%, #134e4a 100%)",
+                header: "rgba(15, 23, 42, 0.95)",
+                accent: "#fbbf24",
+                accent_text: "#1f2937",
+                muted: "#cbd5e1",
+                rail: "#5b3a1e",
+                table_border: "#10b981",
+                table_glow: "rgba(16, 185, 129, 0.3)",
+                seat: "rgba(15, 23, 42, 0.9)",
+                seat_border: "rgba(16, 185, 129, 0.6)",
+                open_seat: "rgba(148, 163, 184, 0.5)",
+                seat_name: "#fbbf24",
+                seat_chips: "#10b981",
+                card_face: "#ffffff",
+                card_ink: "#1f2937",
+                card_red: "#dc2626",
+                ..Palette::default()
+            },
+            ThemeName::Dark => Palette {
+                page: "linear-gradient(135deg, #020617 0%, #0f172a 60%, #1e293b 100%)",
+                header: "rgba(2, 6, 23, 0.97)",
+                accent: "#f59e0b",
+                accent_text: "#0f172a",
+                muted: "#94a3b8",
+                rail: "#1c1917",
+                table_border: "#475569",
+                table_glow: "rgba(0, 0, 0, 0.5)",
+                seat: "rgba(2, 6, 23, 0.92)",
+                seat_border: "rgba(100, 116, 139, 0.6)",
+                open_seat: "rgba(100, 116, 139, 0.5)",
+                seat_name: "#f59e0b",
+                seat_chips: "#34d399",
+                card_face: "#e2e8f0",
+                card_ink: "#0f172a",
+                card_red: "#b91c1c",
+                ..Palette::default()
+            },
+            ThemeName::HighContrast => Palette {
+                page: "#000000",
+                header: "#000000",
+                accent: "#ffff00",
+                accent_text: "#000000",
+                muted: "#ffffff",
+                rail: "#f8fafc",
+                table_border: "#ffffff",
+                table_glow: "rgba(255, 255, 255, 0.4)",
+                seat: "#000000",
+                seat_border: "#ffffff",
+                open_seat: "#ffffff",
+                seat_name: "#ffff00",
+                seat_chips: "#00ff00",
+                card_face: "#ffffff",
+                card_ink: "#000000",
+                card_red: "#d00000",
+                ..Palette::default()
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Felt {
+    #[default]
+    Green,
+    Blue,
+    Burgundy,
+    Charcoal,
+}
+
+impl Felt {
+    pub const ALL: [Felt; 4] = [Felt::Green, Felt::Blue, Felt::Burgundy, Felt::Charcoal];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Felt::Green => "Casino green",
+            Felt::Blue => "Tournament blue",
+            Felt::Burgundy => "Burgundy",
+            Felt::Charcoal => "Charcoal",
+        }
+    }
+
+    pub fn color(self) -> &'static str {
+        match self {
+            Felt::Green => "#0f5132",
+            Felt::Blue => "#1e3a5f",
+            Felt::Burgundy => "#5f1a24",
+            Felt::Charcoal => "#1f2937",
+        }
+    }
+
+    /// Only the green felt has artwork; the others are plain cloth.
+    pub fn texture(self) -> Option<&'static str> {
+        (self == Felt::Green).then_some(FELT_URL)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum CardBack {
+    #[default]
+    Ornate,
+    BlueStripes,
+    RedStripes,
+}
+
+impl CardBack {
+    pub const ALL: [CardBack; 3] = [CardBack::Ornate, CardBack::BlueStripes, CardBack::RedStripes];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CardBack::Ornate => "Ornate",
+            CardBack::BlueStripes => "Blue stripes",
+            CardBack::RedStripes => "Red stripes",
+        }
+    }
+
+    pub fn artwork(self) -> Option<&'static str> {
+        (self == CardBack::Ornate).then_some(CARD_BACK_URL)
+    }
+
+    /// The base colour and the lighter one it fades to; the ornate back shows these
+    /// until its artwork loads.
+    pub fn colors(self) -> (&'static str, &'static str) {
+        match self {
+            CardBack::Ornate => ("#1a365d", "#2c5282"),
+            CardBack::BlueStripes => ("#1e40af", "#3b82f6"),
+            CardBack::RedStripes => ("#991b1b", "#dc2626"),
+        }
+    }
+}
+
+/// Every colour the tables draw with, resolved from the player's ThemeSettings.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Palette {
+    pub page: &'static str, // game page background, any CSS background
+    pub header: &'static str,
+    pub accent: &'static str, // titles, the acting seat, dealer button and winning cards
+    pub accent_text: &'static str, // on the accent colour
+    pub muted: &'static str,
+    pub felt: &'static str,
+    pub felt_texture: Option<&'static str>,
+    pub rail: &'static str,
+    pub table_border: &'static str,
+    pub table_glow: &'static str,
+    pub seat: &'static str,
+    pub seat_border: &'static str,
+    pub open_seat: &'static str,
+    pub seat_name: &'static str,
+    pub seat_chips: &'static str,
+    pub card_face: &'static str,
+    pub card_ink: &'static str,
+    pub card_red: &'static str,
+    pub card_back: (&'static str, &'static str),
+    pub card_back_art: Option<&'static str>,
+    pub four_color: bool,
+}
+
+impl Palette {
+    /// Red and black, or each suit its own colour in a four-colour deck.
+    pub fn suit_color(&self, suit: Suit) -> &'static str {
+        match (suit, self.four_color) {
+            (Suit::Hearts, _) | (Suit::Diamonds, false) => self.card_red,
+            (Suit::Clubs, false) | (Suit::Spades, _) => self.card_ink,
+            (Suit::Diamonds, true) => "#1d4ed8",
+            (Suit::Clubs, true) => "#15803d",
+        }
+    }
+
+    /// Custom properties for the DOM table, names without the leading dashes.
+    pub fn css_variables(&self) -> Vec<(&'static str, String)> {
+        let (back, back_light) = self.card_back;
+        let back_background = match self.card_back_art {
+            Some(url) => format!("url(\"{}\") center / cover, {}", url, back),
+            None => format!("linear-gradient(45deg, {}, {})", back, back_light),
+        };
+        let mut variables = vec![
+            ("game-background", self.page.to_string()),
+            ("game-header", self.header.to_string()),
+            ("game-accent", self.accent.to_string()),
+            ("game-accent-text", self.accent_text.to_string()),
+            ("game-muted", self.muted.to_string()),
+            ("table-felt", self.felt.to_string()),
+            ("table-felt-texture", self.felt_texture.map_or("none".to_string(), |url| format!("url(\"{}\") center / cover", url))),
+            ("table-rail", self.rail.to_string()),
+            ("table-border", self.table_border.to_string()),
+            ("table-glow", self.table_glow.to_string()),
+            ("seat-background", self.seat.to_string()),
+            ("seat-border", self.seat_border.to_string()),
+            ("seat-open", self.open_seat.to_string()),
+            ("seat-name", self.seat_name.to_string()),
+            ("seat-chips", self.seat_chips.to_string()),
+            ("card-face", self.card_face.to_string()),
+            ("card-back", back_background),
+            ("card-back-pattern", if self.card_back_art.is_some() { "none" } else { BACK_STRIPES }.to_string()),
+        ];
+        variables.extend(SUITS.iter().map(|&(suit, name)| (name, self.suit_color(suit).to_string())));
+        variables
+    }
+}
+
+const SUITS: [(Suit, &str); 4] =
+    [(Suit::Hearts, "suit-hearts"), (Suit::Diamonds, "suit-diamonds"), (Suit::Clubs, "suit-clubs"), (Suit::Spades, "suit-spades")];
+
+/// The CSS variable holding `symbol`'s colour, for components that only have the printed suit.
+pub fn suit_variable(symbol: &str) -> String {
+    SUITS
+        .iter()
+        .find(|(suit, _)| suit.to_string() == symbol)
+        .map_or("inherit".to_string(), |(_, name)| format!("var(--{})", name))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSettings {
+    pub theme: ThemeName,
+    pub felt: Felt,
+    pub card_back: CardBack,
+    pub four_color: bool,
+}
+
+impl ThemeSettings {
+    pub fn load() -> Self {
+        LocalStorage::get(THEME_KEY).unwrap_or_default()
+    }
+
+    pub fn save(self) {
+        let _ = LocalStorage::set(THEME_KEY, self);
+    }
+
+    pub fn palette(&self) -> Palette {
+        Palette {
+            felt: self.felt.color(),
+            felt_texture: self.felt.texture(),
+            card_back: self.card_back.colors(),
+            card_back_art: self.card_back.artwork(),
+            four_color: self.four_color,
+            ..self.theme.base()
+        }
+    }
+
+    /// Sets the CSS variables on the root element. Canvas tables pick the theme up the
+    /// next time one opens.
+    pub fn apply(&self) {
+        let Some(root) = gloo_utils::document().document_element() else {
+            return;
+        };
+        let _ = root.set_attribute("data-theme", &format!("{:?}", self.theme).to_lowercase());
+        if let Some(style) = root.dyn_ref::<HtmlElement>().map(HtmlElement::style) {
+            for (name, value) in self.palette().css_variables() {
+                let _ = style.set_property(&format!("--{}", name), &value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn themes_fill_every_colour() {
+        for theme in ThemeName::ALL {
+            let palette = ThemeSettings { theme, ..ThemeSettings::default() }.palette();
+            for (name, value) in palette.css_variables() {
+                assert!(!value.is_empty(), "{:?} leaves --{} empty", theme, name);
+            }
+        }
+    }
+
+    #[test]
+    fn felt_and_card_back_are_chosen_apart_from_the_theme() {
+        let settings = ThemeSettings { theme: ThemeName::Dark, felt: Felt::Burgundy, card_back: CardBack::RedStripes, four_color: false };
+        let palette = settings.palette();
+        assert_eq!(palette.felt, "#5f1a24");
+        assert_eq!(palette.felt_texture, None);
+        assert_eq!(palette.card_back_art, None);
+        assert_eq!(palette.seat_border, ThemeName::Dark.base().seat_border);
+
+        let variables = palette.css_variables();
+        let variable = |name: &str| variables.iter().find(|(key, _)| *key == name).map(|(_, value)| value.clone());
+        assert_eq!(variable("table-felt-texture").as_deref(), Some("none"));
+        assert_eq!(variable("card-back").as_deref(), Some("linear-gradient(45deg, #991b1b, #dc2626)"));
+    }
+
+    #[test]
+    fn defaults_to_classic_with_the_artwork() {
+        let palette = ThemeSettings::default().palette();
+        assert_eq!(palette.felt_texture, Some(FELT_URL));
+        assert_eq!(palette.card_back_art, Some(CARD_BACK_URL));
+        let variables = palette.css_variables();
+        assert!(variables.iter().any(|(name, value)| *name == "card-back" && value.contains(CARD_BACK_URL)));
+        assert!(variables.iter().any(|(name, value)| *name == "card-back-pattern" && value == "none"));
+    }
+
+    #[test]
+    fn four_colour_decks_split_the_suits() {
+        let two = ThemeSettings::default().palette();
+        let four = ThemeSettings { four_color: true, ..ThemeSettings::default() }.palette();
+        assert_eq!(two.suit_color(Suit::Hearts), two.suit_color(Suit::Diamonds));
+        assert_eq!(two.suit_color(Suit::Clubs), two.suit_color(Suit::Spades));
+
+        let colors: std::collections::HashSet<&str> = SUITS.iter().map(|&(suit, _)| four.suit_color(suit)).collect();
+        assert_eq!(colors.len(), 4);
+        assert_eq!(four.suit_color(Suit::Hearts), two.suit_color(Suit::Hearts));
+        assert_eq!(four.suit_color(Suit::Spades), two.suit_color(Suit::Spades));
+    }
+
+    #[test]
+    fn stylesheet_defaults_match_the_classic_theme() {
+        let stylesheet = include_str!("../../styles/main.css");
+        for (name, value) in ThemeSettings::default().palette().css_variables() {
+            let declaration = format!("--{}: {};", name, value);
+            assert!(stylesheet.contains(&declaration), "main.css should declare {}", declaration);
+        }
+    }
+
+    #[test]
+    fn printed_suits_find_their_variable() {
+        assert_eq!(suit_variable("♦"), "var(--suit-diamonds)");
+        assert_eq!(suit_variable("♠"), "var(--suit-spades)");
+        assert_eq!(suit_variable("hidden"), "inherit");
+    }
+}
@@ -0,0 +1,296 @@
+// WebGL2 table renderer - every sprite in a scene drawn from one atlas texture in a
+// single call, with the text on a transparent 2D canvas stacked over the GL one
+use std::cell::Cell;
+use std::rc::Rc;
+use gloo_events::EventListener;
+use gloo_utils::document;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement, WebGl2RenderingContext as Gl, WebGlBuffer,
+    WebGlProgram, WebGlShader, WebGlTexture, WebGlUniformLocation, WebGlVertexArrayObject,
+};
+use yew::Callback;
+
+use crate::graphics::canvas::{context_2d, draw_chip, draw_chip_art, draw_dealer_button, draw_felt, draw_seat_plate, draw_text};
+use crate::graphics::card_renderer::{draw_card, draw_card_face, draw_highlight, rounded_rect};
+use crate::graphics::scene::{PlateStyle, Rect, Scene, RAIL_RATIO};
+use crate::graphics::sprites::{Frame, SpriteBatch, ATLAS_SIZE};
+use crate::graphics::theme::Palette;
+use crate::graphics::TableRenderer;
+use crate::types::Card;
+
+const DEALER_BUTTON_URL: &str = "/assets/ui/dealer-button.svg";
+const CHIP_URL: &str = "/assets/ui/chip-stack.svg";
+const CARD_SUITS_URL: &str = "/assets/ui/card-suits.svg";
+// assets/ui has no plate artwork, so plates take the table rail's wood grain over their fill
+const PLATE_GRAIN_URL: &str = "/assets/backgrounds/wood-grain.svg";
+const PLATE_GRAIN_ALPHA: f64 = 0.3;
+
+const VERTEX_SHADER: &str = r#"#version 300 es
+layout(location = 0) in vec2 a_position;
+layout(location = 1) in vec2 a_uv;
+uniform vec2 u_resolution;
+out vec2 v_uv;
+
+void main() {
+    vec2 clip = a_position / u_resolution * 2.0 - 1.0;
+    gl_Position = vec4(clip.x, -clip.y, 0.0, 1.0);
+    v_uv = a_uv;
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 300 es
+precision mediump float;
+uniform sampler2D u_atlas;
+in vec2 v_uv;
+out vec4 color;
+
+void main() {
+    color = texture(u_atlas, v_uv);
+}
+"#;
+
+pub struct WebGlRenderer {
+    canvas: HtmlCanvasElement,
+    gl: Gl,
+    program: WebGlProgram,
+    vertex_array: WebGlVertexArrayObject,
+    vertex_buffer: WebGlBuffer,
+    texture: WebGlTexture,
+    resolution: Option<WebGlUniformLocation>,
+    atlas: HtmlCanvasElement,
+    atlas_dirty: Rc<Cell<bool>>, // repainted since the last upload
+    overlay: HtmlCanvasElement,
+    overlay_context: CanvasRenderingContext2d,
+    pixel_ratio: f64,
+    _asset_listeners: Vec<EventListener>,
+}
+
+impl WebGlRenderer {
+    /// Fails without touching the page when the browser has no WebGL2.
+    pub fn new(canvas: HtmlCanvasElement, palette: Palette, on_asset_loaded: Callback<()>) -> Result<Self, JsValue> {
+        let gl: Gl = canvas
+            .get_context("webgl2")?
+            .ok_or_else(|| JsValue::from_str("WebGL2 unavailable"))?
+            .dyn_into()
+            .map_err(JsValue::from)?;
+
+        let program = link_program(
+            &gl,
+            &compile_shader(&gl, Gl::VERTEX_SHADER, VERTEX_SHADER)?,
+            &compile_shader(&gl, Gl::FRAGMENT_SHADER, FRAGMENT_SHADER)?,
+        )?;
+        let resolution = gl.get_uniform_location(&program, "u_resolution");
+
+        let vertex_array = gl.create_vertex_array().ok_or_else(|| JsValue::from_str("no vertex array"))?;
+        let vertex_buffer = gl.create_buffer().ok_or_else(|| JsValue::from_str("no vertex buffer"))?;
+        gl.bind_vertex_array(Some(&vertex_array));
+        gl.bind_buffer(Gl::ARRAY_BUFFER, Some(&vertex_buffer));
+        let stride = (SpriteBatch::FLOATS_PER_VERTEX * 4) as i32;
+        gl.enable_vertex_attrib_array(0);
+        gl.vertex_attrib_pointer_with_i32(0, 2, Gl::FLOAT, false, stride, 0);
+        gl.enable_vertex_attrib_array(1);
+        gl.vertex_attrib_pointer_with_i32(1, 2, Gl::FLOAT, false, stride, 8);
+        gl.bind_vertex_array(None);
+
+        let texture = gl.create_texture().ok_or_else(|| JsValue::from_str("no texture"))?;
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&texture));
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, Gl::LINEAR as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, Gl::LINEAR as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
+        gl.pixel_storei(Gl::UNPACK_PREMULTIPLY_ALPHA_WEBGL, 1);
+        gl.enable(Gl::BLEND);
+        gl.blend_func(Gl::ONE, Gl::ONE_MINUS_SRC_ALPHA);
+
+        // The atlas starts out fully drawn by hand; the artwork replaces pieces as it loads
+        let atlas: HtmlCanvasElement = document().create_element("canvas")?.dyn_into()?;
+        atlas.set_width(ATLAS_SIZE as u32);
+        atlas.set_height(ATLAS_SIZE as u32);
+        let atlas_context = context_2d(&atlas)?;
+        let frames = Frame::all();
+        for &frame in &frames {
+            paint_frame(&atlas_context, &palette, frame, None);
+        }
+        let atlas_dirty = Rc::new(Cell::new(true));
+        let every = |matches: fn(&Frame) -> bool| frames.iter().copied().filter(matches).collect::<Vec<_>>();
+        let artwork = [
+            (palette.felt_texture, vec![Frame::Felt]),
+            (palette.card_back_art, vec![Frame::CardBack]),
+            (Some(DEALER_BUTTON_URL), vec![Frame::DealerButton]),
+            (Some(CHIP_URL), every(|frame| matches!(frame, Frame::Chip(_)))),
+            (Some(CARD_SUITS_URL), every(|frame| matches!(frame, Frame::CardFace(..)))),
+            (Some(PLATE_GRAIN_URL), every(|frame| matches!(frame, Frame::Plate(_)))),
+        ];
+        let asset_listeners = artwork
+            .into_iter()
+            .filter_map(|(url, frames)| url.map(|url| (url, frames)))
+            .map(|(url, frames)| load_artwork(url, &palette, frames, &atlas_context, &atlas_dirty, &on_asset_loaded))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let overlay: HtmlCanvasElement = document().create_element("canvas")?.dyn_into()?;
+        overlay.set_class_name("canvas-table-overlay");
+        let overlay_context = context_2d(&overlay)?;
+        canvas.after_with_node_1(&overlay)?;
+
+        Ok(Self {
+            canvas,
+            gl,
+            program,
+            vertex_array,
+            vertex_buffer,
+            texture,
+            resolution,
+            atlas,
+            atlas_dirty,
+            overlay,
+            overlay_context,
+            pixel_ratio: 1.0,
+            _asset_listeners: asset_listeners,
+        })
+    }
+}
+
+impl TableRenderer for WebGlRenderer {
+    fn resize(&mut self, width: f64, height: f64, pixel_ratio: f64) {
+        self.pixel_ratio = pixel_ratio;
+        let (buffer_width, buffer_height) = ((width * pixel_ratio).round() as u32, (height * pixel_ratio).round() as u32);
+        for canvas in [&self.canvas, &self.overlay] {
+            canvas.set_width(buffer_width);
+            canvas.set_height(buffer_height);
+        }
+        self.gl.viewport(0, 0, buffer_width as i32, buffer_height as i32);
+    }
+
+    fn render(&mut self, scene: &Scene) {
+        let gl = &self.gl;
+        if self.atlas_dirty.replace(false) {
+            gl.bind_texture(Gl::TEXTURE_2D, Some(&self.texture));
+            if let Err(e) = gl.tex_image_2d_with_u32_and_u32_and_html_canvas_element(
+                Gl::TEXTURE_2D,
+                0,
+                Gl::RGBA as i32,
+                Gl::RGBA,
+                Gl::UNSIGNED_BYTE,
+                &self.atlas,
+            ) {
+                log::warn!("Atlas upload failed: {:?}", e);
+            }
+        }
+
+        let batch = SpriteBatch::from_scene(scene);
+        gl.clear_color(0.0, 0.0, 0.0, 0.0);
+        gl.clear(Gl::COLOR_BUFFER_BIT);
+        gl.use_program(Some(&self.program));
+        gl.uniform2f(self.resolution.as_ref(), scene.width as f32, scene.height as f32);
+        gl.bind_vertex_array(Some(&self.vertex_array));
+        gl.bind_buffer(Gl::ARRAY_BUFFER, Some(&self.vertex_buffer));
+        let vertices = js_sys::Float32Array::from(batch.vertices().as_slice());
+        gl.buffer_data_with_array_buffer_view(Gl::ARRAY_BUFFER, &vertices, Gl::DYNAMIC_DRAW);
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&self.texture));
+        gl.draw_arrays(Gl::TRIANGLES, 0, batch.vertex_count());
+        gl.bind_vertex_array(None);
+
+        let context = &self.overlay_context;
+        let _ = context.set_transform(self.pixel_ratio, 0.0, 0.0, self.pixel_ratio, 0.0, 0.0);
+        context.clear_rect(0.0, 0.0, scene.width, scene.height);
+        for label in &batch.labels {
+            draw_text(context, label);
+        }
+    }
+}
+
+impl Drop for WebGlRenderer {
+    fn drop(&mut self) {
+        self.overlay.remove();
+    }
+}
+
+/// Paints one frame into its atlas cell, from `artwork` when it has loaded.
+fn paint_frame(context: &CanvasRenderingContext2d, palette: &Palette, frame: Frame, artwork: Option<&HtmlImageElement>) {
+    let cell = frame.cell();
+    context.clear_rect(cell.x, cell.y, cell.width, cell.height);
+    let center = cell.center();
+    let inset = |by: f64| Rect { x: cell.x + by, y: cell.y + by, width: cell.width - by * 2.0, height: cell.height - by * 2.0 };
+
+    match (frame, artwork) {
+        (Frame::Felt, _) => {
+            // Sized so the rail touches the cell's edges, as the quad expects
+            let radius_y = cell.height / 2.0 / (1.0 + RAIL_RATIO);
+            draw_felt(context, palette, artwork, center, cell.width / 2.0 - radius_y * RAIL_RATIO, radius_y);
+        }
+        (Frame::CardFace(suit, rank), artwork) => draw_card_face(context, palette, artwork, inset(3.0), suit, rank),
+        (Frame::CardBack, artwork) => draw_card(context, palette, artwork, inset(3.0), &Card::Unknown),
+        (Frame::Highlight, _) => draw_highlight(context, palette, inset(4.0)),
+        (Frame::Plate(style), artwork) => {
+            let plate = inset(2.0);
+            draw_seat_plate(context, palette, plate, style);
+            if let (Some(grain), false) = (artwork, style == PlateStyle::Open) {
+                context.save();
+                rounded_rect(context, plate, plate.height * 0.27);
+                context.clip();
+                context.set_global_alpha(PLATE_GRAIN_ALPHA * if style == PlateStyle::Folded { 0.5 } else { 1.0 });
+                let _ = context.draw_image_with_html_image_element_and_dw_and_dh(grain, plate.x, plate.y, plate.width, plate.height);
+                context.restore();
+                // The border goes back over the grain
+                draw_seat_plate(context, palette, plate, style);
+            }
+        }
+        (Frame::Chip(denomination), Some(image)) => draw_chip_art(context, image, center, cell.width / 2.0 - 2.0, denomination),
+        (Frame::Chip(denomination), None) => draw_chip(context, center, cell.width / 2.0 - 2.0, denomination),
+        (Frame::DealerButton, Some(image)) => {
+            let _ = context.draw_image_with_html_image_element_and_dw_and_dh(image, cell.x, cell.y, cell.width, cell.height);
+        }
+        (Frame::DealerButton, None) => draw_dealer_button(context, palette, center, cell.width / 2.0 - 2.0),
+    }
+}
+
+fn load_artwork(
+    url: &str,
+    palette: &Palette,
+    frames: Vec<Frame>,
+    atlas_context: &CanvasRenderingContext2d,
+    atlas_dirty: &Rc<Cell<bool>>,
+    on_loaded: &Callback<()>,
+) -> Result<EventListener, JsValue> {
+    let image = HtmlImageElement::new()?;
+    let listener = EventListener::once(&image, "load", {
+        let image = image.clone();
+        let atlas_context = atlas_context.clone();
+        let atlas_dirty = atlas_dirty.clone();
+        let on_loaded = on_loaded.clone();
+        let palette = *palette;
+        move |_| {
+            for &frame in &frames {
+                paint_frame(&atlas_context, &palette, frame, Some(&image));
+            }
+            atlas_dirty.set(true);
+            on_loaded.emit(());
+        }
+    });
+    image.set_src(url);
+    Ok(listener)
+}
+
+fn compile_shader(gl: &Gl, kind: u32, source: &str) -> Result<WebGlShader, JsValue> {
+    let shader = gl.create_shader(kind).ok_or_else(|| JsValue::from_str("no shader"))?;
+    gl.shader_source(&shader, source);
+    gl.compile_shader(&shader);
+    if gl.get_shader_parameter(&shader, Gl::COMPILE_STATUS).as_bool().unwrap_or(false) {
+        Ok(shader)
+    } else {
+        Err(JsValue::from_str(&gl.get_shader_info_log(&shader).unwrap_or_default()))
+    }
+}
+
+fn link_program(gl: &Gl, vertex: &WebGlShader, fragment: &WebGlShader) -> Result<WebGlProgram, JsValue> {
+    let program = gl.create_program().ok_or_else(|| JsValue::from_str("no program"))?;
+    gl.attach_shader(&program, vertex);
+    gl.attach_shader(&program, fragment);
+    gl.link_program(&program);
+    if gl.get_program_parameter(&program, Gl::LINK_STATUS).as_bool().unwrap_or(false) {
+        Ok(program)
+    } else {
+        Err(JsValue::from_str(&gl.get_program_info_log(&program).unwrap_or_default()))
+    }
+}
@@ -9,14 +9,17 @@ pub mod app;
 pub mod components;
 pub mod services;
 pub mod game;
+pub mod rules;
 pub mod auth;
+pub mod lobby;
+pub mod quick_join;
 pub mod networking;
 pub mod graphics;
 pub mod utils;
 pub mod types;
 
 // Import the main app component
-use app::App;
+use app::Root;
 
 // This is the entry point for the web app
 #[wasm_bindgen(start)]
@@ -27,7 +30,7 @@ pub fn run_app() {
     log::info!("Starting PrimoPoker WebAssembly Client");
     
     // Mount the Yew application to the DOM
-    yew::Renderer::<App>::new().render();
+    yew::Renderer::<Root>::new().render();
 }
 
 // Export functions that can be called from JavaScript
@@ -45,5 +48,6 @@ macro_rules! console_log {
 
 // Re-export commonly used types
 pub use types::*;
-pub use game::GameState;
+pub use game::{GameState, TablePrompt, TableSet};
 pub use auth::AuthState;
+pub use lobby::{LobbyState, WaitlistEntry};
@@ -0,0 +1,512 @@
+// Lobby state management - live room list, tournament schedule, registrations and private room access
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Duration, Utc};
+use gloo_storage::{LocalStorage, SessionStorage, Storage};
+use uuid::Uuid;
+use yew::Callback;
+
+use crate::types::{
+    BlindLevel, ClientMessage, GameRoom, GameType, JoinRoomError, PayoutTier, PokerError, SavedRoom, ServerMessage,
+    TournamentConfig, TournamentFormat, TournamentInfo, TournamentStatus,
+};
+
+// Where earlier builds kept room passwords in plain text; cleared on startup
+const UNLOCKED_ROOMS_KEY: &str = "primo_poker_unlocked_rooms";
+// Local copy of favorites and join history; the server's copy wins for signed-in users
+const FAVORITE_ROOMS_KEY: &str = "primo_poker_favorite_rooms";
+const RECENT_ROOMS_KEY: &str = "primo_poker_recent_rooms";
+const RECENT_ROOMS_LIMIT: usize = 10;
+
+/// Our place in one table's waitlist.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WaitlistEntry {
+    pub position: Option<u32>,             // None until the server confirms the join
+    pub seat_offer: Option<DateTime<Utc>>, // a seat is held for us until this time
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LobbyState {
+    pub rooms: Vec<GameRoom>, // server order; deltas update in place so the list doesn't reshuffle
+    pub refreshing_rooms: bool, // GetRoomList sent, no full list back yet
+    pub tournaments: Vec<TournamentInfo>,
+    pub registration_error: Option<String>,
+    pending_registrations: HashSet<String>, // register/unregister sent, no reply yet
+    join_errors: HashMap<String, JoinRoomError>, // room id -> why our last join was refused
+    invite_codes: HashMap<String, String>,       // room id -> latest one-time invite code
+    pub creating_room: bool,                     // CreateRoom sent, no reply yet
+    pub room_creation_error: Option<String>,
+    pub created_room: Option<String>,            // id of the room our last CreateRoom opened
+    waitlists: HashMap<String, WaitlistEntry>,   // room id -> our place in that table's queue
+    pub favorite_rooms: Vec<SavedRoom>,          // newest first
+    pub recent_rooms: Vec<SavedRoom>,            // most recently joined first
+    history_owner: Option<Uuid>,                 // whose history is cached, None when signed out
+    unsynced_favorites: HashMap<String, bool>,   // stars toggled while the server couldn't hear us
+}
+
+/// Lets the lobby record a table the user chose to join.
+#[derive(Clone, PartialEq)]
+pub struct RoomHistoryContext {
+    pub record_join: Callback<String>,
+}
+
+impl LobbyState {
+    pub fn new() -> Self {
+        Self {
+            // Mock schedule for Phase 1 testing; rooms come only from the server's list
+            tournaments: mock_tournaments(),
+            ..Self::default()
+        }
+    }
+
+    pub fn tournament(&self, tournament_id: &str) -> Option<&TournamentInfo> {
+        self.tournaments.iter().find(|t| t.id == tournament_id)
+    }
+
+    pub fn room(&self, room_id: &str) -> Option<&GameRoom> {
+        self.rooms.iter().find(|room| room.id == room_id)
+    }
+
+    pub fn is_favorite(&self, room_id: &str) -> bool {
+        self.favorite_rooms.iter().any(|saved| saved.room_id == room_id)
+    }
+
+    pub fn recent_room_ids(&self) -> Vec<String> {
+        self.recent_rooms.iter().map(|saved| saved.room_id.clone()).collect()
+    }
+
+    /// Switches to a user's cached favorites and history, until the server sends its copy.
+    pub fn load_room_history(&mut self, user_id: Option<Uuid>) {
+        self.history_owner = user_id;
+        self.favorite_rooms = LocalStorage::get(user_storage_key(FAVORITE_ROOMS_KEY, user_id)).unwrap_or_default();
+        self.unsynced_favorites.clear();
+        let recent_key = user_storage_key(RECENT_ROOMS_KEY, user_id);
+        self.recent_rooms = match LocalStorage::get::<Vec<SavedRoom>>(&recent_key) {
+            Ok(recent) => recent,
+            // Earlier builds kept bare room ids under the same key
+            Err(_) => {
+                let ids: Vec<String> = LocalStorage::get(&recent_key).unwrap_or_default();
+                self.recent_rooms.clear();
+                let recent = ids.iter().map(|room_id| self.saved_room(room_id)).collect();
+                let _ = LocalStorage::set(&recent_key, &recent);
+                recent
+            }
+        };
+    }
+
+    /// Puts a table the user joined from the lobby at the top of their recent rooms.
+    pub fn record_join(&mut self, room_id: &str) {
+        let saved = self.saved_room(room_id);
+        self.recent_rooms.retain(|recent| recent.room_id != room_id);
+        self.recent_rooms.insert(0, saved);
+        self.recent_rooms.truncate(RECENT_ROOMS_LIMIT);
+        self.cache_room_history();
+    }
+
+    /// Favorite changes the server hasn't acknowledged, to resend once it's reachable.
+    pub fn unsynced_favorites(&self) -> Vec<ClientMessage> {
+        self.unsynced_favorites
+            .iter()
+            .map(|(room_id, favorite)| ClientMessage::SetFavoriteRoom { room_id: room_id.clone(), favorite: *favorite })
+            .collect()
+    }
+
+    pub fn is_pending(&self, tournament_id: &str) -> bool {
+        self.pending_registrations.contains(tournament_id)
+    }
+
+    pub fn join_error(&self, room_id: &str) -> Option<JoinRoomError> {
+        self.join_errors.get(room_id).copied()
+    }
+
+    pub fn invite_code(&self, room_id: &str) -> Option<&str> {
+        self.invite_codes.get(room_id).map(String::as_str)
+    }
+
+    pub fn waitlist(&self, room_id: &str) -> Option<&WaitlistEntry> {
+        self.waitlists.get(room_id)
+    }
+
+    /// Every table we're queued for, by room id.
+    pub fn waitlists(&self) -> impl Iterator<Item = (&str, &WaitlistEntry)> {
+        self.waitlists.iter().map(|(room_id, entry)| (room_id.as_str(), entry))
+    }
+
+    /// Tables holding a seat for us, soonest expiry first.
+    pub fn seat_offers(&self) -> Vec<(&str, DateTime<Utc>)> {
+        let mut offers: Vec<_> = self
+            .waitlists
+            .iter()
+            .filter_map(|(room_id, entry)| entry.seat_offer.map(|expires_at| (room_id.as_str(), expires_at)))
+            .collect();
+        offers.sort_by_key(|&(_, expires_at)| expires_at);
+        offers
+    }
+
+    /// Records outgoing lobby requests so the UI can show them as in flight.
+    pub fn request_sent(&mut self, message: &ClientMessage) -> bool {
+        match message {
+            ClientMessage::RegisterTournament { tournament_id }
+            | ClientMessage::UnregisterTournament { tournament_id } => {
+                self.pending_registrations.insert(tournament_id.clone());
+                self.registration_error = None;
+                true
+            }
+            ClientMessage::JoinRoom { room_id, .. } => self.join_errors.remove(room_id).is_some(),
+            ClientMessage::SetFavoriteRoom { room_id, favorite } => {
+                self.unsynced_favorites.remove(room_id);
+                self.set_favorite(room_id, *favorite);
+                true
+            }
+            ClientMessage::CreateInvite { room_id } => self.invite_codes.remove(room_id).is_some(),
+            ClientMessage::GetRoomList => {
+                self.refreshing_rooms = true;
+                true
+            }
+            ClientMessage::CreateRoom { .. } => {
+                self.creating_room = true;
+                self.room_creation_error = None;
+                self.created_room = None;
+                true
+            }
+            ClientMessage::JoinWaitlist { room_id } => {
+                self.waitlists.entry(room_id.clone()).or_default();
+                true
+            }
+            // Taking a seat also ends that waitlist; the game page sends the JoinRoom
+            ClientMessage::LeaveWaitlist { room_id }
+            | ClientMessage::AcceptSeat { room_id }
+            | ClientMessage::DeclineSeat { room_id } => self.waitlists.remove(room_id).is_some(),
+            _ => false,
+        }
+    }
+
+    pub fn room_list_timed_out(&mut self) -> bool {
+        std::mem::take(&mut self.refreshing_rooms)
+    }
+
+    /// Surfaces a lobby request that never reached the server.
+    pub fn request_failed(&mut self, message: &ClientMessage, error: &PokerError) -> bool {
+        match message {
+            // Starring still works offline; the change is sent when the server's history arrives
+            ClientMessage::SetFavoriteRoom { room_id, favorite } => {
+                self.unsynced_favorites.insert(room_id.clone(), *favorite);
+                self.set_favorite(room_id, *favorite);
+                true
+            }
+            ClientMessage::CreateRoom { .. } => {
+                self.creating_room = false;
+                self.room_creation_error = Some(format!("Couldn't reach the server: {}", error));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn apply(&mut self, message: ServerMessage) -> bool {
+        match message {
+            ServerMessage::RoomListUpdate { rooms } => {
+                self.rooms = rooms;
+                self.refreshing_rooms = false;
+                true
+            }
+            ServerMessage::RoomListDelta { added, updated, removed } => {
+                self.rooms.retain(|room| !removed.contains(&room.id));
+                for room in updated.into_iter().chain(added) {
+                    self.upsert_room(room);
+                }
+                true
+            }
+            ServerMessage::TournamentListUpdate { tournaments } => {
+                self.tournaments = tournaments;
+                true
+            }
+            ServerMessage::TournamentRegistered { tournament_id } => {
+                self.pending_registrations.remove(&tournament_id);
+                if let Some(tournament) = self.tournament_mut(&tournament_id) {
+                    if !tournament.is_registered {
+                        tournament.is_registered = true;
+                        tournament.registered_players += 1;
+                    }
+                }
+                true
+            }
+            ServerMessage::TournamentUnregistered { tournament_id } => {
+                self.pending_registrations.remove(&tournament_id);
+                if let Some(tournament) = self.tournament_mut(&tournament_id) {
+                    if tournament.is_registered {
+                        tournament.is_registered = false;
+                        tournament.registered_players = tournament.registered_players.saturating_sub(1);
+                    }
+                }
+                true
+            }
+            ServerMessage::TournamentRegistrationFailed { tournament_id, reason } => {
+                self.pending_registrations.remove(&tournament_id);
+                let name = self
+                    .tournament(&tournament_id)
+                    .map_or(tournament_id.clone(), |t| t.config.name.clone());
+                self.registration_error = Some(format!("{}: {}", name, reason));
+                true
+            }
+            ServerMessage::RoomJoinFailed { room_id, reason } => {
+                if reason == JoinRoomError::WrongPassword {
+                    forget_room_password(&room_id);
+                }
+                self.join_errors.insert(room_id, reason);
+                true
+            }
+            ServerMessage::InviteCreated { room_id, code } => {
+                self.invite_codes.insert(room_id, code);
+                true
+            }
+            ServerMessage::RoomCreated { room } => {
+                self.creating_room = false;
+                self.created_room = Some(room.id.clone());
+                self.upsert_room(room);
+                true
+            }
+            ServerMessage::RoomCreationFailed { reason } => {
+                self.creating_room = false;
+                self.room_creation_error = Some(reason);
+                true
+            }
+            ServerMessage::RoomHistory { favorites, recent } => {
+                self.merge_room_history(favorites, recent);
+                true
+            }
+            ServerMessage::WaitlistJoined { room_id, position }
+            | ServerMessage::WaitlistPosition { room_id, position } => {
+                self.waitlists.entry(room_id).or_default().position = Some(position);
+                true
+            }
+            ServerMessage::SeatOffered { room_id, expires_at } => {
+                self.waitlists.entry(room_id).or_default().seat_offer = Some(expires_at);
+                true
+            }
+            // An unanswered offer drops us from the queue, same as leaving
+            ServerMessage::WaitlistLeft { room_id } | ServerMessage::SeatOfferExpired { room_id } => {
+                self.waitlists.remove(&room_id).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    fn set_favorite(&mut self, room_id: &str, favorite: bool) {
+        self.favorite_rooms.retain(|saved| saved.room_id != room_id);
+        if favorite {
+            let saved = self.saved_room(room_id);
+            self.favorite_rooms.insert(0, saved);
+        }
+        self.cache_room_history();
+    }
+
+    /// Takes the server's history, keeping stars toggled here it hasn't heard about
+    /// and any local joins it's missing.
+    fn merge_room_history(&mut self, favorites: Vec<SavedRoom>, recent: Vec<SavedRoom>) {
+        let mut merged = favorites;
+        for (room_id, favorite) in &self.unsynced_favorites {
+            merged.retain(|saved| saved.room_id != *room_id);
+            if *favorite {
+                if let Some(local) = self.favorite_rooms.iter().find(|saved| saved.room_id == *room_id) {
+                    merged.push(local.clone());
+                }
+            }
+        }
+        merged.sort_by_key(|saved| std::cmp::Reverse(saved.saved_at));
+        self.favorite_rooms = merged;
+
+        let mut merged = recent;
+        for local in &self.recent_rooms {
+            match merged.iter_mut().find(|saved| saved.room_id == local.room_id) {
+                Some(saved) if saved.saved_at < local.saved_at => saved.saved_at = local.saved_at,
+                Some(_) => {}
+                None => merged.push(local.clone()),
+            }
+        }
+        merged.sort_by_key(|saved| std::cmp::Reverse(saved.saved_at));
+        merged.truncate(RECENT_ROOMS_LIMIT);
+        self.recent_rooms = merged;
+        self.cache_room_history();
+    }
+
+    /// A bookmark for the room as of now, named from the lobby list when it's there.
+    fn saved_room(&self, room_id: &str) -> SavedRoom {
+        let name = self
+            .room(room_id)
+            .map(|room| room.name.clone())
+            .or_else(|| {
+                self.favorite_rooms
+                    .iter()
+                    .chain(&self.recent_rooms)
+                    .find(|saved| saved.room_id == room_id)
+                    .map(|saved| saved.name.clone())
+            })
+            .unwrap_or_else(|| room_id.to_string());
+        SavedRoom { room_id: room_id.to_string(), name, saved_at: Utc::now() }
+    }
+
+    fn cache_room_history(&self) {
+        let _ = LocalStorage::set(user_storage_key(FAVORITE_ROOMS_KEY, self.history_owner), &self.favorite_rooms);
+        let _ = LocalStorage::set(user_storage_key(RECENT_ROOMS_KEY, self.history_owner), &self.recent_rooms);
+    }
+
+    /// Replaces a known room where it sits in the list, or appends a new one.
+    fn upsert_room(&mut self, room: GameRoom) {
+        match self.rooms.iter_mut().find(|existing| existing.id == room.id) {
+            Some(existing) => *existing = room,
+            None => self.rooms.push(room),
+        }
+    }
+
+    fn tournament_mut(&mut self, tournament_id: &str) -> Option<&mut TournamentInfo> {
+        self.tournaments.iter_mut().find(|t| t.id == tournament_id)
+    }
+}
+
+/// LocalStorage key for a per-user lobby preference; signed-out visitors share the bare key.
+pub fn user_storage_key(base: &str, user_id: Option<Uuid>) -> String {
+    match user_id {
+        Some(user_id) => format!("{}_{}", base, user_id),
+        None => base.to_string(),
+    }
+}
+
+thread_local! {
+    // Passwords for private rooms unlocked in this tab, so rejoining doesn't prompt again.
+    // Held in memory only; after a reload the room asks for its password again.
+    static UNLOCKED_ROOMS: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
+pub fn room_password(room_id: &str) -> Option<String> {
+    UNLOCKED_ROOMS.with_borrow(|rooms| rooms.get(room_id).cloned())
+}
+
+pub fn remember_room_password(room_id: &str, password: &str) {
+    UNLOCKED_ROOMS.with_borrow_mut(|rooms| rooms.insert(room_id.to_string(), password.to_string()));
+}
+
+pub fn forget_room_password(room_id: &str) {
+    UNLOCKED_ROOMS.with_borrow_mut(|rooms| rooms.remove(room_id));
+}
+
+/// Drops any room passwords an earlier build left in session storage.
+pub fn clear_stored_room_passwords() {
+    SessionStorage::delete(UNLOCKED_ROOMS_KEY);
+}
+
+fn blind_structure(starting_big_blind: i64, duration_minutes: u32, levels: u32) -> Vec<BlindLevel> {
+    let mut big_blind = starting_big_blind;
+    (1..=levels)
+        .map(|level| {
+            let blind_level = BlindLevel {
+                level,
+                small_blind: big_blind / 2,
+                big_blind,
+                ante: if level >= 4 { big_blind / 10 } else { 0 },
+                duration_minutes,
+            };
+            big_blind = big_blind * 3 / 2;
+            blind_level
+        })
+        .collect()
+}
+
+fn mock_tournaments() -> Vec<TournamentInfo> {
+    vec![
+        TournamentInfo {
+            id: "mtt_sunday_major".to_string(),
+            config: TournamentConfig {
+                name: "Sunday Major".to_string(),
+                format: TournamentFormat::MultiTable,
+                game_type: GameType::TexasHoldem,
+                buy_in: 100,
+                fee: 10,
+                starting_stack: 10000,
+                max_entrants: 5000,
+                blind_structure: blind_structure(100, 15, 20),
+                payouts: vec![
+                    PayoutTier { first_place: 1, last_place: 1, percentage: 27.0 },
+                    PayoutTier { first_place: 2, last_place: 2, percentage: 15.0 },
+                    PayoutTier { first_place: 3, last_place: 3, percentage: 10.0 },
+                    PayoutTier { first_place: 4, last_place: 9, percentage: 5.0 },
+                    PayoutTier { first_place: 10, last_place: 18, percentage: 2.0 },
+                ],
+                late_registration_levels: 8,
+                starts_at: Some(Utc::now() + Duration::hours(2)),
+            },
+            status: TournamentStatus::Registering,
+            registered_players: 1204,
+            current_level: 0,
+            is_registered: false,
+        },
+        TournamentInfo {
+            id: "mtt_nightly_deepstack".to_string(),
+            config: TournamentConfig {
+                name: "Nightly Deepstack".to_string(),
+                format: TournamentFormat::MultiTable,
+                game_type: GameType::TexasHoldem,
+                buy_in: 20,
+                fee: 2,
+                starting_stack: 20000,
+                max_entrants: 1000,
+                blind_structure: blind_structure(100, 12, 20),
+                payouts: vec![
+                    PayoutTier { first_place: 1, last_place: 1, percentage: 30.0 },
+                    PayoutTier { first_place: 2, last_place: 2, percentage: 20.0 },
+                    PayoutTier { first_place: 3, last_place: 3, percentage: 12.0 },
+                    PayoutTier { first_place: 4, last_place: 10, percentage: 5.0 },
+                    PayoutTier { first_place: 11, last_place: 11, percentage: 3.0 },
+                ],
+                late_registration_levels: 6,
+                starts_at: Some(Utc::now() - Duration::minutes(30)),
+            },
+            status: TournamentStatus::Running,
+            registered_players: 312,
+            current_level: 3,
+            is_registered: false,
+        },
+        TournamentInfo {
+            id: "sng_turbo_9max".to_string(),
+            config: TournamentConfig {
+                name: "Turbo Sit & Go".to_string(),
+                format: TournamentFormat::SitAndGo,
+                game_type: GameType::TexasHoldem,
+                buy_in: 10,
+                fee: 1,
+                starting_stack: 1500,
+                max_entrants: 9,
+                blind_structure: blind_structure(20, 5, 12),
+                payouts: vec![
+                    PayoutTier { first_place: 1, last_place: 1, percentage: 50.0 },
+                    PayoutTier { first_place: 2, last_place: 2, percentage: 30.0 },
+                    PayoutTier { first_place: 3, last_place: 3, percentage: 20.0 },
+                ],
+                late_registration_levels: 0,
+                starts_at: None,
+            },
+            status: TournamentStatus::Registering,
+            registered_players: 6,
+            current_level: 0,
+            is_registered: false,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_tournament_payouts_pay_out_the_whole_pool() {
+        for tournament in mock_tournaments() {
+            let total: f64 = tournament
+                .config
+                .payouts
+                .iter()
+                .map(|tier| tier.percentage * (tier.last_place - tier.first_place + 1) as f64)
+                .sum();
+            assert!((total - 100.0).abs() < 1e-9, "{} pays {}%", tournament.config.name, total);
+        }
+    }
+}
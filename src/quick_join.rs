@@ -0,0 +1,207 @@
+// QuickJoin table ranking - scores open tables against the user's stakes,
+// game and table-size preferences so the lobby can seat them in one click
+use crate::types::{GameRoom, QuickJoinSettings};
+
+const STAKES_WEIGHT: f64 = 20.0;        // per e-fold between the buy-in range and what we'd bring
+const GAME_TYPE_BONUS: f64 = 30.0;
+const FULLNESS_WEIGHT: f64 = 20.0;      // a table one seat from full earns the whole weight
+const EMPTY_TABLE_PENALTY: f64 = 40.0;
+const HEADS_UP_PENALTY: f64 = 25.0;
+const RECENT_ROOM_BONUS: f64 = 15.0;
+
+/// How well a table suits the user, or None if QuickJoin must never pick it.
+pub fn score_room(room: &GameRoom, chips: i64, settings: &QuickJoinSettings, recent_rooms: &[String]) -> Option<f64> {
+    let seat_open = room.current_players < room.max_players;
+    if !seat_open
+        || !room.is_active
+        || room.is_private
+        || room.min_buy_in > chips
+        || settings.blocked_rooms.contains(&room.id)
+    {
+        return None;
+    }
+
+    let mut score = 0.0;
+
+    // Stakes: nothing lost while our usual buy-in fits the table's range
+    let target = (chips as f64 * settings.buy_in_percent as f64 / 100.0).max(1.0);
+    let buy_in = target.clamp(room.min_buy_in.max(1) as f64, room.max_buy_in.max(1) as f64);
+    score -= (buy_in / target).ln().abs() * STAKES_WEIGHT;
+
+    if settings.game_type.as_ref() == Some(&room.game_type) {
+        score += GAME_TYPE_BONUS;
+    }
+
+    if room.max_players > 1 {
+        score += FULLNESS_WEIGHT * room.current_players as f64 / (room.max_players - 1) as f64;
+    }
+    if settings.avoid_short_tables {
+        score -= match room.current_players {
+            0 => EMPTY_TABLE_PENALTY,
+            1 => HEADS_UP_PENALTY,
+            _ => 0.0,
+        };
+    }
+
+    if settings.prefer_recent && recent_rooms.contains(&room.id) {
+        score += RECENT_ROOM_BONUS;
+    }
+
+    Some(score)
+}
+
+/// Tables QuickJoin may pick, best first; ties keep the order they were given in.
+pub fn rank_rooms<'a>(
+    rooms: &'a [GameRoom],
+    chips: i64,
+    settings: &QuickJoinSettings,
+    recent_rooms: &[String],
+) -> Vec<&'a GameRoom> {
+    let mut scored: Vec<_> = rooms
+        .iter()
+        .filter_map(|room| score_room(room, chips, settings, recent_rooms).map(|score| (room, score)))
+        .collect();
+    scored.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    scored.into_iter().map(|(room, _)| room).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::types::{BettingStructure, GameType};
+
+    fn room(id: &str, game_type: GameType, buy_in: (i64, i64), players: (u8, u8)) -> GameRoom {
+        GameRoom {
+            id: id.to_string(),
+            name: id.to_string(),
+            game_type,
+            betting_structure: BettingStructure::NoLimit,
+            small_blind: buy_in.1 / 200,
+            big_blind: buy_in.1 / 100,
+            min_buy_in: buy_in.0,
+            max_buy_in: buy_in.1,
+            current_players: players.0,
+            max_players: players.1,
+            is_private: false,
+            is_active: true,
+            tournament_id: None,
+            ante: 0,
+            bring_in: 0,
+            button_ante: false,
+            straddle_allowed: false,
+            run_it_twice: false,
+            owner_id: None,
+            avg_pot: 0,
+            hands_per_hour: 0,
+            waitlist: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn ids(rooms: Vec<&GameRoom>) -> Vec<&str> {
+        rooms.into_iter().map(|room| room.id.as_str()).collect()
+    }
+
+    #[test]
+    fn prefers_stakes_matching_the_bankroll() {
+        // 5% of 10,000 chips is a 500 buy-in
+        let rooms = vec![
+            room("micro", GameType::TexasHoldem, (20, 100), (4, 6)),
+            room("mid", GameType::TexasHoldem, (200, 1000), (4, 6)),
+            room("high", GameType::TexasHoldem, (2000, 9000), (4, 6)),
+        ];
+        let ranked = rank_rooms(&rooms, 10_000, &QuickJoinSettings::default(), &[]);
+        assert_eq!(ids(ranked), vec!["mid", "high", "micro"]);
+    }
+
+    #[test]
+    fn skips_tables_we_cannot_afford_or_sit_at() {
+        let mut private = room("private", GameType::TexasHoldem, (200, 1000), (3, 6));
+        private.is_private = true;
+        let mut closed = room("closed", GameType::TexasHoldem, (200, 1000), (3, 6));
+        closed.is_active = false;
+        let rooms = vec![
+            room("full", GameType::TexasHoldem, (200, 1000), (6, 6)),
+            room("pricey", GameType::TexasHoldem, (20_000, 100_000), (3, 6)),
+            private,
+            closed,
+            room("open", GameType::TexasHoldem, (200, 1000), (3, 6)),
+        ];
+        let ranked = rank_rooms(&rooms, 10_000, &QuickJoinSettings::default(), &[]);
+        assert_eq!(ids(ranked), vec!["open"]);
+    }
+
+    #[test]
+    fn blocked_rooms_are_never_picked() {
+        let rooms = vec![
+            room("blocked", GameType::TexasHoldem, (200, 1000), (5, 6)),
+            room("other", GameType::TexasHoldem, (20, 100), (2, 6)),
+        ];
+        let settings = QuickJoinSettings { blocked_rooms: vec!["blocked".to_string()], ..Default::default() };
+        let ranked = rank_rooms(&rooms, 10_000, &settings, &[]);
+        assert_eq!(ids(ranked), vec!["other"]);
+    }
+
+    #[test]
+    fn preferred_game_type_outranks_other_games() {
+        let rooms = vec![
+            room("holdem", GameType::TexasHoldem, (200, 1000), (4, 6)),
+            room("omaha", GameType::Omaha, (200, 1000), (4, 6)),
+        ];
+        let settings = QuickJoinSettings { game_type: Some(GameType::Omaha), ..Default::default() };
+        let ranked = rank_rooms(&rooms, 10_000, &settings, &[]);
+        assert_eq!(ids(ranked), vec!["omaha", "holdem"]);
+    }
+
+    #[test]
+    fn avoids_empty_and_heads_up_tables_when_asked() {
+        let rooms = vec![
+            room("empty", GameType::TexasHoldem, (200, 1000), (0, 6)),
+            room("heads_up", GameType::TexasHoldem, (200, 1000), (1, 6)),
+            room("lively", GameType::TexasHoldem, (200, 1000), (3, 6)),
+        ];
+        let ranked = rank_rooms(&rooms, 10_000, &QuickJoinSettings::default(), &[]);
+        assert_eq!(ids(ranked), vec!["lively", "heads_up", "empty"]);
+
+        // Still the last resort when nothing else is open
+        let ranked = rank_rooms(&rooms[..1], 10_000, &QuickJoinSettings::default(), &[]);
+        assert_eq!(ids(ranked), vec!["empty"]);
+    }
+
+    #[test]
+    fn fuller_tables_rank_higher_without_the_short_table_penalty() {
+        let rooms = vec![
+            room("quiet", GameType::TexasHoldem, (200, 1000), (2, 9)),
+            room("busy", GameType::TexasHoldem, (200, 1000), (7, 9)),
+        ];
+        let settings = QuickJoinSettings { avoid_short_tables: false, ..Default::default() };
+        let ranked = rank_rooms(&rooms, 10_000, &settings, &[]);
+        assert_eq!(ids(ranked), vec!["busy", "quiet"]);
+    }
+
+    #[test]
+    fn recently_played_rooms_break_close_calls() {
+        let rooms = vec![
+            room("new", GameType::TexasHoldem, (200, 1000), (4, 6)),
+            room("familiar", GameType::TexasHoldem, (200, 1000), (4, 6)),
+        ];
+        let recent = vec!["familiar".to_string()];
+        let ranked = rank_rooms(&rooms, 10_000, &QuickJoinSettings::default(), &recent);
+        assert_eq!(ids(ranked), vec!["familiar", "new"]);
+
+        let settings = QuickJoinSettings { prefer_recent: false, ..Default::default() };
+        let ranked = rank_rooms(&rooms, 10_000, &settings, &recent);
+        assert_eq!(ids(ranked), vec!["new", "familiar"]);
+    }
+
+    #[test]
+    fn ties_keep_the_lobby_order() {
+        let rooms = vec![
+            room("first", GameType::TexasHoldem, (200, 1000), (4, 6)),
+            room("second", GameType::TexasHoldem, (200, 1000), (4, 6)),
+        ];
+        let ranked = rank_rooms(&rooms, 10_000, &QuickJoinSettings::default(), &[]);
+        assert_eq!(ids(ranked), vec!["first", "second"]);
+    }
+}
@@ -0,0 +1,414 @@
+// Per-variant table rules, bet sizing per betting structure, and the
+// low-hand evaluator for hi/lo split games
+use std::fmt;
+
+use crate::types::{BettingStructure, Card, GamePhase, GameRoom, GameType, Rank};
+
+pub const FIXED_LIMIT_RAISE_CAP: u32 = 4; // a bet and three raises per street
+const STUD_COMPLETION_MULTIPLE: i64 = 2; // the small bet, as a multiple of the bring-in
+
+const HOLDEM_STREETS: &[GamePhase] = &[GamePhase::PreFlop, GamePhase::Flop, GamePhase::Turn, GamePhase::River];
+const STUD_STREETS: &[GamePhase] = &[
+    GamePhase::ThirdStreet,
+    GamePhase::FourthStreet,
+    GamePhase::FifthStreet,
+    GamePhase::SixthStreet,
+    GamePhase::SeventhStreet,
+];
+
+/// Everything the table UI needs to know about how a variant is dealt and settled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantRules {
+    pub name: &'static str,
+    pub hole_cards: usize,      // cards each player holds by the last street
+    pub community_cards: usize, // 0 for stud games
+    pub hole_cards_used: Option<usize>, // Omaha: exactly two hole cards must play
+    pub streets: &'static [GamePhase],
+    pub low_qualifier: Option<Rank>, // Some for hi/lo split games
+    pub uses_bring_in: bool,
+    pub uses_antes: bool,
+}
+
+static TEXAS_HOLDEM: VariantRules = VariantRules {
+    name: "Texas Hold'em",
+    hole_cards: 2,
+    community_cards: 5,
+    hole_cards_used: None,
+    streets: HOLDEM_STREETS,
+    low_qualifier: None,
+    uses_bring_in: false,
+    uses_antes: false,
+};
+
+static OMAHA: VariantRules = VariantRules {
+    name: "Omaha",
+    hole_cards: 4,
+    community_cards: 5,
+    hole_cards_used: Some(2),
+    streets: HOLDEM_STREETS,
+    low_qualifier: None,
+    uses_bring_in: false,
+    uses_antes: false,
+};
+
+static OMAHA_HI_LO: VariantRules = VariantRules {
+    name: "Omaha Hi-Lo",
+    hole_cards: 4,
+    community_cards: 5,
+    hole_cards_used: Some(2),
+    streets: HOLDEM_STREETS,
+    low_qualifier: Some(Rank::Eight),
+    uses_bring_in: false,
+    uses_antes: false,
+};
+
+static SEVEN_CARD_STUD: VariantRules = VariantRules {
+    name: "Seven Card Stud",
+    hole_cards: 7,
+    community_cards: 0,
+    hole_cards_used: None,
+    streets: STUD_STREETS,
+    low_qualifier: None,
+    uses_bring_in: true,
+    uses_antes: true,
+};
+
+impl GameType {
+    pub fn rules(&self) -> &'static VariantRules {
+        match self {
+            GameType::TexasHoldem => &TEXAS_HOLDEM,
+            GameType::Omaha => &OMAHA,
+            GameType::OmahaHiLo => &OMAHA_HI_LO,
+            GameType::SevenCardStud => &SEVEN_CARD_STUD,
+        }
+    }
+}
+
+impl VariantRules {
+    pub fn is_hi_lo(&self) -> bool {
+        self.low_qualifier.is_some()
+    }
+
+    pub fn is_stud(&self) -> bool {
+        self.community_cards == 0
+    }
+
+    /// Cards each player holds once the given street has been dealt.
+    pub fn cards_dealt_by(&self, phase: &GamePhase) -> usize {
+        match phase {
+            GamePhase::WaitingForPlayers => 0,
+            GamePhase::ThirdStreet => 3,
+            GamePhase::FourthStreet => 4,
+            GamePhase::FifthStreet => 5,
+            GamePhase::SixthStreet => 6,
+            _ => self.hole_cards,
+        }
+    }
+
+    /// Fixed-limit games double the bet size from the turn (Hold'em/Omaha) or fifth street (Stud).
+    pub fn is_big_bet_street(&self, phase: &GamePhase) -> bool {
+        self.streets
+            .iter()
+            .position(|street| street == phase)
+            .map_or(matches!(phase, GamePhase::Showdown), |index| index >= 2)
+    }
+
+    /// The small bet at a fixed-limit table, and the minimum bet otherwise.
+    /// Stud tables post no blinds, so their stakes come from the bring-in.
+    pub fn small_bet(&self, room: &GameRoom) -> i64 {
+        if self.uses_bring_in && room.big_blind == 0 {
+            room.bring_in * STUD_COMPLETION_MULTIPLE
+        } else {
+            room.big_blind
+        }
+    }
+
+    /// Whether the card at `index` in a player's hand is dealt face up.
+    /// Stud deals two down, four up (third through sixth street) and the river down.
+    pub fn card_face_up(&self, index: usize) -> bool {
+        self.is_stud() && (2..=5).contains(&index)
+    }
+}
+
+/// Table state needed to size a bet or raise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BettingContext {
+    pub pot: i64,          // everything in the middle, including this street's bets
+    pub current_bet: i64,  // highest total bet this street
+    pub player_bet: i64,   // what we've already put in this street
+    pub player_stack: i64, // chips behind
+    pub big_blind: i64,    // the small bet at fixed-limit tables
+    pub last_raise: i64,   // size of the last bet or raise this street
+    pub raises: u32,       // bets and raises made this street
+    pub big_bet_street: bool,
+}
+
+impl BettingContext {
+    pub fn to_call(&self) -> i64 {
+        (self.current_bet - self.player_bet).clamp(0, self.player_stack)
+    }
+
+    pub fn all_in_to(&self) -> i64 {
+        self.player_bet + self.player_stack
+    }
+
+    /// Total bet for a raise of `fraction` of the pot after calling.
+    pub fn pot_fraction_to(&self, fraction: f64) -> i64 {
+        let pot_after_call = self.pot + (self.current_bet - self.player_bet).max(0);
+        self.current_bet + (pot_after_call as f64 * fraction).round() as i64
+    }
+}
+
+/// Legal range for a bet or raise, expressed as the total put in this street.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BetLimits {
+    pub min_to: i64,
+    pub max_to: i64,
+    pub step: i64,
+    pub capped: bool, // only calling or folding is allowed
+}
+
+impl BetLimits {
+    pub fn clamp(&self, amount: i64) -> i64 {
+        amount.clamp(self.min_to, self.max_to)
+    }
+}
+
+impl BettingStructure {
+    pub fn bet_limits(&self, ctx: &BettingContext) -> BetLimits {
+        let all_in_to = ctx.all_in_to();
+        let min_raise_to = ctx.current_bet + ctx.last_raise.max(ctx.big_blind);
+
+        let (min_to, max_to, step, capped) = match self {
+            BettingStructure::NoLimit => (min_raise_to, all_in_to, ctx.big_blind, false),
+            BettingStructure::PotLimit => {
+                let pot_to = ctx.pot_fraction_to(1.0);
+                (min_raise_to, pot_to.min(all_in_to), ctx.big_blind, false)
+            }
+            BettingStructure::FixedLimit => {
+                let bet_size = if ctx.big_bet_street { ctx.big_blind * 2 } else { ctx.big_blind };
+                // Completing a bring-in makes it one full bet; only raises after that add a bet
+                let to = if ctx.current_bet < bet_size { bet_size } else { ctx.current_bet + bet_size };
+                (to, to, bet_size, ctx.raises >= FIXED_LIMIT_RAISE_CAP)
+            }
+        };
+
+        // A short stack may always go all-in for less than a full raise
+        let min_to = min_to.min(all_in_to);
+        BetLimits {
+            min_to,
+            max_to: max_to.clamp(min_to, all_in_to),
+            step: step.max(1),
+            capped: capped || all_in_to <= ctx.current_bet,
+        }
+    }
+}
+
+/// A qualifying low hand, ranks highest first with aces counted as 1.
+/// Lower compares as better, so the best low is the minimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LowHand {
+    pub ranks: [u8; 5],
+}
+
+impl fmt::Display for LowHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranks: Vec<String> = self
+            .ranks
+            .iter()
+            .map(|&rank| match rank {
+                1 => "A".to_string(),
+                rank => rank.to_string(),
+            })
+            .collect();
+        write!(f, "{}", ranks.join("-"))
+    }
+}
+
+fn low_value(card: &Card) -> Option<u8> {
+    match card {
+        Card::Card { rank: Rank::Ace, .. } => Some(1),
+        Card::Card { rank, .. } => Some(*rank as u8),
+        Card::Unknown => None,
+    }
+}
+
+/// Best low from any five of `cards` (stud-style: any cards may play).
+/// Needs five distinct ranks at or below the qualifier; straights and flushes don't count against a low.
+pub fn evaluate_low(cards: &[Card], qualifier: Rank) -> Option<LowHand> {
+    let qualifier = qualifier as u8;
+    let mut values: Vec<u8> = cards
+        .iter()
+        .filter_map(low_value)
+        .filter(|&value| value <= qualifier)
+        .collect();
+    values.sort_unstable();
+    values.dedup();
+
+    if values.len() < 5 {
+        return None;
+    }
+    let mut ranks = [0u8; 5];
+    for (slot, value) in ranks.iter_mut().zip(values[..5].iter().rev()) {
+        *slot = *value;
+    }
+    Some(LowHand { ranks })
+}
+
+/// Best Omaha low, using exactly two hole cards and three board cards.
+pub fn evaluate_omaha_low(hole: &[Card], board: &[Card], qualifier: Rank) -> Option<LowHand> {
+    let mut best: Option<LowHand> = None;
+    for (i, first) in hole.iter().enumerate() {
+        for second in &hole[i + 1..] {
+            for (j, a) in board.iter().enumerate() {
+                for (k, b) in board.iter().enumerate().skip(j + 1) {
+                    for c in &board[k + 1..] {
+                        let hand = [*first, *second, *a, *b, *c];
+                        if let Some(low) = evaluate_low(&hand, qualifier) {
+                            best = Some(best.map_or(low, |current| current.min(low)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Evaluates a player's low under the table's rules, if the variant plays for low.
+pub fn best_low(rules: &VariantRules, hole: &[Card], board: &[Card]) -> Option<LowHand> {
+    let qualifier = rules.low_qualifier?;
+    match rules.hole_cards_used {
+        Some(_) => evaluate_omaha_low(hole, board, qualifier),
+        None => {
+            let cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+            evaluate_low(&cards, qualifier)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Suit;
+
+    fn cards(spec: &str) -> Vec<Card> {
+        spec.split_whitespace()
+            .map(|card| {
+                let (rank, suit) = card.split_at(card.len() - 1);
+                let rank = match rank {
+                    "A" => Rank::Ace,
+                    "K" => Rank::King,
+                    "Q" => Rank::Queen,
+                    "J" => Rank::Jack,
+                    "T" => Rank::Ten,
+                    "9" => Rank::Nine,
+                    "8" => Rank::Eight,
+                    "7" => Rank::Seven,
+                    "6" => Rank::Six,
+                    "5" => Rank::Five,
+                    "4" => Rank::Four,
+                    "3" => Rank::Three,
+                    _ => Rank::Two,
+                };
+                let suit = match suit {
+                    "s" => Suit::Spades,
+                    "h" => Suit::Hearts,
+                    "d" => Suit::Diamonds,
+                    _ => Suit::Clubs,
+                };
+                Card::Card { suit, rank }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wheel_is_the_best_low() {
+        let low = evaluate_low(&cards("5s 4h 3d 2c As"), Rank::Eight).unwrap();
+        assert_eq!(low.ranks, [5, 4, 3, 2, 1]);
+        assert_eq!(low.to_string(), "5-4-3-2-A");
+        assert!(low < evaluate_low(&cards("6s 4h 3d 2c As"), Rank::Eight).unwrap());
+    }
+
+    #[test]
+    fn low_uses_the_best_five_and_ignores_pairs() {
+        let low = evaluate_low(&cards("As Ah 2d 3c 7s 8h Kd"), Rank::Eight).unwrap();
+        assert_eq!(low.to_string(), "8-7-3-2-A");
+        assert!(evaluate_low(&cards("8s 7h 6d 5c 4s"), Rank::Eight).unwrap() > low);
+    }
+
+    #[test]
+    fn low_needs_five_ranks_under_the_qualifier() {
+        assert_eq!(evaluate_low(&cards("9s 4h 3d 2c As"), Rank::Eight), None);
+        assert_eq!(evaluate_low(&cards("As Ah 2d 3c 4s Kd Qh"), Rank::Eight), None);
+        assert_eq!(evaluate_low(&[Card::Unknown; 7], Rank::Eight), None);
+    }
+
+    #[test]
+    fn omaha_low_plays_exactly_two_hole_cards_and_three_board_cards() {
+        // Four low hole cards can't make a low with only one low card on the board
+        assert_eq!(evaluate_omaha_low(&cards("As 2h 3d 4c"), &cards("5s Kh Qd Jc Ts"), Rank::Eight), None);
+        // Three low board cards plus the best two from the hand
+        let low = evaluate_omaha_low(&cards("As 2h Kd Kc"), &cards("3s 6h 7d Jc Ts"), Rank::Eight).unwrap();
+        assert_eq!(low.to_string(), "7-6-3-2-A");
+        // Two low hole cards duplicating board ranks don't count
+        assert_eq!(evaluate_omaha_low(&cards("3c 6c Kd Kc"), &cards("3s 6h 7d Jc Ts"), Rank::Eight), None);
+        // Picks the best pair of hole cards, not the first
+        let low = evaluate_omaha_low(&cards("8s 7h 2d Ac"), &cards("3s 4h 5d Kc Qs"), Rank::Eight).unwrap();
+        assert_eq!(low.to_string(), "5-4-3-2-A");
+    }
+
+    fn fixed_limit(current_bet: i64, raises: u32, big_bet_street: bool) -> BetLimits {
+        BettingStructure::FixedLimit.bet_limits(&BettingContext {
+            pot: 150,
+            current_bet,
+            player_bet: 0,
+            player_stack: 5000,
+            big_blind: 50,
+            last_raise: current_bet,
+            raises,
+            big_bet_street,
+        })
+    }
+
+    #[test]
+    fn fixed_limit_raises_by_the_street_bet_until_capped() {
+        // Pre-flop the big blind is the first of the four bets
+        let limits = fixed_limit(50, 1, false);
+        assert_eq!((limits.min_to, limits.max_to, limits.capped), (100, 100, false));
+        assert!(fixed_limit(200, FIXED_LIMIT_RAISE_CAP, false).capped);
+        assert_eq!(fixed_limit(0, 0, true).min_to, 100);
+    }
+
+    #[test]
+    fn completing_a_bring_in_makes_it_one_small_bet() {
+        // A 25 bring-in under a 50 small bet completes to 50, not 75
+        let limits = fixed_limit(25, 0, false);
+        assert_eq!((limits.min_to, limits.max_to, limits.capped), (50, 50, false));
+        assert_eq!(fixed_limit(50, 1, false).min_to, 100);
+    }
+
+    #[test]
+    fn stud_small_bet_comes_from_the_bring_in() {
+        let room = |game_type: &str, big_blind: i64, bring_in: i64| -> GameRoom {
+            serde_json::from_value(serde_json::json!({
+                "id": "t", "name": "t", "game_type": game_type, "current_players": 0, "max_players": 8,
+                "small_blind": big_blind / 2, "big_blind": big_blind, "min_buy_in": 0, "max_buy_in": 0,
+                "is_private": false, "is_active": true, "created_at": "2026-01-01T00:00:00Z",
+                "ante": 5, "bring_in": bring_in,
+            }))
+            .unwrap()
+        };
+        assert_eq!(GameType::SevenCardStud.rules().small_bet(&room("SevenCardStud", 0, 10)), 20);
+        assert_eq!(GameType::SevenCardStud.rules().small_bet(&room("SevenCardStud", 40, 10)), 40);
+        assert_eq!(GameType::TexasHoldem.rules().small_bet(&room("TexasHoldem", 50, 0)), 50);
+    }
+
+    #[test]
+    fn best_low_follows_the_variant() {
+        let hole = cards("As 2h Kd Kc");
+        let board = cards("3s 6h 7d Jc Ts");
+        assert_eq!(best_low(GameType::OmahaHiLo.rules(), &hole, &board).unwrap().to_string(), "7-6-3-2-A");
+        assert_eq!(best_low(GameType::Omaha.rules(), &hole, &board), None);
+    }
+}
@@ -0,0 +1,339 @@
+// Attention alerts - desktop notifications, a flashing tab title and a favicon badge
+// for events that need the player while the tab is in the background
+use gloo_events::EventListener;
+use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::callback::Interval;
+use gloo_utils::{document, window};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, Notification, NotificationOptions, NotificationPermission};
+use yew::html::Scope;
+use yew::Callback;
+
+use crate::app::{App, AppMsg};
+use crate::game::TableSet;
+use crate::lobby::LobbyState;
+use crate::types::ServerMessage;
+
+const PREFERENCES_KEY: &str = "primo_poker_alert_preferences";
+const TITLE_FLASH_MS: u32 = 1_000;
+const BADGE_FAVICON_ID: &str = "primo-alert-favicon";
+const NOTIFICATION_ICON: &str = "/assets/logos/primo-poker-icon.svg";
+
+/// Which events may pull the player back to the tab, and whether they may use desktop notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertPreferences {
+    pub desktop: bool, // opt-in; also needs the browser's permission
+    pub your_turn: bool,
+    pub invitations: bool,
+    pub seat_available: bool,
+}
+
+impl Default for AlertPreferences {
+    fn default() -> Self {
+        Self {
+            desktop: false,
+            your_turn: true,
+            invitations: true,
+            seat_available: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DesktopPermission {
+    Unsupported,
+    NotAsked,
+    Granted,
+    Denied,
+}
+
+impl DesktopPermission {
+    pub fn current() -> Self {
+        if js_sys::Reflect::get(&window(), &JsValue::from_str("Notification")).map_or(true, |api| api.is_undefined()) {
+            return DesktopPermission::Unsupported;
+        }
+        match Notification::permission() {
+            NotificationPermission::Granted => DesktopPermission::Granted,
+            NotificationPermission::Denied => DesktopPermission::Denied,
+            _ => DesktopPermission::NotAsked,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlertEvent {
+    YourTurn { room_id: String, table: String },
+    Invitation { room_id: String, room_name: String, from: String },
+    SeatAvailable { room_id: String, room_name: String },
+}
+
+impl AlertEvent {
+    /// The alert a server message calls for, if any; call before the message is applied.
+    pub fn from_server_message(message: &ServerMessage, tables: &TableSet, lobby: &LobbyState) -> Option<Self> {
+        match message {
+            ServerMessage::PlayerActionRequired { room_id, .. } => {
+                let table = tables
+                    .get(room_id)
+                    .and_then(|state| state.current_room.as_ref())
+                    .map_or_else(|| room_id.clone(), |room| room.name.clone());
+                Some(AlertEvent::YourTurn { room_id: room_id.clone(), table })
+            }
+            ServerMessage::GameInvitation { room_id, room_name, from_username, .. } => Some(AlertEvent::Invitation {
+                room_id: room_id.clone(),
+                room_name: room_name.clone(),
+                from: from_username.clone(),
+            }),
+            ServerMessage::SeatOffered { room_id, .. } => {
+                let room_name = lobby.room(room_id).map_or_else(|| room_id.clone(), |room| room.name.clone());
+                Some(AlertEvent::SeatAvailable { room_id: room_id.clone(), room_name })
+            }
+            _ => None,
+        }
+    }
+
+    fn enabled(&self, preferences: &AlertPreferences) -> bool {
+        match self {
+            AlertEvent::YourTurn { .. } => preferences.your_turn,
+            AlertEvent::Invitation { .. } => preferences.invitations,
+            AlertEvent::SeatAvailable { .. } => preferences.seat_available,
+        }
+    }
+
+    /// Desktop notifications with the same tag replace each other instead of piling up.
+    fn tag(&self) -> String {
+        match self {
+            AlertEvent::YourTurn { room_id, .. } => format!("turn_{}", room_id),
+            AlertEvent::Invitation { room_id, .. } => format!("invite_{}", room_id),
+            AlertEvent::SeatAvailable { room_id, .. } => format!("seat_{}", room_id),
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self {
+            AlertEvent::YourTurn { table, .. } => format!("Your turn at {}", table),
+            AlertEvent::Invitation { from, .. } => format!("{} invited you to play", from),
+            AlertEvent::SeatAvailable { room_name, .. } => format!("Seat open at {}", room_name),
+        }
+    }
+
+    fn body(&self) -> String {
+        match self {
+            AlertEvent::YourTurn { .. } => "Act before your timer runs out".to_string(),
+            AlertEvent::Invitation { room_name, .. } => format!("Join them at {}", room_name),
+            AlertEvent::SeatAvailable { .. } => "Take the seat before the offer expires".to_string(),
+        }
+    }
+}
+
+/// Provided by App for the alert settings panel.
+#[derive(Clone, PartialEq)]
+pub struct AlertContext {
+    pub preferences: AlertPreferences,
+    pub permission: DesktopPermission,
+    pub set_preferences: Callback<AlertPreferences>,
+    pub request_permission: Callback<()>,
+}
+
+// An open desktop notification and the listeners that live exactly as long as it does
+struct DesktopNotification {
+    notification: Notification,
+    tag: String,
+    _listeners: [EventListener; 2],
+}
+
+pub struct AlertService {
+    link: Scope<App>,
+    preferences: AlertPreferences,
+    permission: DesktopPermission,
+    pending: Vec<AlertEvent>, // raised since the tab lost focus, newest last
+    desktop_notifications: Vec<DesktopNotification>,
+    title_flash: Option<Interval>,
+    original_title: Option<String>,
+    _focus_listeners: [EventListener; 2],
+}
+
+impl AlertService {
+    pub fn new(link: Scope<App>) -> Self {
+        let on_focus = link.callback(|_| AppMsg::WindowFocused);
+        let on_visible = on_focus.clone();
+        let focus_listener = EventListener::new(&window(), "focus", move |_| on_focus.emit(()));
+        let visibility_listener = EventListener::new(&document(), "visibilitychange", move |_| {
+            if !document().hidden() {
+                on_visible.emit(());
+            }
+        });
+
+        Self {
+            link,
+            preferences: LocalStorage::get(PREFERENCES_KEY).unwrap_or_default(),
+            permission: DesktopPermission::current(),
+            pending: Vec::new(),
+            desktop_notifications: Vec::new(),
+            title_flash: None,
+            original_title: None,
+            _focus_listeners: [focus_listener, visibility_listener],
+        }
+    }
+
+    pub fn preferences(&self) -> AlertPreferences {
+        self.preferences
+    }
+
+    pub fn permission(&self) -> DesktopPermission {
+        self.permission
+    }
+
+    pub fn set_preferences(&mut self, preferences: AlertPreferences) -> bool {
+        let _ = LocalStorage::set(PREFERENCES_KEY, preferences);
+        self.preferences = preferences;
+        if preferences.desktop && self.permission == DesktopPermission::NotAsked {
+            self.request_permission();
+        }
+        true
+    }
+
+    /// Asks the browser for notification permission; the answer arrives as AppMsg::AlertPermissionChanged.
+    pub fn request_permission(&self) {
+        let Ok(promise) = Notification::request_permission() else {
+            return;
+        };
+        let link = self.link.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+            link.send_message(AppMsg::AlertPermissionChanged(DesktopPermission::current()));
+        });
+    }
+
+    pub fn permission_changed(&mut self, permission: DesktopPermission) -> bool {
+        std::mem::replace(&mut self.permission, permission) != permission
+    }
+
+    /// Raises an alert unless the player turned it off or is already looking at the tab.
+    pub fn alert(&mut self, event: AlertEvent) {
+        if !event.enabled(&self.preferences) || tab_focused() {
+            return;
+        }
+
+        if self.preferences.desktop && self.permission == DesktopPermission::Granted {
+            self.show_desktop_notification(&event);
+        }
+        self.pending.retain(|pending| pending.tag() != event.tag());
+        self.pending.push(event);
+        self.flash_title();
+        set_favicon_badge(self.pending.len());
+    }
+
+    /// The player is back: stop flashing and clear everything raised while they were away.
+    pub fn window_focused(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        self.pending.clear();
+        self.title_flash = None;
+        if let Some(title) = self.original_title.take() {
+            document().set_title(&title);
+        }
+        for desktop in self.desktop_notifications.drain(..) {
+            desktop.notification.close();
+        }
+        set_favicon_badge(0);
+    }
+
+    /// A notification the browser or the player closed; its listeners go with it.
+    pub fn desktop_notification_closed(&mut self, tag: &str) {
+        self.desktop_notifications.retain(|desktop| desktop.tag != tag);
+    }
+
+    fn show_desktop_notification(&mut self, event: &AlertEvent) {
+        let tag = event.tag();
+        let options = NotificationOptions::new();
+        options.set_body(&event.body());
+        options.set_tag(&tag);
+        options.set_icon(NOTIFICATION_ICON);
+        match Notification::new_with_options(&event.title(), &options) {
+            Ok(notification) => {
+                let on_click = EventListener::new(&notification, "click", |_| {
+                    let _ = window().focus();
+                });
+                let link = self.link.clone();
+                let closed_tag = tag.clone();
+                let on_close = EventListener::new(&notification, "close", move |_| {
+                    link.send_message(AppMsg::DesktopNotificationClosed(closed_tag.clone()));
+                });
+                // The same tag replaces the old notification in the browser, so drop ours too
+                self.desktop_notification_closed(&tag);
+                self.desktop_notifications.push(DesktopNotification {
+                    notification,
+                    tag,
+                    _listeners: [on_click, on_close],
+                });
+            }
+            Err(e) => log::warn!("Desktop notification failed: {:?}", e),
+        }
+    }
+
+    fn flash_title(&mut self) {
+        let original = self.original_title.get_or_insert_with(|| document().title()).clone();
+        let latest = self.pending.last().map(AlertEvent::title).unwrap_or_default();
+        let alert_title = match self.pending.len() {
+            1 => format!("🔔 {}", latest),
+            count => format!("🔔 ({}) {}", count, latest),
+        };
+
+        document().set_title(&alert_title);
+        let mut showing_alert = true;
+        self.title_flash = Some(Interval::new(TITLE_FLASH_MS, move || {
+            showing_alert = !showing_alert;
+            document().set_title(if showing_alert { &alert_title } else { &original });
+        }));
+    }
+}
+
+fn tab_focused() -> bool {
+    let document = document();
+    !document.hidden() && document.has_focus().unwrap_or(true)
+}
+
+/// Swaps in a generated favicon showing the alert count; 0 restores the page's own icons.
+fn set_favicon_badge(count: usize) {
+    let document = document();
+    if let Some(existing) = document.get_element_by_id(BADGE_FAVICON_ID) {
+        existing.remove();
+    }
+    if count == 0 {
+        return;
+    }
+    let Some(data_url) = badge_data_url(count) else {
+        return;
+    };
+    let (Ok(link), Some(head)) = (document.create_element("link"), document.query_selector("head").ok().flatten()) else {
+        return;
+    };
+    let _ = link.set_attribute("id", BADGE_FAVICON_ID);
+    let _ = link.set_attribute("rel", "icon");
+    let _ = link.set_attribute("href", &data_url);
+    // The last icon declared wins, so appending is enough to take over
+    let _ = head.append_child(&link);
+}
+
+fn badge_data_url(count: usize) -> Option<String> {
+    let canvas: HtmlCanvasElement = document().create_element("canvas").ok()?.dyn_into().ok()?;
+    canvas.set_width(64);
+    canvas.set_height(64);
+    let context: CanvasRenderingContext2d = canvas.get_context("2d").ok()??.dyn_into().ok()?;
+
+    context.begin_path();
+    let _ = context.arc(32.0, 32.0, 30.0, 0.0, std::f64::consts::TAU);
+    context.set_fill_style_str("#dc2626");
+    context.fill();
+    context.set_fill_style_str("#ffffff");
+    context.set_font("bold 38px sans-serif");
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+    let label = if count > 9 { "9+".to_string() } else { count.to_string() };
+    let _ = context.fill_text(&label, 32.0, 34.0);
+
+    canvas.to_data_url().ok()
+}
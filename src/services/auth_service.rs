@@ -4,11 +4,19 @@ use yew::Callback;
 use gloo_net::http::Request;
 use gloo_storage::{LocalStorage, Storage};
 use gloo_timers::future::TimeoutFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-use crate::types::{User, LoginCredentials, RegisterData, PokerError};
+use crate::types::{
+    User, LoginCredentials, RegisterData, PokerError, LoginOutcome, TwoFactorChallenge,
+    TwoFactorEnrollment,
+};
+use crate::utils::{totp, validation};
 use crate::app::AppMsg;
 
 const API_BASE_URL: &str = "https://api.primopoker.com"; // Replace with actual API URL
+const TWO_FACTOR_STORAGE_KEY: &str = "primo_poker_2fa";
+const CHALLENGE_TTL_SECS: i64 = 300; // time allowed between the password and the code
 
 #[derive(Clone)]
 pub struct AuthService {
@@ -31,7 +39,7 @@ impl AuthService {
     }
     
     
-    pub async fn login(&self, credentials: LoginCredentials) -> Result<User, PokerError> {
+    pub async fn login(&self, credentials: LoginCredentials) -> Result<LoginOutcome, PokerError> {
         // Mock authentication for Phase 1 testing
         // In production, this would make real API calls
         
@@ -45,12 +53,41 @@ impl AuthService {
             return Err(PokerError::AuthenticationError(error_msg));
         }
         
+        // Accounts with two-factor enabled must complete a second step before
+        // the session is established
+        if MockTwoFactorVerifier::is_enabled(&credentials.username) {
+            let challenge = MockTwoFactorVerifier::issue_challenge(&credentials.username);
+            return Ok(LoginOutcome::TwoFactorRequired { challenge });
+        }
+        
+        let user = self.complete_login(&credentials.username);
+        Ok(LoginOutcome::Authenticated { user })
+    }
+    
+    pub async fn verify_two_factor(
+        &self,
+        challenge: TwoFactorChallenge,
+        code: String,
+    ) -> Result<User, PokerError> {
+        // Simulate network delay
+        TimeoutFuture::new(300).await;
+        
+        if !MockTwoFactorVerifier::verify_challenge(&challenge, &code) {
+            let error_msg = "Invalid authentication code".to_string();
+            self.on_auth_error.emit(error_msg.clone());
+            return Err(PokerError::AuthenticationError(error_msg));
+        }
+        
+        Ok(self.complete_login(&challenge.username))
+    }
+    
+    fn complete_login(&self, username: &str) -> User {
         // For testing, accept any non-empty credentials
         let user = User {
             id: uuid::Uuid::new_v4(),
-            username: credentials.username.clone(),
-            email: format!("{}@primopoker.com", credentials.username),
-            display_name: credentials.username.clone(),
+            username: username.to_string(),
+            email: format!("{}@primopoker.com", username),
+            display_name: username.to_string(),
             avatar_url: None,
             chips: 10000, // Starting chips
             balance: 10000, // Starting balance
@@ -65,7 +102,7 @@ impl AuthService {
         let _ = LocalStorage::set("primo_poker_token", "mock_jwt_token_12345");
         
         self.on_auth_success.emit(user.clone());
-        Ok(user)
+        user
     }
     
     pub async fn register(&self, register_data: RegisterData) -> Result<User, PokerError> {
@@ -85,9 +122,8 @@ impl AuthService {
             return Err(PokerError::AuthenticationError(error_msg));
         }
         
-        // Mock email validation
-        if !register_data.email.contains('@') {
-            let error_msg = "Invalid email address".to_string();
+        if let Err(error) = validation::email_validator().validate(&register_data.email) {
+            let error_msg = error.message;
             self.on_auth_error.emit(error_msg.clone());
             return Err(PokerError::AuthenticationError(error_msg));
         }
@@ -115,6 +151,62 @@ impl AuthService {
         Ok(user)
     }
     
+    pub async fn check_username_available(&self, username: &str) -> Result<bool, PokerError> {
+        // Mock availability check for Phase 1 testing
+        // In production, this would query GET /auth/username-available
+        
+        // Simulate network delay
+        TimeoutFuture::new(250).await;
+        
+        const RESERVED_USERNAMES: [&str; 5] = ["admin", "dealer", "support", "moderator", "primopoker"];
+        let username = username.trim().to_lowercase();
+        let taken_locally = LocalStorage::get::<User>("primo_poker_user")
+            .map(|user| user.username.to_lowercase() == username)
+            .unwrap_or(false);
+        
+        Ok(!taken_locally && !RESERVED_USERNAMES.contains(&username.as_str()))
+    }
+    
+    pub fn is_two_factor_enabled(&self, username: &str) -> bool {
+        MockTwoFactorVerifier::is_enabled(username)
+    }
+    
+    pub async fn begin_two_factor_enrollment(&self, user: &User) -> Result<TwoFactorEnrollment, PokerError> {
+        // Simulate network delay
+        TimeoutFuture::new(300).await;
+        
+        if MockTwoFactorVerifier::is_enabled(&user.username) {
+            return Err(PokerError::AuthenticationError("Two-factor authentication is already enabled".to_string()));
+        }
+        
+        let secret = totp::generate_secret()?;
+        let enrollment = TwoFactorEnrollment {
+            otpauth_uri: totp::otpauth_uri(&user.username, &secret),
+            secret,
+            recovery_codes: totp::generate_recovery_codes(),
+        };
+        MockTwoFactorVerifier::store_pending(&user.username, &enrollment);
+        Ok(enrollment)
+    }
+    
+    pub async fn confirm_two_factor_enrollment(&self, user: &User, code: String) -> Result<(), PokerError> {
+        // Simulate network delay
+        TimeoutFuture::new(300).await;
+        
+        MockTwoFactorVerifier::activate(&user.username, &code)
+    }
+    
+    pub async fn disable_two_factor(&self, user: &User, code: String) -> Result<(), PokerError> {
+        // Simulate network delay
+        TimeoutFuture::new(300).await;
+        
+        if !MockTwoFactorVerifier::verify_login_code(&user.username, &code) {
+            return Err(PokerError::AuthenticationError("Invalid authentication code".to_string()));
+        }
+        MockTwoFactorVerifier::remove(&user.username);
+        Ok(())
+    }
+    
     pub fn get_stored_token(&self) -> Option<String> {
         LocalStorage::get("primo_poker_token").ok()
     }
@@ -165,3 +257,151 @@ impl AuthService {
         }
     }
 }
+
+// Local stand-in for the server's two-factor verifier so the flow can be
+// exercised offline. Secrets live in local storage keyed by username; the
+// real backend keeps them server-side and never returns them after enrollment.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct MockTwoFactorRecord {
+    secret: String,
+    recovery_codes: Vec<String>,
+    enabled: bool,
+    #[serde(default)]
+    last_used_step: Option<i64>, // a code's time-step is only accepted once
+    #[serde(default)]
+    challenge: Option<PendingChallenge>, // issued at password login, good for one verification
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PendingChallenge {
+    id: String,
+    issued_at: i64, // unix seconds
+}
+
+impl PendingChallenge {
+    fn is_live(&self, now: i64) -> bool {
+        now - self.issued_at < CHALLENGE_TTL_SECS
+    }
+}
+
+struct MockTwoFactorVerifier;
+
+impl MockTwoFactorVerifier {
+    fn load() -> HashMap<String, MockTwoFactorRecord> {
+        LocalStorage::get(TWO_FACTOR_STORAGE_KEY).unwrap_or_default()
+    }
+    
+    fn save(records: &HashMap<String, MockTwoFactorRecord>) {
+        let _ = LocalStorage::set(TWO_FACTOR_STORAGE_KEY, records);
+    }
+    
+    fn is_enabled(username: &str) -> bool {
+        Self::load().get(username).map(|r| r.enabled).unwrap_or(false)
+    }
+    
+    fn store_pending(username: &str, enrollment: &TwoFactorEnrollment) {
+        let mut records = Self::load();
+        records.insert(username.to_string(), MockTwoFactorRecord {
+            secret: enrollment.secret.clone(),
+            recovery_codes: enrollment.recovery_codes.clone(),
+            ..MockTwoFactorRecord::default()
+        });
+        Self::save(&records);
+    }
+    
+    fn activate(username: &str, code: &str) -> Result<(), PokerError> {
+        let mut records = Self::load();
+        let record = records.get_mut(username).ok_or_else(|| {
+            PokerError::AuthenticationError("No two-factor enrollment in progress".to_string())
+        })?;
+        
+        let Some(step) = totp::matching_step(&record.secret, code, chrono::Utc::now().timestamp()) else {
+            return Err(PokerError::AuthenticationError("Invalid authentication code".to_string()));
+        };
+        
+        record.enabled = true;
+        record.last_used_step = Some(step);
+        Self::save(&records);
+        Ok(())
+    }
+    
+    fn remove(username: &str) {
+        let mut records = Self::load();
+        records.remove(username);
+        Self::save(&records);
+    }
+    
+    fn issue_challenge(username: &str) -> TwoFactorChallenge {
+        let challenge = TwoFactorChallenge {
+            challenge_id: uuid::Uuid::new_v4().to_string(),
+            username: username.to_string(),
+        };
+        let now = chrono::Utc::now().timestamp();
+        let mut records = Self::load();
+        for record in records.values_mut() {
+            record.challenge = record.challenge.take().filter(|pending| pending.is_live(now));
+        }
+        // A new login replaces any challenge left over from an abandoned one
+        if let Some(record) = records.get_mut(username) {
+            record.challenge = Some(PendingChallenge { id: challenge.challenge_id.clone(), issued_at: now });
+        }
+        Self::save(&records);
+        challenge
+    }
+    
+    // The second login step only counts against the live challenge issued for that
+    // user; the challenge is spent once the code checks out, and dropped once expired
+    fn verify_challenge(challenge: &TwoFactorChallenge, code: &str) -> bool {
+        let mut records = Self::load();
+        let Some(record) = records.get_mut(&challenge.username) else {
+            return false;
+        };
+        let now = chrono::Utc::now().timestamp();
+        if record.challenge.as_ref().is_some_and(|pending| !pending.is_live(now)) {
+            record.challenge = None;
+            Self::save(&records);
+            return false;
+        }
+        let issued = record.challenge.as_ref().is_some_and(|pending| pending.id == challenge.challenge_id);
+        if !issued || !Self::verify_login_code(&challenge.username, code) {
+            return false;
+        }
+        let mut records = Self::load();
+        if let Some(record) = records.get_mut(&challenge.username) {
+            record.challenge = None;
+            Self::save(&records);
+        }
+        true
+    }
+    
+    // Accepts either a TOTP code from a time-step not used before or an unused
+    // recovery code; both are consumed on use
+    fn verify_login_code(username: &str, code: &str) -> bool {
+        let mut records = Self::load();
+        let Some(record) = records.get_mut(username).filter(|r| r.enabled) else {
+            return false;
+        };
+        
+        if let Some(step) = totp::matching_step(&record.secret, code, chrono::Utc::now().timestamp()) {
+            if record.last_used_step.is_some_and(|used| step <= used) {
+                return false; // replayed, or older than a code already accepted
+            }
+            record.last_used_step = Some(step);
+            Self::save(&records);
+            return true;
+        }
+        
+        let normalized = totp::normalize_code(code);
+        if let Some(index) = record
+            .recovery_codes
+            .iter()
+            .position(|c| totp::normalize_code(c) == normalized)
+        {
+            record.recovery_codes.remove(index);
+            Self::save(&records);
+            return true;
+        }
+        
+        false
+    }
+}
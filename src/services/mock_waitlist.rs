@@ -0,0 +1,95 @@
+// Mock waitlist server for Phase 1 testing - stands in for the backend's table queues
+// while the socket is down: positions tick forward until a seat is offered, then lapse
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use chrono::{DateTime, Duration, Utc};
+use gloo_timers::callback::Interval;
+use yew::html::Scope;
+
+use crate::app::{App, AppMsg};
+use crate::types::{ClientMessage, ServerMessage};
+
+const ADVANCE_INTERVAL_MS: u32 = 5_000; // one player ahead of us is seated per tick
+const SEAT_OFFER_SECONDS: i64 = 30;
+
+#[derive(Clone, Copy)]
+enum Stage {
+    Queued(u32),
+    Offered(DateTime<Utc>),
+    Lapsed,
+}
+
+pub struct MockWaitlist {
+    link: Scope<App>,
+    queues: HashMap<String, Interval>, // room id -> ticker advancing our position
+}
+
+impl MockWaitlist {
+    pub fn new(link: Scope<App>) -> Self {
+        Self {
+            link,
+            queues: HashMap::new(),
+        }
+    }
+
+    pub fn handles(message: &ClientMessage) -> bool {
+        matches!(
+            message,
+            ClientMessage::JoinWaitlist { .. }
+                | ClientMessage::LeaveWaitlist { .. }
+                | ClientMessage::AcceptSeat { .. }
+                | ClientMessage::DeclineSeat { .. }
+        )
+    }
+
+    /// Answers a waitlist request as the server would; `ahead` is the queue length we join behind.
+    pub fn handle(&mut self, message: &ClientMessage, ahead: u32) {
+        match message {
+            ClientMessage::JoinWaitlist { room_id } => {
+                let position = ahead + 1;
+                self.send(ServerMessage::WaitlistJoined { room_id: room_id.clone(), position });
+                let ticker = self.ticker(room_id.clone(), position);
+                self.queues.insert(room_id.clone(), ticker);
+            }
+            ClientMessage::LeaveWaitlist { room_id } => {
+                self.queues.remove(room_id);
+                self.send(ServerMessage::WaitlistLeft { room_id: room_id.clone() });
+            }
+            ClientMessage::AcceptSeat { room_id } | ClientMessage::DeclineSeat { room_id } => {
+                self.queues.remove(room_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn ticker(&self, room_id: String, position: u32) -> Interval {
+        let link = self.link.clone();
+        let stage = Rc::new(Cell::new(Stage::Queued(position)));
+        Interval::new(ADVANCE_INTERVAL_MS, move || {
+            let room_id = room_id.clone();
+            let message = match stage.get() {
+                Stage::Queued(position) if position > 1 => {
+                    stage.set(Stage::Queued(position - 1));
+                    ServerMessage::WaitlistPosition { room_id, position: position - 1 }
+                }
+                Stage::Queued(_) => {
+                    let expires_at = Utc::now() + Duration::seconds(SEAT_OFFER_SECONDS);
+                    stage.set(Stage::Offered(expires_at));
+                    ServerMessage::SeatOffered { room_id, expires_at }
+                }
+                // The ticker is dropped once the offer is answered, so reaching here means it lapsed
+                Stage::Offered(expires_at) if Utc::now() >= expires_at => {
+                    stage.set(Stage::Lapsed);
+                    ServerMessage::SeatOfferExpired { room_id }
+                }
+                Stage::Offered(_) | Stage::Lapsed => return,
+            };
+            link.send_message(AppMsg::LobbyUpdate(message));
+        })
+    }
+
+    fn send(&self, message: ServerMessage) {
+        self.link.send_message(AppMsg::LobbyUpdate(message));
+    }
+}
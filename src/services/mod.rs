@@ -1,5 +1,10 @@
 // Services module - handles external API interactions and business logic
 pub mod auth_service;
 pub mod websocket_service;
+pub mod tab_coordinator;
+#[cfg(debug_assertions)] // dev builds only; release builds always talk to the server
+pub mod mock_waitlist;
+pub mod notification_service;
+pub mod alert_service;
+pub mod sound_service;
 // pub mod api_service;
-// pub mod notification_service;
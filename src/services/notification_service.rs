@@ -0,0 +1,156 @@
+// Notification service - the app-wide queue behind ToastContainer: priorities,
+// deduplication, auto-dismiss timers and a history for the notification drawer
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use gloo_timers::callback::Timeout;
+use uuid::Uuid;
+use yew::html::Scope;
+use yew::Callback;
+
+use crate::app::{App, AppMsg};
+use crate::types::{NotificationMessage, NotificationPriority};
+
+const MAX_VISIBLE: usize = 4; // the rest wait their turn
+const HISTORY_LIMIT: usize = 50;
+
+/// Everything the UI renders: what's on screen, what's waiting, and what's been shown.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NotificationCenter {
+    active: Vec<NotificationMessage>,  // in arrival order
+    history: Vec<NotificationMessage>, // newest first, including active ones
+    pub unread: usize,                 // pushed since the drawer was last opened
+}
+
+impl NotificationCenter {
+    /// On-screen notifications, highest priority first and oldest first within a priority.
+    pub fn visible(&self) -> Vec<&NotificationMessage> {
+        let mut visible: Vec<_> = self.active.iter().collect();
+        visible.sort_by_key(|notification| Reverse(notification.priority));
+        visible.truncate(MAX_VISIBLE);
+        visible
+    }
+
+    pub fn queued(&self) -> usize {
+        self.active.len().saturating_sub(MAX_VISIBLE)
+    }
+
+    pub fn history(&self) -> &[NotificationMessage] {
+        &self.history
+    }
+
+    /// Adds a notification, or folds it into the matching one already showing.
+    fn push(&mut self, notification: NotificationMessage) {
+        if let Some(existing) = self.active.iter_mut().find(|existing| notification.is_repeat_of(existing)) {
+            let repeat_count = existing.repeat_count + 1;
+            let id = existing.id;
+            *existing = NotificationMessage { id, repeat_count, ..notification };
+            if let Some(entry) = self.history.iter_mut().find(|entry| entry.id == id) {
+                *entry = existing.clone();
+            }
+            return;
+        }
+
+        self.unread += 1;
+        self.history.insert(0, notification.clone());
+        self.history.truncate(HISTORY_LIMIT);
+        self.active.push(notification);
+    }
+
+    fn dismiss(&mut self, id: Uuid) -> bool {
+        let before = self.active.len();
+        self.active.retain(|notification| notification.id != id);
+        self.active.len() != before
+    }
+
+    fn dismiss_key(&mut self, key: &str) -> bool {
+        let before = self.active.len();
+        self.active.retain(|notification| notification.dedupe_key.as_deref() != Some(key));
+        self.active.len() != before
+    }
+}
+
+/// Provided by App so any component can raise or dismiss notifications.
+#[derive(Clone, PartialEq)]
+pub struct NotificationContext {
+    pub center: NotificationCenter,
+    pub notify: Callback<NotificationMessage>,
+    pub dismiss: Callback<Uuid>,
+    pub mark_read: Callback<()>,
+    pub clear_history: Callback<()>,
+}
+
+pub struct NotificationService {
+    link: Scope<App>,
+    center: NotificationCenter,
+    timers: HashMap<Uuid, Timeout>, // auto-dismiss, running only while a notification is on screen
+}
+
+impl NotificationService {
+    pub fn new(link: Scope<App>) -> Self {
+        Self {
+            link,
+            center: NotificationCenter::default(),
+            timers: HashMap::new(),
+        }
+    }
+
+    pub fn center(&self) -> &NotificationCenter {
+        &self.center
+    }
+
+    pub fn notify(&mut self, notification: NotificationMessage) -> bool {
+        // A repeat restarts the clock on the notification it folds into
+        if let Some(existing) = self.center.active.iter().find(|existing| notification.is_repeat_of(existing)) {
+            self.timers.remove(&existing.id);
+        }
+        self.center.push(notification);
+        self.sync_timers();
+        true
+    }
+
+    pub fn dismiss(&mut self, id: Uuid) -> bool {
+        let dismissed = self.center.dismiss(id);
+        self.sync_timers();
+        dismissed
+    }
+
+    /// Dismisses whatever is showing under a dedupe key, e.g. once a problem clears up.
+    pub fn dismiss_key(&mut self, key: &str) -> bool {
+        let dismissed = self.center.dismiss_key(key);
+        self.sync_timers();
+        dismissed
+    }
+
+    pub fn mark_read(&mut self) -> bool {
+        std::mem::take(&mut self.center.unread) > 0
+    }
+
+    pub fn clear_history(&mut self) -> bool {
+        let active = &self.center.active;
+        self.center.history.retain(|entry| active.iter().any(|notification| notification.id == entry.id));
+        true
+    }
+
+    fn sync_timers(&mut self) {
+        let visible: Vec<(Uuid, Option<f32>)> = self
+            .center
+            .visible()
+            .into_iter()
+            .filter(|notification| notification.priority < NotificationPriority::Critical)
+            .map(|notification| (notification.id, notification.duration))
+            .collect();
+
+        self.timers.retain(|id, _| visible.iter().any(|(visible_id, _)| visible_id == id));
+        for (id, duration) in visible {
+            let Some(seconds) = duration else { continue };
+            if self.timers.contains_key(&id) {
+                continue;
+            }
+            let link = self.link.clone();
+            let timeout = Timeout::new((seconds * 1000.0) as u32, move || {
+                link.send_message(AppMsg::DismissNotification(id));
+            });
+            self.timers.insert(id, timeout);
+        }
+    }
+}
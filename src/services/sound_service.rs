@@ -0,0 +1,416 @@
+// Sound engine - table sounds synthesized once into AudioBuffers and played through
+// per-category gain nodes, so volume changes apply to sounds already playing
+use std::collections::HashMap;
+use gloo_events::EventListener;
+use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use web_sys::{AudioBuffer, AudioContext, AudioContextState, GainNode};
+use yew::html::Scope;
+use yew::Callback;
+
+use crate::app::{App, AppMsg};
+use crate::game::TableSet;
+use crate::types::{GamePhase, PlayerAction, ServerMessage};
+
+const SETTINGS_KEY: &str = "primo_poker_sound_settings";
+const TIMER_WARNING_SECS: i32 = 5; // before the action clock runs out
+const REPEAT_GAP_SECS: f64 = 0.06; // the same sound twice inside this plays once
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundCategory {
+    Cards,
+    Chips,
+    Actions,
+    Alerts,
+}
+
+impl SoundCategory {
+    pub const ALL: [SoundCategory; 4] = [SoundCategory::Cards, SoundCategory::Chips, SoundCategory::Actions, SoundCategory::Alerts];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SoundCategory::Cards => "Cards",
+            SoundCategory::Chips => "Chips",
+            SoundCategory::Actions => "Checks & folds",
+            SoundCategory::Alerts => "Turn alerts",
+        }
+    }
+
+    /// The sound the settings panel plays when this category's slider moves.
+    pub fn sample(self) -> Sound {
+        match self {
+            SoundCategory::Cards => Sound::Deal,
+            SoundCategory::Chips => Sound::Chips,
+            SoundCategory::Actions => Sound::Check,
+            SoundCategory::Alerts => Sound::YourTurn,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sound {
+    Deal,
+    Check,
+    Chips,
+    Fold,
+    AllIn,
+    Win,
+    YourTurn,
+    TimerWarning,
+}
+
+impl Sound {
+    pub const ALL: [Sound; 8] = [
+        Sound::Deal,
+        Sound::Check,
+        Sound::Chips,
+        Sound::Fold,
+        Sound::AllIn,
+        Sound::Win,
+        Sound::YourTurn,
+        Sound::TimerWarning,
+    ];
+
+    pub fn category(self) -> SoundCategory {
+        match self {
+            Sound::Deal => SoundCategory::Cards,
+            Sound::Chips | Sound::AllIn | Sound::Win => SoundCategory::Chips,
+            Sound::Check | Sound::Fold => SoundCategory::Actions,
+            Sound::YourTurn | Sound::TimerWarning => SoundCategory::Alerts,
+        }
+    }
+
+    /// Sounds a server message calls for; call before the message is applied so
+    /// the table still shows what it looked like beforehand.
+    ///
+    /// Background tables only make noise when they need us - a player sitting four
+    /// tables doesn't want to hear every check at all of them.
+    pub fn from_server_message(message: &ServerMessage, tables: &TableSet) -> Vec<Sound> {
+        let in_focus = |room_id: &str| tables.len() <= 1 || tables.focused() == Some(room_id);
+        match message {
+            ServerMessage::PlayerActionRequired { .. } => vec![Sound::YourTurn],
+            ServerMessage::GameStateUpdate { room_id, phase, community_cards, .. } if in_focus(room_id) => {
+                let Some(state) = tables.get(room_id) else {
+                    return Vec::new();
+                };
+                let new_street = state.game_phase != *phase
+                    && !matches!(phase, GamePhase::WaitingForPlayers | GamePhase::Showdown);
+                if new_street || community_cards.len() > state.community_cards.len() {
+                    vec![Sound::Deal]
+                } else {
+                    Vec::new()
+                }
+            }
+            ServerMessage::PlayerActionUpdate { room_id, action } if in_focus(room_id) => vec![match action.action {
+                PlayerAction::Fold => Sound::Fold,
+                PlayerAction::Check => Sound::Check,
+                PlayerAction::AllIn => Sound::AllIn,
+                PlayerAction::Call | PlayerAction::Bet(_) | PlayerAction::Raise(_) | PlayerAction::BringIn => Sound::Chips,
+            }],
+            ServerMessage::StraddlePosted { room_id, .. } if in_focus(room_id) => vec![Sound::Chips],
+            ServerMessage::HandResult { room_id, winners, .. } => {
+                let won = tables.hero_id().is_some_and(|hero| winners.iter().any(|winner| winner.player_id == hero));
+                if won {
+                    vec![Sound::Win]
+                } else if in_focus(room_id) {
+                    vec![Sound::Chips] // the pot being pushed
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The tones and noise bursts this sound is synthesized from, each placed by its start time.
+    fn voices(self) -> Vec<Voice> {
+        let click = |at| Voice { at, pitch: Some(2600.0), length: 0.025 };
+        match self {
+            Sound::Deal => vec![Voice { at: 0.0, pitch: None, length: 0.06 }],
+            Sound::Check => vec![
+                Voice { at: 0.0, pitch: Some(140.0), length: 0.05 },
+                Voice { at: 0.12, pitch: Some(140.0), length: 0.05 },
+            ],
+            Sound::Chips => vec![click(0.0), click(0.05), click(0.09)],
+            Sound::Fold => vec![Voice { at: 0.0, pitch: None, length: 0.18 }],
+            Sound::AllIn => (0..8).map(|i| click(i as f32 * 0.035)).collect(),
+            Sound::Win => [523.25, 659.25, 783.99, 1046.5]
+                .into_iter()
+                .enumerate()
+                .map(|(i, pitch)| Voice { at: i as f32 * 0.09, pitch: Some(pitch), length: 0.25 })
+                .collect(),
+            Sound::YourTurn => vec![
+                Voice { at: 0.0, pitch: Some(880.0), length: 0.2 },
+                Voice { at: 0.15, pitch: Some(1320.0), length: 0.3 },
+            ],
+            Sound::TimerWarning => (0..3)
+                .map(|i| Voice { at: i as f32 * 0.15, pitch: Some(1000.0), length: 0.08 })
+                .collect(),
+        }
+    }
+}
+
+/// One decaying tone (or burst of noise when there's no pitch) in a synthesized sound.
+struct Voice {
+    at: f32,
+    pitch: Option<f32>,
+    length: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundSettings {
+    pub muted: bool,
+    pub master: f32, // 0.0 - 1.0, like the per-category volumes
+    pub cards: f32,
+    pub chips: f32,
+    pub actions: f32,
+    pub alerts: f32,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        Self {
+            muted: false,
+            master: 0.8,
+            cards: 1.0,
+            chips: 1.0,
+            actions: 1.0,
+            alerts: 1.0,
+        }
+    }
+}
+
+impl SoundSettings {
+    pub fn volume(&self, category: SoundCategory) -> f32 {
+        match category {
+            SoundCategory::Cards => self.cards,
+            SoundCategory::Chips => self.chips,
+            SoundCategory::Actions => self.actions,
+            SoundCategory::Alerts => self.alerts,
+        }
+    }
+
+    pub fn set_volume(&mut self, category: SoundCategory, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+        match category {
+            SoundCategory::Cards => self.cards = volume,
+            SoundCategory::Chips => self.chips = volume,
+            SoundCategory::Actions => self.actions = volume,
+            SoundCategory::Alerts => self.alerts = volume,
+        }
+    }
+}
+
+/// Provided by App for the sound settings panel.
+#[derive(Clone, PartialEq)]
+pub struct SoundContext {
+    pub settings: SoundSettings,
+    pub available: bool, // false when the browser has no Web Audio
+    pub set_settings: Callback<SoundSettings>,
+    pub preview: Callback<Sound>,
+}
+
+/// The Web Audio graph: each category's gain feeds the master gain.
+struct Mixer {
+    context: AudioContext,
+    master: GainNode,
+    categories: HashMap<SoundCategory, GainNode>,
+}
+
+impl Mixer {
+    fn new() -> Result<Self, JsValue> {
+        let context = AudioContext::new()?;
+        let master = context.create_gain()?;
+        master.connect_with_audio_node(&context.destination())?;
+        let mut categories = HashMap::new();
+        for category in SoundCategory::ALL {
+            let gain = context.create_gain()?;
+            gain.connect_with_audio_node(&master)?;
+            categories.insert(category, gain);
+        }
+        Ok(Self { context, master, categories })
+    }
+
+    fn apply(&self, settings: &SoundSettings) {
+        let master = if settings.muted { 0.0 } else { settings.master };
+        self.master.gain().set_value(master);
+        for (category, gain) in &self.categories {
+            gain.gain().set_value(settings.volume(*category));
+        }
+    }
+}
+
+pub struct SoundService {
+    link: Scope<App>,
+    settings: SoundSettings,
+    mixer: Option<Mixer>,
+    buffers: HashMap<Sound, AudioBuffer>,
+    last_played: HashMap<Sound, f64>,    // AudioContext time
+    timer_warnings: HashMap<String, Timeout>, // per table, while the server waits on us
+    _unlock_listeners: Vec<EventListener>,
+}
+
+impl SoundService {
+    pub fn new(link: Scope<App>) -> Self {
+        let settings: SoundSettings = LocalStorage::get(SETTINGS_KEY).unwrap_or_default();
+        let mixer = match Mixer::new() {
+            Ok(mixer) => Some(mixer),
+            Err(e) => {
+                log::warn!("Sound disabled, no Web Audio: {:?}", e);
+                None
+            }
+        };
+
+        let mut unlock_listeners = Vec::new();
+        let mut buffers = HashMap::new();
+        if let Some(ref mixer) = mixer {
+            mixer.apply(&settings);
+            // Browsers keep audio suspended until the page is interacted with
+            for event in ["pointerdown", "keydown"] {
+                let context = mixer.context.clone();
+                unlock_listeners.push(EventListener::once(&document(), event, move |_| resume(&context)));
+            }
+            // No audio files ship with the client, so every sound is generated up front
+            for sound in Sound::ALL {
+                match synthesize(&mixer.context, sound) {
+                    Ok(buffer) => {
+                        buffers.insert(sound, buffer);
+                    }
+                    Err(e) => log::warn!("Failed to synthesize {:?}: {:?}", sound, e),
+                }
+            }
+        }
+
+        Self {
+            link,
+            settings,
+            mixer,
+            buffers,
+            last_played: HashMap::new(),
+            timer_warnings: HashMap::new(),
+            _unlock_listeners: unlock_listeners,
+        }
+    }
+
+    pub fn settings(&self) -> SoundSettings {
+        self.settings
+    }
+
+    pub fn available(&self) -> bool {
+        self.mixer.is_some()
+    }
+
+    pub fn set_settings(&mut self, settings: SoundSettings) -> bool {
+        let _ = LocalStorage::set(SETTINGS_KEY, settings);
+        self.settings = settings;
+        if let Some(ref mixer) = self.mixer {
+            mixer.apply(&settings);
+        }
+        true
+    }
+
+    /// Plays whatever a server message calls for (when `audible`) and keeps the
+    /// action clock warnings in step.
+    pub fn server_message(&mut self, message: &ServerMessage, tables: &TableSet, audible: bool) {
+        match message {
+            ServerMessage::PlayerActionRequired { room_id, time_limit } => {
+                self.timer_warnings.remove(room_id);
+                if *time_limit > TIMER_WARNING_SECS {
+                    let link = self.link.clone();
+                    let room_id = room_id.clone();
+                    let delay = ((*time_limit - TIMER_WARNING_SECS) * 1000) as u32;
+                    let timeout = Timeout::new(delay, {
+                        let room_id = room_id.clone();
+                        move || link.send_message(AppMsg::TimerWarning(room_id))
+                    });
+                    self.timer_warnings.insert(room_id, timeout);
+                }
+            }
+            ServerMessage::HandResult { room_id, .. } | ServerMessage::RoomLeft { room_id } => {
+                self.timer_warnings.remove(room_id);
+            }
+            _ => {}
+        }
+        if !audible {
+            return;
+        }
+        for sound in Sound::from_server_message(message, tables) {
+            self.play(sound);
+        }
+    }
+
+    /// The action clock at a table is nearly out; warn only if we still haven't acted.
+    pub fn timer_warning(&mut self, room_id: &str, tables: &TableSet) {
+        self.timer_warnings.remove(room_id);
+        if tables.get(room_id).is_some_and(|state| state.action_required) {
+            self.play(Sound::TimerWarning);
+        }
+    }
+
+    pub fn play(&mut self, sound: Sound) {
+        let Some(ref mixer) = self.mixer else {
+            return;
+        };
+        if self.settings.muted || self.settings.master <= 0.0 || self.settings.volume(sound.category()) <= 0.0 {
+            return;
+        }
+        let Some(buffer) = self.buffers.get(&sound) else {
+            return;
+        };
+
+        let now = mixer.context.current_time();
+        if self.last_played.get(&sound).is_some_and(|&last| now - last < REPEAT_GAP_SECS) {
+            return;
+        }
+        self.last_played.insert(sound, now);
+
+        resume(&mixer.context);
+        let played = mixer.context.create_buffer_source().and_then(|source| {
+            source.set_buffer(Some(buffer));
+            source.connect_with_audio_node(&mixer.categories[&sound.category()])?;
+            source.start()
+        });
+        if let Err(e) = played {
+            log::warn!("Failed to play {:?}: {:?}", sound, e);
+        }
+    }
+}
+
+fn resume(context: &AudioContext) {
+    if context.state() == AudioContextState::Suspended {
+        let _ = context.resume();
+    }
+}
+
+fn synthesize(context: &AudioContext, sound: Sound) -> Result<AudioBuffer, JsValue> {
+    let sample_rate = context.sample_rate();
+    let voices = sound.voices();
+    let length = voices.iter().map(|voice| voice.at + voice.length).fold(0.0, f32::max);
+    let mut samples = vec![0.0f32; (length * sample_rate).ceil() as usize];
+
+    let mut noise_seed: u32 = 0x2545_f491;
+    for voice in voices {
+        let start = (voice.at * sample_rate) as usize;
+        let count = (voice.length * sample_rate) as usize;
+        for (i, sample) in samples.iter_mut().skip(start).take(count).enumerate() {
+            let t = i as f32 / sample_rate;
+            let envelope = (-6.0 * t / voice.length).exp();
+            let wave = match voice.pitch {
+                Some(pitch) => (std::f32::consts::TAU * pitch * t).sin(),
+                None => {
+                    noise_seed = noise_seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                    (noise_seed >> 8) as f32 / (1u32 << 23) as f32 - 1.0
+                }
+            };
+            *sample += 0.4 * envelope * wave;
+        }
+    }
+
+    let buffer = context.create_buffer(1, samples.len() as u32, sample_rate)?;
+    buffer.copy_to_channel(&samples, 0)?;
+    Ok(buffer)
+}
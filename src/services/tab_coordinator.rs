@@ -0,0 +1,299 @@
+// Tab coordinator - elects a single browser tab to own the WebSocket and
+// relays traffic for every other tab of the same session over a BroadcastChannel
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use gloo_events::EventListener;
+use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::callback::{Interval, Timeout};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{BroadcastChannel, MessageEvent};
+use yew::Callback;
+
+use crate::types::{ClientMessage, PokerError};
+
+const CHANNEL_NAME: &str = "primo_poker_session";
+const LEASE_STORAGE_KEY: &str = "primo_poker_socket_owner";
+const LEASE_TTL_MS: f64 = 15_000.0;
+const LEASE_RENEW_INTERVAL_MS: u32 = 4_000;
+const CLAIM_SETTLE_MS: u32 = 150; // wait for competing claims before reading the lease back
+const FOCUSED_TAB_KEY: &str = "primo_poker_focused_tab";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabRole {
+    Owner,
+    Follower,
+}
+
+// Ownership lease shared through local storage; the last writer of a claim wins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SocketLease {
+    tab_id: String,
+    expires_at: f64, // ms since epoch
+}
+
+impl SocketLease {
+    fn is_live(&self) -> bool {
+        self.expires_at > js_sys::Date::now()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum TabMessage {
+    // Raw server JSON mirrored by the owner so followers decode it exactly as the owner did
+    ServerMessage { payload: String },
+    ClientMessage { from: String, message: ClientMessage },
+    OwnerResigned { tab_id: String },
+}
+
+pub struct TabCoordinator {
+    inner: Rc<CoordinatorInner>,
+    _message_listener: EventListener,
+    _unload_listener: EventListener,
+    _focus_listeners: [EventListener; 2],
+    _lease_interval: Interval,
+}
+
+struct CoordinatorInner {
+    tab_id: String,
+    channel: BroadcastChannel,
+    role: Cell<TabRole>,
+    claiming: Cell<bool>,
+    claim_timeout: RefCell<Option<Timeout>>,
+    on_role_change: Callback<TabRole>,
+}
+
+impl TabCoordinator {
+    /// `on_role_change` hears the outcome of every claim, even one that leaves the role
+    /// as it was, so messages held while the claim settled can go out.
+    pub fn new(
+        on_role_change: Callback<TabRole>,
+        on_server_message: Callback<String>,
+        on_client_message: Callback<ClientMessage>,
+    ) -> Result<Self, PokerError> {
+        let channel = BroadcastChannel::new(CHANNEL_NAME)
+            .map_err(|e| PokerError::Unknown(format!("BroadcastChannel unavailable: {:?}", e)))?;
+
+        let inner = Rc::new(CoordinatorInner {
+            tab_id: uuid::Uuid::new_v4().to_string(),
+            channel,
+            role: Cell::new(TabRole::Follower),
+            claiming: Cell::new(false),
+            claim_timeout: RefCell::new(None),
+            on_role_change,
+        });
+
+        let message_listener = {
+            let channel = inner.channel.clone();
+            let inner = inner.clone();
+            EventListener::new(&channel, "message", move |event| {
+                let Some(event) = event.dyn_ref::<MessageEvent>() else {
+                    return;
+                };
+                let Some(data) = event.data().as_string() else {
+                    return;
+                };
+                match serde_json::from_str::<TabMessage>(&data) {
+                    Ok(TabMessage::ServerMessage { payload }) => {
+                        if inner.role.get() == TabRole::Follower {
+                            on_server_message.emit(payload);
+                        }
+                    }
+                    Ok(TabMessage::ClientMessage { from, message }) => {
+                        if inner.role.get() == TabRole::Owner {
+                            log::debug!("Relaying client message from tab {}", from);
+                            on_client_message.emit(message);
+                        }
+                    }
+                    Ok(TabMessage::OwnerResigned { tab_id }) => {
+                        log::info!("Socket owner tab {} closed, claiming ownership", tab_id);
+                        CoordinatorInner::try_claim(&inner);
+                    }
+                    Err(e) => log::warn!("Ignoring malformed tab message: {}", e),
+                }
+            })
+        };
+
+        let unload_listener = {
+            let inner = inner.clone();
+            EventListener::new(&gloo_utils::window(), "pagehide", move |_| inner.resign())
+        };
+
+        // Every tab hears every frame, so sounds and alerts belong to whichever tab has focus
+        let focus_listeners = {
+            let focused = inner.clone();
+            let blurred = inner.clone();
+            [
+                EventListener::new(&gloo_utils::window(), "focus", move |_| focused.took_focus()),
+                EventListener::new(&gloo_utils::window(), "blur", move |_| blurred.lost_focus()),
+            ]
+        };
+        if gloo_utils::document().has_focus().unwrap_or(false) {
+            inner.took_focus();
+        }
+
+        let lease_interval = {
+            let inner = inner.clone();
+            Interval::new(LEASE_RENEW_INTERVAL_MS, move || CoordinatorInner::tick(&inner))
+        };
+
+        CoordinatorInner::try_claim(&inner);
+
+        Ok(Self {
+            inner,
+            _message_listener: message_listener,
+            _unload_listener: unload_listener,
+            _focus_listeners: focus_listeners,
+            _lease_interval: lease_interval,
+        })
+    }
+
+    pub fn role(&self) -> TabRole {
+        self.inner.role.get()
+    }
+
+    /// False while a claim on the socket is waiting to settle, when no tab may own it yet.
+    pub fn is_settled(&self) -> bool {
+        !self.inner.claiming.get()
+    }
+
+    /// Callback the socket owner feeds every raw server frame into.
+    pub fn mirror_callback(&self) -> Callback<String> {
+        let inner = self.inner.clone();
+        Callback::from(move |payload: String| {
+            if inner.role.get() == TabRole::Owner {
+                inner.post(&TabMessage::ServerMessage { payload });
+            }
+        })
+    }
+
+    /// Whether this tab plays sounds and raises alerts for the session: the focused
+    /// tab does, or the socket owner while no tab has focus.
+    pub fn plays_feedback(&self) -> bool {
+        match LocalStorage::get::<String>(FOCUSED_TAB_KEY) {
+            Ok(tab_id) => tab_id == self.inner.tab_id,
+            Err(_) => self.role() == TabRole::Owner,
+        }
+    }
+
+    /// Fails when no other tab holds a live lease, since nobody would hear the message.
+    pub fn forward_to_owner(&self, message: ClientMessage) -> Result<(), PokerError> {
+        let owned = CoordinatorInner::current_lease().is_some_and(|lease| lease.is_live() && lease.tab_id != self.inner.tab_id);
+        if !owned {
+            return Err(PokerError::WebSocketError("No tab holds the server connection".to_string()));
+        }
+        self.inner.post(&TabMessage::ClientMessage {
+            from: self.inner.tab_id.clone(),
+            message,
+        });
+        Ok(())
+    }
+}
+
+impl Drop for TabCoordinator {
+    fn drop(&mut self) {
+        self.inner.resign();
+    }
+}
+
+impl CoordinatorInner {
+    fn current_lease() -> Option<SocketLease> {
+        LocalStorage::get::<SocketLease>(LEASE_STORAGE_KEY).ok()
+    }
+
+    fn write_lease(&self) {
+        let lease = SocketLease {
+            tab_id: self.tab_id.clone(),
+            expires_at: js_sys::Date::now() + LEASE_TTL_MS,
+        };
+        let _ = LocalStorage::set(LEASE_STORAGE_KEY, &lease);
+    }
+
+    fn try_claim(inner: &Rc<Self>) {
+        if Self::current_lease().is_some_and(|lease| lease.is_live() && lease.tab_id != inner.tab_id) {
+            return;
+        }
+
+        inner.write_lease();
+        inner.claiming.set(true);
+        let settle = {
+            let inner: Weak<Self> = Rc::downgrade(inner);
+            Timeout::new(CLAIM_SETTLE_MS, move || {
+                let Some(inner) = inner.upgrade() else {
+                    return;
+                };
+                let won = Self::current_lease().is_some_and(|lease| lease.tab_id == inner.tab_id);
+                inner.claiming.set(false);
+                let role = if won { TabRole::Owner } else { TabRole::Follower };
+                if !inner.set_role(role) {
+                    inner.on_role_change.emit(role);
+                }
+            })
+        };
+        *inner.claim_timeout.borrow_mut() = Some(settle);
+    }
+
+    fn tick(inner: &Rc<Self>) {
+        let lease = Self::current_lease();
+        match inner.role.get() {
+            TabRole::Owner => {
+                match lease {
+                    Some(lease) if lease.tab_id != inner.tab_id && lease.is_live() => {
+                        // Another tab took over while this one was throttled in the background
+                        inner.set_role(TabRole::Follower);
+                    }
+                    _ => inner.write_lease(),
+                }
+            }
+            TabRole::Follower => {
+                if !inner.claiming.get() && !lease.is_some_and(|l| l.is_live()) {
+                    Self::try_claim(inner);
+                }
+            }
+        }
+    }
+
+    /// Returns whether the role changed (and `on_role_change` was told).
+    fn set_role(&self, role: TabRole) -> bool {
+        let changed = self.role.replace(role) != role;
+        if changed {
+            log::info!("Tab {} is now socket {:?}", self.tab_id, role);
+            self.on_role_change.emit(role);
+        }
+        changed
+    }
+
+    fn took_focus(&self) {
+        let _ = LocalStorage::set(FOCUSED_TAB_KEY, &self.tab_id);
+    }
+
+    fn lost_focus(&self) {
+        if LocalStorage::get::<String>(FOCUSED_TAB_KEY).is_ok_and(|tab_id| tab_id == self.tab_id) {
+            LocalStorage::delete(FOCUSED_TAB_KEY);
+        }
+    }
+
+    fn resign(&self) {
+        self.lost_focus();
+        if self.role.get() != TabRole::Owner {
+            return;
+        }
+        if Self::current_lease().is_some_and(|lease| lease.tab_id == self.tab_id) {
+            LocalStorage::delete(LEASE_STORAGE_KEY);
+        }
+        self.post(&TabMessage::OwnerResigned { tab_id: self.tab_id.clone() });
+        self.role.set(TabRole::Follower);
+    }
+
+    fn post(&self, message: &TabMessage) {
+        match serde_json::to_string(message) {
+            Ok(json) => {
+                if let Err(e) = self.channel.post_message(&JsValue::from_str(&json)) {
+                    log::error!("Failed to post tab message: {:?}", e);
+                }
+            }
+            Err(e) => log::error!("Failed to serialize tab message: {}", e),
+        }
+    }
+}
@@ -1,75 +1,135 @@
 // WebSocket service - handles real-time communication with the PrimoPoker backend
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
 use yew::html::Scope;
+use yew::Callback;
 use gloo_net::websocket::{futures::WebSocket, Message};
 use gloo_timers::future::TimeoutFuture;
+use futures::channel::{mpsc, oneshot};
 use futures::{SinkExt, StreamExt};
 use wasm_bindgen_futures::spawn_local;
 
 use crate::types::{ClientMessage, ServerMessage, PokerError};
 use crate::app::AppMsg;
-use crate::game::GameState;
+use crate::services::tab_coordinator::TabRole;
 
 const WS_URL: &str = "wss://ws.primopoker.com"; // Replace with actual WebSocket URL
 const HEARTBEAT_INTERVAL: u32 = 30; // seconds
-const RECONNECT_DELAY: u32 = 5; // seconds
+const RECONNECT_DELAY: u32 = 5; // seconds, doubled after each failed attempt
+const MAX_RECONNECT_DELAY: u32 = 60; // seconds
+
+// Provided by App so any component can send through the tab that owns the socket
+#[derive(Clone, PartialEq)]
+pub struct ConnectionContext {
+    pub send: Callback<ClientMessage>,
+    pub role: TabRole,
+}
 
 pub struct WebSocketService {
     link: Scope<crate::app::App>,
-    websocket: Option<WebSocket>,
-    is_connected: bool,
+    outgoing: Option<mpsc::UnboundedSender<ClientMessage>>,
+    close_signal: Option<oneshot::Sender<()>>,
+    // Rooms with a PlayerActionRequired outstanding; cleared by the first action sent for that turn
+    awaiting_action: Rc<RefCell<HashSet<String>>>,
+    on_raw_message: Callback<String>, // kept so a reconnect mirrors frames the same way
     reconnection_attempts: u32,
     max_reconnection_attempts: u32,
+    reconnect_pending: bool,
 }
 
 impl WebSocketService {
     pub fn new(link: Scope<crate::app::App>) -> Self {
-        let mut service = Self {
+        // Connection is opened once this tab is elected socket owner
+        Self {
             link,
-            websocket: None,
-            is_connected: false,
+            outgoing: None,
+            close_signal: None,
+            awaiting_action: Rc::new(RefCell::new(HashSet::new())),
+            on_raw_message: Callback::noop(),
             reconnection_attempts: 0,
             max_reconnection_attempts: 10,
-        };
-        
-        // Start connection attempt
-        service.connect();
-        service
+            reconnect_pending: false,
+        }
     }
-    
-    pub fn connect(&mut self) {
+
+    /// Opens the socket; every raw text frame is also passed to `on_raw_message`
+    /// so it can be mirrored to other tabs.
+    pub fn connect(&mut self, on_raw_message: Callback<String>) {
+        if self.is_connected() {
+            return;
+        }
+        self.on_raw_message = on_raw_message.clone();
+
         let link = self.link.clone();
-        
+        let awaiting_action = self.awaiting_action.clone();
+        let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded::<ClientMessage>();
+        let (close_tx, close_rx) = oneshot::channel::<()>();
+        self.outgoing = Some(outgoing_tx.clone());
+        self.close_signal = Some(close_tx);
+
         spawn_local(async move {
             match WebSocket::open(WS_URL) {
                 Ok(ws) => {
                     log::info!("WebSocket connection established");
                     link.send_message(AppMsg::ConnectionEstablished);
-                    
-                    let (mut write, mut read) = ws.split();
-                    
+
+                    let (mut write, read) = ws.split();
+
                     // Start heartbeat
-                    let heartbeat_link = link.clone();
+                    let heartbeat_tx = outgoing_tx.clone();
                     spawn_local(async move {
                         loop {
                             TimeoutFuture::new(HEARTBEAT_INTERVAL * 1000).await;
-                            
-                            let heartbeat_msg = ClientMessage::Heartbeat;
-                            if let Ok(json) = serde_json::to_string(&heartbeat_msg) {
-                                if write.send(Message::Text(json)).await.is_err() {
-                                    log::error!("Failed to send heartbeat");
-                                    break;
+
+                            if heartbeat_tx.unbounded_send(ClientMessage::Heartbeat).is_err() {
+                                break;
+                            }
+                        }
+                    });
+
+                    // Local actions, relayed actions from other tabs and heartbeats
+                    // all share the single write half
+                    spawn_local(async move {
+                        while let Some(message) = outgoing_rx.next().await {
+                            let json = match serde_json::to_string(&message) {
+                                Ok(json) => json,
+                                Err(e) => {
+                                    log::error!("Failed to serialize message: {}", e);
+                                    continue;
                                 }
+                            };
+                            if write.send(Message::Text(json)).await.is_err() {
+                                log::error!("Failed to send message");
+                                break;
                             }
                         }
                     });
-                    
-                    // Handle incoming messages
+
+                    // Handle incoming messages until the server closes or we hand off ownership
+                    let mut read = read.take_until(close_rx);
                     while let Some(msg) = read.next().await {
                         match msg {
                             Ok(Message::Text(text)) => {
-                                if let Err(e) = Self::handle_message(&link, &text).await {
-                                    log::error!("Error handling message: {}", e);
-                                    link.send_message(AppMsg::Error(e.to_string()));
+                                on_raw_message.emit(text.clone());
+                                match Self::parse_message(&text) {
+                                    Ok(server_message) => {
+                                        match server_message {
+                                            ServerMessage::PlayerActionRequired { ref room_id, .. } => {
+                                                awaiting_action.borrow_mut().insert(room_id.clone());
+                                            }
+                                            ServerMessage::RoomLeft { ref room_id }
+                                            | ServerMessage::TableMoved { from_room_id: ref room_id, .. } => {
+                                                awaiting_action.borrow_mut().remove(room_id);
+                                            }
+                                            _ => {}
+                                        }
+                                        Self::dispatch(&link, server_message);
+                                    }
+                                    Err(e) => {
+                                        log::error!("Error handling message: {}", e);
+                                        link.send_message(AppMsg::Error(e.to_string()));
+                                    }
                                 }
                             }
                             Ok(Message::Bytes(_)) => {
@@ -77,33 +137,44 @@ impl WebSocketService {
                             }
                             Err(e) => {
                                 log::error!("WebSocket error: {:?}", e);
-                                link.send_message(AppMsg::ConnectionLost);
                                 break;
                             }
                         }
                     }
-                    
-                    log::info!("WebSocket connection closed");
-                    link.send_message(AppMsg::ConnectionLost);
+
+                    outgoing_tx.close_channel();
+                    if read.is_stopped() {
+                        log::info!("WebSocket closed locally");
+                    } else {
+                        log::info!("WebSocket connection closed");
+                        link.send_message(AppMsg::ConnectionLost);
+                    }
                 }
                 Err(e) => {
                     log::error!("Failed to connect to WebSocket: {:?}", e);
-                    link.send_message(AppMsg::Error(format!("Connection failed: {}", e)));
-                    
-                    // Attempt reconnection
-                    Self::attempt_reconnection(link).await;
+                    outgoing_tx.close_channel();
+                    link.send_message(AppMsg::ConnectionLost);
                 }
             }
         });
     }
-    
-    async fn handle_message(
+
+    /// Decodes and applies a raw server frame, whether it arrived on this
+    /// tab's socket or was mirrored from the owning tab.
+    pub fn handle_message(
         link: &Scope<crate::app::App>,
         message: &str,
     ) -> Result<(), PokerError> {
-        let server_message: ServerMessage = serde_json::from_str(message)
-            .map_err(|e| PokerError::SerializationError(e))?;
-        
+        let server_message = Self::parse_message(message)?;
+        Self::dispatch(link, server_message);
+        Ok(())
+    }
+
+    fn parse_message(message: &str) -> Result<ServerMessage, PokerError> {
+        serde_json::from_str(message).map_err(PokerError::SerializationError)
+    }
+
+    fn dispatch(link: &Scope<crate::app::App>, server_message: ServerMessage) {
         match server_message {
             ServerMessage::AuthenticationSuccess { user } => {
                 link.send_message(AppMsg::UserLoggedIn(user));
@@ -111,35 +182,29 @@ impl WebSocketService {
             ServerMessage::AuthenticationFailed { error } => {
                 link.send_message(AppMsg::Error(format!("Authentication failed: {}", error)));
             }
-            ServerMessage::RoomJoined { room, players } => {
-                let mut game_state = GameState::new();
-                game_state.join_room(room, players);
-                link.send_message(AppMsg::GameStateUpdated(game_state));
+            ServerMessage::RoomListUpdate { .. }
+            | ServerMessage::RoomListDelta { .. }
+            | ServerMessage::TournamentListUpdate { .. }
+            | ServerMessage::TournamentRegistered { .. }
+            | ServerMessage::TournamentUnregistered { .. }
+            | ServerMessage::TournamentRegistrationFailed { .. }
+            | ServerMessage::TournamentFinished { .. }
+            | ServerMessage::RoomJoinFailed { .. }
+            | ServerMessage::InviteCreated { .. }
+            | ServerMessage::RoomCreated { .. }
+            | ServerMessage::RoomCreationFailed { .. }
+            | ServerMessage::RoomHistory { .. }
+            | ServerMessage::GameInvitation { .. }
+            | ServerMessage::WaitlistJoined { .. }
+            | ServerMessage::WaitlistPosition { .. }
+            | ServerMessage::WaitlistLeft { .. }
+            | ServerMessage::SeatOffered { .. }
+            | ServerMessage::SeatOfferExpired { .. } => {
+                link.send_message(AppMsg::LobbyUpdate(server_message));
             }
-            ServerMessage::RoomLeft => {
-                let game_state = GameState::new();
-                link.send_message(AppMsg::GameStateUpdated(game_state));
-            }
-            ServerMessage::PlayerJoined { player } => {
-                // This would need to be handled by updating the current game state
-                log::info!("Player joined: {}", player.username);
-            }
-            ServerMessage::PlayerLeft { player_id } => {
-                // This would need to be handled by updating the current game state
-                log::info!("Player left: {}", player_id);
-            }
-            ServerMessage::GameStateUpdate { phase, community_cards, pot } => {
-                // This would need to be handled by updating the current game state
-                log::info!("Game state updated: {:?}", phase);
-            }
-            ServerMessage::PlayerActionRequired { time_limit } => {
-                log::info!("Action required with {} seconds", time_limit);
-            }
-            ServerMessage::PlayerActionUpdate { action } => {
-                log::info!("Player action: {:?}", action);
-            }
-            ServerMessage::HandResult { winners, pot_distribution } => {
-                log::info!("Hand completed with {} winners", winners.len());
+            message if message.room_id().is_some() => {
+                // Per-table updates are routed to the matching GameState by App
+                link.send_message(AppMsg::TableUpdate(message));
             }
             ServerMessage::ChatMessage { player_id, username, message, timestamp } => {
                 log::info!("Chat from {}: {}", username, message);
@@ -158,43 +223,80 @@ impl WebSocketService {
                 log::info!("Unhandled server message: {:?}", server_message);
             }
         }
-        
-        Ok(())
     }
-    
-    async fn attempt_reconnection(link: Scope<crate::app::App>) {
-        TimeoutFuture::new(RECONNECT_DELAY * 1000).await;
-        
-        // This would trigger a reconnection attempt
-        // In a real implementation, you'd want to track reconnection attempts
-        // and implement exponential backoff
-        log::info!("Attempting to reconnect...");
+
+    /// Drops the dead connection and schedules `AppMsg::Reconnect` with
+    /// exponential backoff. Repeated reports of the same loss are ignored.
+    pub fn schedule_reconnect(&mut self) {
+        self.close();
+        if self.reconnect_pending {
+            return;
+        }
+        if self.reconnection_attempts >= self.max_reconnection_attempts {
+            log::warn!("Giving up after {} reconnection attempts", self.reconnection_attempts);
+            return;
+        }
+
+        let delay = (RECONNECT_DELAY << self.reconnection_attempts.min(4)).min(MAX_RECONNECT_DELAY);
+        self.reconnection_attempts += 1;
+        self.reconnect_pending = true;
+        let link = self.link.clone();
+        spawn_local(async move {
+            TimeoutFuture::new(delay * 1000).await;
+            link.send_message(AppMsg::Reconnect);
+        });
+    }
+
+    pub fn reconnect(&mut self) {
+        // A reconnect cancelled by disconnect() (ownership handed off) is a no-op
+        if !std::mem::take(&mut self.reconnect_pending) {
+            return;
+        }
+        log::info!("Attempting to reconnect ({}/{})...", self.reconnection_attempts, self.max_reconnection_attempts);
+        self.connect(self.on_raw_message.clone());
     }
-    
-    pub async fn send_message(&mut self, message: ClientMessage) -> Result<(), PokerError> {
-        if let Some(ref mut ws) = self.websocket {
-            let json = serde_json::to_string(&message)
-                .map_err(|e| PokerError::SerializationError(e))?;
-            
-            // This is a simplified version - in reality you'd need to handle the split websocket
-            // ws.send(Message::Text(json)).await
-            //     .map_err(|e| PokerError::WebSocketError(format!("Send failed: {:?}", e)))?;
-            
-            Ok(())
-        } else {
-            Err(PokerError::WebSocketError("Not connected".to_string()))
+
+    pub fn connection_established(&mut self) {
+        self.reconnection_attempts = 0;
+    }
+
+    pub fn send_message(&self, message: ClientMessage) -> Result<(), PokerError> {
+        let Some(ref outgoing) = self.outgoing else {
+            return Err(PokerError::WebSocketError("Not connected".to_string()));
+        };
+
+        // Actions from every tab funnel through here, so a second action for
+        // the same turn (e.g. clicked in two tabs) is dropped instead of sent
+        if let ClientMessage::PlayerAction { ref room_id, .. } = message {
+            if !self.awaiting_action.borrow_mut().remove(room_id) {
+                return Err(PokerError::GameError(format!(
+                    "An action was already submitted for this turn at table {}",
+                    room_id
+                )));
+            }
         }
+
+        outgoing
+            .unbounded_send(message)
+            .map_err(|e| PokerError::WebSocketError(format!("Send failed: {}", e)))
     }
-    
+
     pub fn is_connected(&self) -> bool {
-        self.is_connected
+        self.outgoing.as_ref().is_some_and(|tx| !tx.is_closed())
     }
-    
+
     pub fn disconnect(&mut self) {
-        if let Some(ws) = self.websocket.take() {
-            // Close the websocket connection
-            drop(ws);
-            self.is_connected = false;
+        self.reconnect_pending = false;
+        self.reconnection_attempts = 0;
+        self.close();
+    }
+
+    fn close(&mut self) {
+        self.outgoing = None;
+        self.awaiting_action.borrow_mut().clear();
+        if let Some(close_signal) = self.close_signal.take() {
+            // Ends the read loop, which drops the socket and closes the connection
+            let _ = close_signal.send(());
         }
     }
 }
@@ -17,6 +17,10 @@ pub enum AppRoute {
     Lobby,
     #[at("/game/:room_id")]
     Game { room_id: String },
+    #[at("/tables")]
+    Tables,
+    #[at("/invite/:room_id/:code")]
+    Invite { room_id: String, code: String },
     #[at("/profile")]
     Profile,
     #[at("/demo")]
@@ -57,6 +61,27 @@ pub struct RegisterData {
     pub display_name: String,
 }
 
+// Two-factor authentication types
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "status")]
+pub enum LoginOutcome {
+    Authenticated { user: User },
+    TwoFactorRequired { challenge: TwoFactorChallenge },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorChallenge {
+    pub challenge_id: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorEnrollment {
+    pub secret: String, // base32-encoded shared secret
+    pub otpauth_uri: String,
+    pub recovery_codes: Vec<String>,
+}
+
 // Game-related types
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum Card {
@@ -64,7 +89,7 @@ pub enum Card {
     Card { suit: Suit, rank: Rank },
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Suit {
     Hearts,
     Diamonds,
@@ -72,7 +97,7 @@ pub enum Suit {
     Spades,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd)]
 pub enum Rank {
     Two = 2,
     Three = 3,
@@ -122,6 +147,83 @@ impl Default for GameType {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum BettingStructure {
+    #[default]
+    NoLimit,
+    PotLimit,
+    FixedLimit,
+}
+
+impl BettingStructure {
+    pub const ALL: [BettingStructure; 3] = [
+        BettingStructure::NoLimit,
+        BettingStructure::PotLimit,
+        BettingStructure::FixedLimit,
+    ];
+
+    /// Stable key used for form values and saved filters.
+    pub fn key(&self) -> &'static str {
+        match self {
+            BettingStructure::NoLimit => "no_limit",
+            BettingStructure::PotLimit => "pot_limit",
+            BettingStructure::FixedLimit => "fixed_limit",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|structure| structure.key() == key)
+    }
+
+    /// Lobby abbreviation, e.g. "PL" in "PLO".
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            BettingStructure::NoLimit => "NL",
+            BettingStructure::PotLimit => "PL",
+            BettingStructure::FixedLimit => "FL",
+        }
+    }
+}
+
+impl std::fmt::Display for BettingStructure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BettingStructure::NoLimit => write!(f, "No Limit"),
+            BettingStructure::PotLimit => write!(f, "Pot Limit"),
+            BettingStructure::FixedLimit => write!(f, "Fixed Limit"),
+        }
+    }
+}
+
+// Why the server turned down a JoinRoom request
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum JoinRoomError {
+    PasswordRequired,
+    WrongPassword,
+    InviteInvalid, // expired or already used
+    RoomFull,
+    RoomClosed,
+}
+
+impl JoinRoomError {
+    /// Whether asking for the password again can get us in.
+    pub fn needs_password(&self) -> bool {
+        matches!(self, JoinRoomError::PasswordRequired | JoinRoomError::WrongPassword | JoinRoomError::InviteInvalid)
+    }
+}
+
+impl std::fmt::Display for JoinRoomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinRoomError::PasswordRequired => write!(f, "This room is private. Enter the password to join."),
+            JoinRoomError::WrongPassword => write!(f, "Incorrect password. Please try again."),
+            JoinRoomError::InviteInvalid => write!(f, "This invite link has expired or was already used."),
+            JoinRoomError::RoomFull => write!(f, "This room is full."),
+            JoinRoomError::RoomClosed => write!(f, "This room has been closed."),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum GamePhase {
     WaitingForPlayers,
@@ -129,6 +231,12 @@ pub enum GamePhase {
     Flop,
     Turn,
     River,
+    // Stud streets
+    ThirdStreet,
+    FourthStreet,
+    FifthStreet,
+    SixthStreet,
+    SeventhStreet,
     Showdown,
     HandComplete,
 }
@@ -141,6 +249,7 @@ pub enum PlayerAction {
     Bet(i64),
     Raise(i64),
     AllIn,
+    BringIn, // forced opening bet by the lowest up card on third street (Stud)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -159,14 +268,44 @@ pub enum ClientMessage {
     Authenticate { token: String },
     
     // Lobby actions
-    JoinRoom { room_id: String },
-    LeaveRoom,
+    JoinRoom {
+        room_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        invite_code: Option<String>, // one-time code from an invite link, instead of the password
+    },
+    CreateInvite { room_id: String },
+    LeaveRoom { room_id: String },
     CreateRoom { room_config: RoomConfig },
+    
+    // Room owner controls
+    KickPlayer { room_id: String, player_id: Uuid },
+    SetPaused { room_id: String, paused: bool },
+    ChangeBlinds { room_id: String, small_blind: i64, big_blind: i64 }, // takes effect from the next hand
     GetRoomList,
     
-    // Game actions
-    PlayerAction { action: PlayerAction },
-    RequestTimeExtension,
+    // Favorite and recently joined rooms, kept by the server for signed-in users
+    GetRoomHistory,
+    SetFavoriteRoom { room_id: String, favorite: bool },
+    
+    // Waitlists - queue for full tables, several at once
+    JoinWaitlist { room_id: String },
+    LeaveWaitlist { room_id: String },
+    AcceptSeat { room_id: String },
+    DeclineSeat { room_id: String }, // also leaves that table's waitlist
+    
+    // Tournaments
+    GetTournamentList,
+    RegisterTournament { tournament_id: String },
+    UnregisterTournament { tournament_id: String },
+    
+    // Game actions - always tagged with the table they belong to
+    PlayerAction { room_id: String, action: PlayerAction },
+    RequestTimeExtension { room_id: String },
+    StraddleDecision { room_id: String, post: bool },
+    RunItTwiceDecision { room_id: String, agree: bool },
+    ShowdownDecision { room_id: String, show: bool }, // after winning uncontested: show the hand or muck it
     
     // Chat
     SendMessage { message: String, room_id: Option<String> },
@@ -185,16 +324,77 @@ pub enum ServerMessage {
     
     // Room updates
     RoomJoined { room: RoomInfo, players: Vec<PlayerInfo> },
-    RoomLeft,
+    RoomLeft { room_id: String },
+    RoomJoinFailed { room_id: String, reason: JoinRoomError },
+    InviteCreated { room_id: String, code: String },
+    RoomCreated { room: RoomInfo },
+    RoomCreationFailed { reason: String },
+    PlayerKicked { room_id: String, player_id: Uuid },
+    RoomPaused { room_id: String, paused: bool },
+    BlindsChangeScheduled { room_id: String, small_blind: i64, big_blind: i64 },
     RoomListUpdate { rooms: Vec<RoomInfo> },
-    PlayerJoined { player: PlayerInfo },
-    PlayerLeft { player_id: Uuid },
+    // Incremental lobby changes since the last full list
+    RoomListDelta {
+        #[serde(default)]
+        added: Vec<RoomInfo>,
+        #[serde(default)]
+        updated: Vec<RoomInfo>,
+        #[serde(default)]
+        removed: Vec<String>,
+    },
+    PlayerJoined { room_id: String, player: PlayerInfo },
+    PlayerLeft { room_id: String, player_id: Uuid },
+    RoomHistory {
+        #[serde(default)]
+        favorites: Vec<SavedRoom>,
+        #[serde(default)]
+        recent: Vec<SavedRoom>,
+    },
+    // Another player asking us to their table; the code lets us into private rooms
+    GameInvitation {
+        room_id: String,
+        room_name: String,
+        from_username: String,
+        #[serde(default)]
+        invite_code: Option<String>,
+    },
+    
+    // Waitlists - lobby-scoped, since we aren't seated at these tables
+    WaitlistJoined { room_id: String, position: u32 },
+    WaitlistPosition { room_id: String, position: u32 },
+    WaitlistLeft { room_id: String },
+    SeatOffered { room_id: String, expires_at: DateTime<Utc> },
+    SeatOfferExpired { room_id: String },
+    
+    // Tournament updates
+    TournamentListUpdate { tournaments: Vec<TournamentInfo> },
+    TournamentRegistered { tournament_id: String },
+    TournamentUnregistered { tournament_id: String },
+    TournamentRegistrationFailed { tournament_id: String, reason: String },
+    TournamentFinished { tournament_id: String, position: u32, prize: i64 }, // our finishing place
+    BlindLevelChanged { room_id: String, level: BlindLevel, next_level: Option<BlindLevel>, ends_at: DateTime<Utc> },
+    // Table balancing - the server moves us to another table of the same tournament
+    TableMoved { tournament_id: String, from_room_id: String, room: RoomInfo, players: Vec<PlayerInfo> },
     
     // Game updates
-    GameStateUpdate { phase: GamePhase, community_cards: Vec<Card>, pot: i64 },
-    PlayerActionRequired { time_limit: i32 },
-    PlayerActionUpdate { action: GameAction },
-    HandResult { winners: Vec<HandWinner>, pot_distribution: Vec<PotWinner> },
+    GameStateUpdate { room_id: String, phase: GamePhase, community_cards: Vec<Card>, pot: i64 },
+    PlayerActionRequired { room_id: String, time_limit: i32 },
+    PlayerActionUpdate { room_id: String, action: GameAction },
+    HandResult {
+        room_id: String,
+        winners: Vec<HandWinner>,
+        pot_distribution: Vec<PotWinner>,
+        #[serde(default)]
+        shown: Vec<ShownHand>, // hole cards turned up at showdown; empty when everyone else folded
+    },
+    HandShown { room_id: String, player_id: Uuid, cards: Vec<Card> }, // an uncontested winner chose to show
+    
+    // Home-game options - straddle offers and run-it-twice agreement on all-ins
+    StraddleOffered { room_id: String, amount: i64, time_limit: i32 },
+    StraddlePosted { room_id: String, player_id: Uuid, amount: i64 },
+    RunItTwiceOffered { room_id: String, time_limit: i32 },
+    RunItTwiceDecided { room_id: String, agreed: bool },
+    SecondBoardUpdate { room_id: String, community_cards: Vec<Card> },
     
     // Chat
     ChatMessage { player_id: Uuid, username: String, message: String, timestamp: DateTime<Utc> },
@@ -205,10 +405,40 @@ pub enum ServerMessage {
     Disconnect { reason: String },
 }
 
+impl ServerMessage {
+    /// The table a message applies to, for messages scoped to a single room.
+    pub fn room_id(&self) -> Option<&str> {
+        match self {
+            ServerMessage::RoomJoined { room, .. } => Some(&room.id),
+            ServerMessage::RoomLeft { room_id }
+            | ServerMessage::PlayerJoined { room_id, .. }
+            | ServerMessage::PlayerLeft { room_id, .. }
+            | ServerMessage::GameStateUpdate { room_id, .. }
+            | ServerMessage::PlayerActionRequired { room_id, .. }
+            | ServerMessage::PlayerActionUpdate { room_id, .. }
+            | ServerMessage::HandResult { room_id, .. }
+            | ServerMessage::HandShown { room_id, .. }
+            | ServerMessage::StraddleOffered { room_id, .. }
+            | ServerMessage::StraddlePosted { room_id, .. }
+            | ServerMessage::RunItTwiceOffered { room_id, .. }
+            | ServerMessage::RunItTwiceDecided { room_id, .. }
+            | ServerMessage::SecondBoardUpdate { room_id, .. }
+            | ServerMessage::PlayerKicked { room_id, .. }
+            | ServerMessage::RoomPaused { room_id, .. }
+            | ServerMessage::BlindsChangeScheduled { room_id, .. }
+            | ServerMessage::BlindLevelChanged { room_id, .. } => Some(room_id),
+            ServerMessage::TableMoved { from_room_id, .. } => Some(from_room_id),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoomConfig {
     pub name: String,
     pub game_type: GameType,
+    #[serde(default)]
+    pub betting_structure: BettingStructure,
     pub max_players: u8,
     pub small_blind: i64,
     pub big_blind: i64,
@@ -216,6 +446,14 @@ pub struct RoomConfig {
     pub max_buy_in: i64,
     pub is_private: bool,
     pub password: Option<String>,
+    #[serde(default)]
+    pub ante: i64,
+    #[serde(default)]
+    pub button_ante: bool, // the dealer pays the whole table's ante
+    #[serde(default)]
+    pub straddle_allowed: bool,
+    #[serde(default)]
+    pub run_it_twice: bool, // all-in players may agree to deal the rest of the board twice
 }
 
 // Lobby and room types
@@ -224,6 +462,8 @@ pub struct GameRoom {
     pub id: String,
     pub name: String,
     pub game_type: GameType,
+    #[serde(default)]
+    pub betting_structure: BettingStructure,
     pub current_players: u8,
     pub max_players: u8,
     pub small_blind: i64,
@@ -233,18 +473,227 @@ pub struct GameRoom {
     pub is_private: bool,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub tournament_id: Option<String>, // set for tables that belong to a tournament
+    #[serde(default)]
+    pub ante: i64,
+    #[serde(default)]
+    pub bring_in: i64, // Stud only
+    #[serde(default)]
+    pub button_ante: bool,
+    #[serde(default)]
+    pub straddle_allowed: bool,
+    #[serde(default)]
+    pub run_it_twice: bool,
+    #[serde(default)]
+    pub owner_id: Option<Uuid>, // player who created the room; None for house tables
+    #[serde(default)]
+    pub avg_pot: i64,
+    #[serde(default)]
+    pub hands_per_hour: u32,
+    #[serde(default)]
+    pub waitlist: u32, // players waiting for a seat
 }
 
 pub type RoomInfo = GameRoom; // Alias for compatibility
 
+// Tournament types
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum TournamentFormat {
+    MultiTable,
+    SitAndGo, // starts as soon as every seat is filled
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum TournamentStatus {
+    Registering,
+    Running,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BlindLevel {
+    pub level: u32,
+    pub small_blind: i64,
+    pub big_blind: i64,
+    pub ante: i64,
+    pub duration_minutes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PayoutTier {
+    pub first_place: u32,
+    pub last_place: u32, // inclusive; equal to first_place for a single position
+    pub percentage: f64, // share of the prize pool paid to each place in the tier
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TournamentConfig {
+    pub name: String,
+    pub format: TournamentFormat,
+    pub game_type: GameType,
+    pub buy_in: i64, // goes to the prize pool
+    pub fee: i64,    // kept by the house
+    pub starting_stack: i64,
+    pub max_entrants: u32,
+    pub blind_structure: Vec<BlindLevel>,
+    pub payouts: Vec<PayoutTier>,
+    pub late_registration_levels: u32, // registration stays open through this level
+    pub starts_at: Option<DateTime<Utc>>, // None for sit & gos
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TournamentInfo {
+    pub id: String,
+    pub config: TournamentConfig,
+    pub status: TournamentStatus,
+    pub registered_players: u32,
+    pub current_level: u32, // 0 before the first level starts
+    pub is_registered: bool,
+}
+
+impl TournamentInfo {
+    pub fn prize_pool(&self) -> i64 {
+        self.config.buy_in * i64::from(self.registered_players)
+    }
+
+    pub fn total_cost(&self) -> i64 {
+        self.config.buy_in + self.config.fee
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.registered_players >= self.config.max_entrants
+    }
+
+    pub fn registration_open(&self) -> bool {
+        match self.status {
+            TournamentStatus::Registering => !self.is_full(),
+            TournamentStatus::Running => {
+                self.config.format == TournamentFormat::MultiTable
+                    && self.current_level <= self.config.late_registration_levels
+                    && !self.is_full()
+            }
+            TournamentStatus::Completed | TournamentStatus::Cancelled => false,
+        }
+    }
+
+    pub fn is_late_registration(&self) -> bool {
+        self.status == TournamentStatus::Running && self.registration_open()
+    }
+
+    /// Chips paid to a finishing position, given the current prize pool.
+    pub fn payout_for_place(&self, place: u32) -> i64 {
+        self.config
+            .payouts
+            .iter()
+            .find(|tier| (tier.first_place..=tier.last_place).contains(&place))
+            .map_or(0, |tier| (self.prize_pool() as f64 * tier.percentage / 100.0).round() as i64)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RoomFilter {
     pub min_stakes: Option<i64>,
     pub max_stakes: Option<i64>,
     pub max_players_filter: Option<u8>,
     pub game_types: Vec<GameType>,
+    #[serde(default)]
+    pub betting_structures: Vec<BettingStructure>, // empty matches every structure
     pub show_full_rooms: bool,
     pub show_empty_rooms: bool,
+    #[serde(default)]
+    pub search: String, // case-insensitive match on room names
+}
+
+// A filter the user saved under a name of their choosing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FilterPreset {
+    pub name: String,
+    pub filter: RoomFilter,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum RoomSortKey {
+    #[default]
+    Stakes,
+    Players,
+    AveragePot,
+    HandsPerHour,
+    Waitlist,
+}
+
+impl RoomSortKey {
+    pub const ALL: [RoomSortKey; 5] = [
+        RoomSortKey::Stakes,
+        RoomSortKey::Players,
+        RoomSortKey::AveragePot,
+        RoomSortKey::HandsPerHour,
+        RoomSortKey::Waitlist,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RoomSortKey::Stakes => "Stakes",
+            RoomSortKey::Players => "Players",
+            RoomSortKey::AveragePot => "Avg Pot",
+            RoomSortKey::HandsPerHour => "Hands/hr",
+            RoomSortKey::Waitlist => "Waitlist",
+        }
+    }
+
+    pub fn value(&self, room: &GameRoom) -> i64 {
+        match self {
+            RoomSortKey::Stakes => room.big_blind,
+            RoomSortKey::Players => i64::from(room.current_players),
+            RoomSortKey::AveragePot => room.avg_pot,
+            RoomSortKey::HandsPerHour => i64::from(room.hands_per_hour),
+            RoomSortKey::Waitlist => i64::from(room.waitlist),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct RoomSort {
+    pub key: RoomSortKey,
+    pub descending: bool,
+}
+
+impl Default for RoomSort {
+    fn default() -> Self {
+        Self { key: RoomSortKey::default(), descending: true }
+    }
+}
+
+/// A favorite or recently joined room, remembered even after it leaves the lobby list.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedRoom {
+    pub room_id: String,
+    pub name: String,
+    pub saved_at: DateTime<Utc>, // when it was starred, or last joined
+}
+
+/// What QuickJoin looks for when it picks a table, saved per user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct QuickJoinSettings {
+    pub buy_in_percent: u32,         // share of the user's chips to bring to one table
+    pub game_type: Option<GameType>, // None: any game
+    pub avoid_short_tables: bool,    // pass over empty and heads-up tables while fuller ones are open
+    pub prefer_recent: bool,         // lean toward tables played recently
+    pub blocked_rooms: Vec<String>,  // never quick-join these
+}
+
+impl Default for QuickJoinSettings {
+    fn default() -> Self {
+        Self {
+            buy_in_percent: 5,
+            game_type: None,
+            avoid_short_tables: true,
+            prefer_recent: true,
+            blocked_rooms: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -252,12 +701,35 @@ pub struct HandWinner {
     pub player_id: Uuid,
     pub hand_type: HandType,
     pub cards: Vec<Card>,
+    #[serde(default)]
+    pub share: PotShare,
+    #[serde(default)]
+    pub board: u8, // 1 for the second board when the hand was run twice
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShownHand {
+    pub player_id: Uuid,
+    pub cards: Vec<Card>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PotWinner {
     pub player_id: Uuid,
     pub amount: i64,
+    #[serde(default)]
+    pub share: PotShare,
+    #[serde(default)]
+    pub board: u8,
+}
+
+// Which half of a hi/lo pot a win came from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum PotShare {
+    #[default]
+    High,
+    Low,
+    Scoop, // won both halves
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -291,16 +763,156 @@ pub enum EaseFunction {
     Bounce,
 }
 
+impl Animation {
+    pub fn new(target: impl Into<String>, duration: f32, ease_function: EaseFunction) -> Self {
+        Self { target: target.into(), duration, ease_function }
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        f64::from(self.duration.max(0.0)) * 1000.0
+    }
+
+    /// Eased progress from 0 to 1, `elapsed` milliseconds after the start.
+    pub fn progress(&self, elapsed: f64) -> f64 {
+        let duration = self.duration_ms();
+        if duration == 0.0 {
+            return 1.0;
+        }
+        self.ease_function.ease((elapsed / duration).clamp(0.0, 1.0))
+    }
+}
+
+impl EaseFunction {
+    /// Maps linear time `t` in 0..=1 onto the curve; every curve starts at 0 and ends at 1.
+    pub fn ease(&self, t: f64) -> f64 {
+        match self {
+            EaseFunction::Linear => t,
+            EaseFunction::EaseIn => t * t * t,
+            EaseFunction::EaseOut => 1.0 - (1.0 - t).powi(3),
+            EaseFunction::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            // Drops in and settles with three shrinking bounces
+            EaseFunction::Bounce => {
+                const N: f64 = 7.5625;
+                const D: f64 = 2.75;
+                if t < 1.0 / D {
+                    N * t * t
+                } else if t < 2.0 / D {
+                    let t = t - 1.5 / D;
+                    N * t * t + 0.75
+                } else if t < 2.5 / D {
+                    let t = t - 2.25 / D;
+                    N * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D;
+                    N * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct NotificationMessage {
     pub id: Uuid,
     pub message: String,
     pub notification_type: NotificationType,
-    pub duration: Option<f32>,
+    pub duration: Option<f32>, // seconds on screen once shown; None stays until dismissed
     pub created_at: DateTime<Utc>,
+    pub priority: NotificationPriority,
+    pub dedupe_key: Option<String>, // repeats with the same key bump `repeat_count` instead of stacking
+    pub repeat_count: u32,
+    pub actions: Vec<NotificationAction>,
+}
+
+impl NotificationMessage {
+    pub fn new(notification_type: NotificationType, message: impl Into<String>) -> Self {
+        let duration = match notification_type {
+            NotificationType::Info | NotificationType::Success => 4.0,
+            NotificationType::Warning => 6.0,
+            NotificationType::Error => 8.0,
+        };
+        Self {
+            id: Uuid::new_v4(),
+            message: message.into(),
+            notification_type,
+            duration: Some(duration),
+            created_at: Utc::now(),
+            priority: NotificationPriority::default(),
+            dedupe_key: None,
+            repeat_count: 1,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(NotificationType::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(NotificationType::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(NotificationType::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(NotificationType::Error, message)
+    }
+
+    pub fn with_priority(mut self, priority: NotificationPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.dedupe_key = Some(key.into());
+        self
+    }
+
+    /// Adds a button; clicking it runs the callback and dismisses the notification.
+    pub fn with_action(mut self, label: impl Into<String>, on_click: yew::Callback<()>) -> Self {
+        self.actions.push(NotificationAction { label: label.into(), on_click });
+        self
+    }
+
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Whether a new notification repeats this one rather than being news of its own.
+    pub fn is_repeat_of(&self, other: &NotificationMessage) -> bool {
+        match (&self.dedupe_key, &other.dedupe_key) {
+            (Some(key), Some(other_key)) => key == other_key,
+            (None, None) => self.notification_type == other.notification_type && self.message == other.message,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum NotificationPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical, // never auto-dismissed, always shown first
 }
 
 #[derive(Debug, Clone, PartialEq)]
+pub struct NotificationAction {
+    pub label: String,
+    pub on_click: yew::Callback<()>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NotificationType {
     Info,
     Success,
@@ -383,6 +995,23 @@ impl std::fmt::Display for Card {
     }
 }
 
+impl std::fmt::Display for HandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandType::HighCard => write!(f, "High Card"),
+            HandType::Pair => write!(f, "Pair"),
+            HandType::TwoPair => write!(f, "Two Pair"),
+            HandType::ThreeOfAKind => write!(f, "Three of a Kind"),
+            HandType::Straight => write!(f, "Straight"),
+            HandType::Flush => write!(f, "Flush"),
+            HandType::FullHouse => write!(f, "Full House"),
+            HandType::FourOfAKind => write!(f, "Four of a Kind"),
+            HandType::StraightFlush => write!(f, "Straight Flush"),
+            HandType::RoyalFlush => write!(f, "Royal Flush"),
+        }
+    }
+}
+
 // Social and Player Status Types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PlayerStatus {
@@ -1,6 +1,7 @@
 // Utility functions and helper modules
 // pub mod time;
-// pub mod validation;
+pub mod validation;
 // pub mod storage;
 // pub mod math;
 // pub mod logging;
+pub mod totp;
@@ -0,0 +1,212 @@
+// Time-based one-time passwords (RFC 6238) for two-factor authentication
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use uuid::Uuid;
+
+use crate::types::PokerError;
+
+pub const TOTP_DIGITS: usize = 6;
+pub const TOTP_PERIOD: i64 = 30; // seconds
+pub const TOTP_ISSUER: &str = "Primo Poker";
+const ALLOWED_DRIFT_STEPS: i64 = 1; // accept one period either side for clock skew
+const SECRET_LENGTH: usize = 20; // bytes, 160 bits as recommended by RFC 4226
+const RECOVERY_CODE_COUNT: usize = 8;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Generates a new base32-encoded shared secret, every bit of it from the platform CSPRNG.
+pub fn generate_secret() -> Result<String, PokerError> {
+    let mut bytes = [0u8; SECRET_LENGTH];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|e| PokerError::AuthenticationError(format!("No secure random source: {}", e)))?;
+    Ok(base32_encode(&bytes))
+}
+
+/// Generates one-time recovery codes in `xxxxx-xxxxx` format.
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let hex = Uuid::new_v4().simple().to_string();
+            format!("{}-{}", &hex[..5], &hex[5..10])
+        })
+        .collect()
+}
+
+/// Computes the code for the period containing `unix_time`.
+pub fn generate_code(secret: &str, unix_time: i64) -> Result<String, PokerError> {
+    let key = base32_decode(secret)
+        .ok_or_else(|| PokerError::AuthenticationError("Invalid two-factor secret".to_string()))?;
+    let counter = unix_time.div_euclid(TOTP_PERIOD) as u64;
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key)
+        .map_err(|e| PokerError::AuthenticationError(e.to_string()))?;
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    // Dynamic truncation (RFC 4226 section 5.3)
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    let code = binary % 10u32.pow(TOTP_DIGITS as u32);
+
+    Ok(format!("{:0width$}", code, width = TOTP_DIGITS))
+}
+
+/// Checks a user-entered code, tolerating spaces and small clock drift.
+pub fn verify_code(secret: &str, code: &str, unix_time: i64) -> bool {
+    matching_step(secret, code, unix_time).is_some()
+}
+
+/// The time-step a valid code was generated for, so a verifier can refuse to accept
+/// the same step twice.
+pub fn matching_step(secret: &str, code: &str, unix_time: i64) -> Option<i64> {
+    let code = normalize_code(code);
+    if code.len() != TOTP_DIGITS || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    (-ALLOWED_DRIFT_STEPS..=ALLOWED_DRIFT_STEPS)
+        .map(|drift| unix_time.div_euclid(TOTP_PERIOD) + drift)
+        .find(|step| {
+            generate_code(secret, step * TOTP_PERIOD)
+                .map(|expected| expected == code)
+                .unwrap_or(false)
+        })
+}
+
+/// Strips whitespace and dashes so "123 456" and "abcde-fghij" compare cleanly.
+pub fn normalize_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Builds the Key URI understood by authenticator apps.
+pub fn otpauth_uri(account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(TOTP_ISSUER),
+        percent_encode(account),
+        secret,
+        percent_encode(TOTP_ISSUER),
+        TOTP_DIGITS,
+        TOTP_PERIOD
+    )
+}
+
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            output.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        output.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    output
+}
+
+pub fn base32_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in encoded.chars().filter(|c| *c != '=' && !c.is_whitespace()) {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a as char == c.to_ascii_uppercase())? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(output)
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The RFC 6238 appendix B key, "12345678901234567890"
+    const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn matches_the_rfc_6238_sha1_vectors() {
+        // The RFC lists eight digits; six-digit codes are their last six
+        let vectors = [
+            (59, "94287082"),
+            (1_111_111_109, "07081804"),
+            (1_111_111_111, "14050471"),
+            (1_234_567_890, "89005924"),
+            (2_000_000_000, "69279037"),
+            (20_000_000_000, "65353130"),
+        ];
+        for (time, expected) in vectors {
+            assert_eq!(generate_code(RFC_SECRET, time).unwrap(), expected[2..], "at T={}", time);
+        }
+    }
+
+    #[test]
+    fn accepts_one_step_of_drift_and_reports_the_step() {
+        let code = generate_code(RFC_SECRET, 1_111_111_109).unwrap();
+        let step = 1_111_111_109 / TOTP_PERIOD;
+        assert_eq!(matching_step(RFC_SECRET, &code, 1_111_111_109), Some(step));
+        assert_eq!(matching_step(RFC_SECRET, &code, 1_111_111_109 + TOTP_PERIOD), Some(step));
+        assert_eq!(matching_step(RFC_SECRET, &code, 1_111_111_109 - TOTP_PERIOD), Some(step));
+        assert_eq!(matching_step(RFC_SECRET, &code, 1_111_111_109 + 3 * TOTP_PERIOD), None);
+        assert!(verify_code(RFC_SECRET, &format!("{} {}", &code[..3], &code[3..]), 1_111_111_109));
+        assert!(!verify_code(RFC_SECRET, "12a456", 1_111_111_109));
+    }
+
+    #[test]
+    fn base32_matches_the_rfc_4648_vectors() {
+        let vectors = [("", ""), ("f", "MY"), ("fo", "MZXQ"), ("foo", "MZXW6"), ("foob", "MZXW6YQ"), ("fooba", "MZXW6YTB"), ("foobar", "MZXW6YTBOI")];
+        for (plain, encoded) in vectors {
+            assert_eq!(base32_encode(plain.as_bytes()), encoded);
+            assert_eq!(base32_decode(encoded).as_deref(), Some(plain.as_bytes()));
+        }
+        assert_eq!(base32_decode("mzxw6ytb oi======").as_deref(), Some(&b"foobar"[..]), "padding, spaces and case are ignored");
+        assert_eq!(base32_decode("MZXW1"), None, "1 is not in the alphabet");
+    }
+
+    #[test]
+    fn base32_round_trips_any_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        for len in 0..40 {
+            assert_eq!(base32_decode(&base32_encode(&bytes[..len])).as_deref(), Some(&bytes[..len]));
+        }
+    }
+
+    #[test]
+    fn secrets_are_full_length_and_distinct() {
+        let first = generate_secret().unwrap();
+        let second = generate_secret().unwrap();
+        assert_eq!(base32_decode(&first).map(|key| key.len()), Some(SECRET_LENGTH));
+        assert_ne!(first, second);
+    }
+}
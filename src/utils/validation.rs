@@ -0,0 +1,339 @@
+// Form validation - composable rules shared by every form in the client
+use std::fmt;
+
+pub const USERNAME_MIN_LENGTH: usize = 3;
+pub const USERNAME_MAX_LENGTH: usize = 20;
+pub const PASSWORD_MIN_LENGTH: usize = 8;
+pub const USERNAME_CHECK_DEBOUNCE_MS: u32 = 400;
+const EMAIL_MAX_LENGTH: usize = 254;
+const EMAIL_LOCAL_MAX_LENGTH: usize = 64;
+const DOMAIN_LABEL_MAX_LENGTH: usize = 63;
+const PASSWORD_SPECIAL_CHARACTERS: &str = "!@#$%^&*()_+-=[]{}|;:,.<>?";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Per-field validation state, including checks that complete asynchronously.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum FieldState {
+    #[default]
+    Idle,
+    Validating,
+    Valid,
+    Invalid(String),
+}
+
+impl FieldState {
+    pub fn from_result(result: Result<(), ValidationError>) -> Self {
+        match result {
+            Ok(()) => FieldState::Valid,
+            Err(error) => FieldState::Invalid(error.message),
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            FieldState::Invalid(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, FieldState::Invalid(_))
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, FieldState::Validating)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    UsernameCharacters,
+    Email,
+    PasswordStrength,
+    MatchesField { other_value: String, message: String },
+}
+
+impl Rule {
+    pub fn check(&self, label: &str, value: &str) -> Result<(), String> {
+        match self {
+            Rule::Required => {
+                if value.trim().is_empty() {
+                    return Err(format!("{} is required", label));
+                }
+            }
+            Rule::MinLength(min) => {
+                if value.chars().count() < *min {
+                    return Err(format!("{} must be at least {} characters", label, min));
+                }
+            }
+            Rule::MaxLength(max) => {
+                if value.chars().count() > *max {
+                    return Err(format!("{} must be no more than {} characters", label, max));
+                }
+            }
+            Rule::UsernameCharacters => {
+                if !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                    return Err(format!("{} can only contain letters, numbers, _ and -", label));
+                }
+            }
+            Rule::Email => {
+                if !is_valid_email(value) {
+                    return Err("Please enter a valid email address".to_string());
+                }
+            }
+            Rule::PasswordStrength => {
+                if !value.chars().any(|c| c.is_uppercase()) {
+                    return Err(format!("{} must contain at least one uppercase letter", label));
+                }
+                if !value.chars().any(|c| c.is_lowercase()) {
+                    return Err(format!("{} must contain at least one lowercase letter", label));
+                }
+                if !value.chars().any(|c| c.is_ascii_digit()) {
+                    return Err(format!("{} must contain at least one number", label));
+                }
+                if !value.chars().any(|c| PASSWORD_SPECIAL_CHARACTERS.contains(c)) {
+                    return Err(format!("{} must contain at least one special character", label));
+                }
+            }
+            Rule::MatchesField { other_value, message } => {
+                if value != other_value {
+                    return Err(message.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An ordered list of rules for one field; the first failing rule wins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldValidator {
+    field: String,
+    label: String,
+    rules: Vec<Rule>,
+}
+
+impl FieldValidator {
+    pub fn new(field: &str, label: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            label: label.to_string(),
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        for rule in &self.rules {
+            rule.check(&self.label, value).map_err(|message| ValidationError {
+                field: self.field.clone(),
+                message,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// Standard field validators used across auth and profile forms
+pub fn username_validator() -> FieldValidator {
+    FieldValidator::new("username", "Username")
+        .rule(Rule::Required)
+        .rule(Rule::MinLength(USERNAME_MIN_LENGTH))
+        .rule(Rule::MaxLength(USERNAME_MAX_LENGTH))
+        .rule(Rule::UsernameCharacters)
+}
+
+pub fn email_validator() -> FieldValidator {
+    FieldValidator::new("email", "Email")
+        .rule(Rule::Required)
+        .rule(Rule::Email)
+}
+
+pub fn display_name_validator() -> FieldValidator {
+    FieldValidator::new("display_name", "Display name")
+        .rule(Rule::Required)
+        .rule(Rule::MaxLength(32))
+}
+
+pub fn password_validator() -> FieldValidator {
+    FieldValidator::new("password", "Password")
+        .rule(Rule::Required)
+        .rule(Rule::MinLength(PASSWORD_MIN_LENGTH))
+        .rule(Rule::PasswordStrength)
+}
+
+pub fn confirm_password_validator(password: &str) -> FieldValidator {
+    FieldValidator::new("confirm_password", "Password confirmation")
+        .rule(Rule::Required)
+        .rule(Rule::MatchesField {
+            other_value: password.to_string(),
+            message: "Passwords do not match".to_string(),
+        })
+}
+
+/// Practical subset of RFC 5322 addr-spec: dot-atom local part and a
+/// hostname-style domain with an alphabetic top-level label.
+pub fn is_valid_email(email: &str) -> bool {
+    if email.len() > EMAIL_MAX_LENGTH {
+        return false;
+    }
+
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+
+    is_valid_email_local_part(local) && is_valid_email_domain(domain)
+}
+
+fn is_valid_email_local_part(local: &str) -> bool {
+    const ATEXT_SPECIALS: &str = "!#$%&'*+/=?^_`{|}~-";
+
+    !local.is_empty()
+        && local.len() <= EMAIL_LOCAL_MAX_LENGTH
+        && local.split('.').all(|atom| {
+            !atom.is_empty()
+                && atom.chars().all(|c| c.is_ascii_alphanumeric() || ATEXT_SPECIALS.contains(c))
+        })
+}
+
+fn is_valid_email_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return false;
+    }
+
+    let labels_valid = labels.iter().all(|label| {
+        !label.is_empty()
+            && label.len() <= DOMAIN_LABEL_MAX_LENGTH
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    });
+
+    let tld = labels[labels.len() - 1];
+    labels_valid && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+// Compatibility helpers for call sites that validate a single field ad hoc
+pub struct FormValidator;
+
+impl FormValidator {
+    pub fn validate_email(email: &str) -> Result<(), ValidationError> {
+        email_validator().validate(email)
+    }
+
+    pub fn validate_required(value: &str, field_name: &str) -> Result<(), ValidationError> {
+        FieldValidator::new(field_name, field_name)
+            .rule(Rule::Required)
+            .validate(value)
+    }
+
+    pub fn validate_min_length(value: &str, min_length: usize, field_name: &str) -> Result<(), ValidationError> {
+        FieldValidator::new(field_name, field_name)
+            .rule(Rule::MinLength(min_length))
+            .validate(value)
+    }
+
+    pub fn validate_password_strength(password: &str) -> Result<(), ValidationError> {
+        password_validator().validate(password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(result: Result<(), ValidationError>) -> String {
+        result.expect_err("expected a validation error").message
+    }
+
+    #[test]
+    fn email_accepts_common_addresses() {
+        for email in ["player@example.com", "first.last+poker@mail.example.co.uk", "a_b-c@sub-domain.io"] {
+            assert!(is_valid_email(email), "{} should be valid", email);
+        }
+    }
+
+    #[test]
+    fn email_rejects_malformed_addresses() {
+        for email in [
+            "",
+            "player",
+            "player@",
+            "@example.com",
+            "player@example",
+            "player@example.c",
+            "player@example.123",
+            "pla yer@example.com",
+            ".player@example.com",
+            "player..one@example.com",
+            "player@-example.com",
+            "player@example-.com",
+            "player@exa_mple.com",
+        ] {
+            assert!(!is_valid_email(email), "{:?} should be invalid", email);
+        }
+        assert!(!is_valid_email(&format!("{}@example.com", "a".repeat(EMAIL_LOCAL_MAX_LENGTH + 1))));
+        assert_eq!(message(email_validator().validate("nope")), "Please enter a valid email address");
+    }
+
+    #[test]
+    fn username_rules_apply_in_order() {
+        let validator = username_validator();
+        assert!(validator.validate("River_Rat-42").is_ok());
+        assert_eq!(message(validator.validate("  ")), "Username is required");
+        assert_eq!(message(validator.validate("ab")), "Username must be at least 3 characters");
+        assert_eq!(
+            message(validator.validate(&"a".repeat(USERNAME_MAX_LENGTH + 1))),
+            "Username must be no more than 20 characters"
+        );
+        assert_eq!(
+            message(validator.validate("bad name!")),
+            "Username can only contain letters, numbers, _ and -"
+        );
+        assert_eq!(validator.validate("ab").unwrap_err().field, "username");
+    }
+
+    #[test]
+    fn password_requires_length_and_every_character_class() {
+        let validator = password_validator();
+        assert!(validator.validate("Pocket#Aces9").is_ok());
+        assert_eq!(message(validator.validate("")), "Password is required");
+        assert_eq!(message(validator.validate("Ab1!")), "Password must be at least 8 characters");
+        assert_eq!(message(validator.validate("pocket#aces9")), "Password must contain at least one uppercase letter");
+        assert_eq!(message(validator.validate("POCKET#ACES9")), "Password must contain at least one lowercase letter");
+        assert_eq!(message(validator.validate("Pocket#Aces")), "Password must contain at least one number");
+        assert_eq!(message(validator.validate("PocketAces9")), "Password must contain at least one special character");
+    }
+
+    #[test]
+    fn confirm_password_must_match() {
+        assert!(confirm_password_validator("Pocket#Aces9").validate("Pocket#Aces9").is_ok());
+        assert_eq!(
+            message(confirm_password_validator("Pocket#Aces9").validate("Pocket#Aces8")),
+            "Passwords do not match"
+        );
+    }
+}